@@ -1,5 +1,6 @@
 use sc_service::ChainType;
-use node_template_runtime::{AccountId, Signature, WASM_BINARY};
+use node_template_runtime::{AccountId, Runtime, Signature, WASM_BINARY};
+use pallet_game::{PropertyInfoDataV1, RoundNumber, VersionedProperty};
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
 use sp_core::{sr25519, Pair, Public};
@@ -91,6 +92,29 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
 	.build())
 }
 
+/// The drand round the prices of the development catalogue are locked to.
+const DEV_PROPERTY_ROUND: RoundNumber = 100_000_000;
+
+/// The property catalogue of development and local testnets.
+///
+/// The prices are placeholders, games on these properties are refunded once their deadline is
+/// over. Use the `property-catalogue` subcommand to convert a catalogue with timelocked prices.
+fn dev_properties() -> Vec<VersionedProperty<Runtime>> {
+	[147229391, 146480642, 147031382]
+		.into_iter()
+		.map(|id| {
+			VersionedProperty::V1(PropertyInfoDataV1 {
+				id,
+				data: b"nfdjakl;fueif;janf,dnfm,dhfhfdksks".to_vec().try_into().unwrap(),
+				region: b"Bristol".to_vec().try_into().unwrap(),
+				features: b"3 bedrooms, garden".to_vec().try_into().unwrap(),
+				round: DEV_PROPERTY_ROUND,
+				price: Default::default(),
+			})
+		})
+		.collect()
+}
+
 /// Configure initial storage state for FRAME modules.
 fn testnet_genesis(
 	initial_authorities: Vec<(AuraId, GrandpaId)>,
@@ -113,5 +137,8 @@ fn testnet_genesis(
 			// Assign network admin rights.
 			"key": Some(root_key),
 		},
+		"game": {
+			"properties": dev_properties(),
+		},
 	})
 }
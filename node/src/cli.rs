@@ -43,4 +43,7 @@ pub enum Subcommand {
 
 	/// Db meta columns information.
 	ChainInfo(sc_cli::ChainInfoCmd),
+
+	/// Convert a CSV property catalogue for the game pallet.
+	PropertyCatalogue(crate::property_catalogue::PropertyCatalogueCmd),
 }
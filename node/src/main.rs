@@ -5,6 +5,7 @@ mod benchmarking;
 mod chain_spec;
 mod cli;
 mod command;
+mod property_catalogue;
 mod rpc;
 mod service;
 
@@ -0,0 +1,111 @@
+//! Converts a CSV property catalogue into the formats accepted by `pallet_game`.
+
+use codec::Encode;
+use node_template_runtime::Runtime;
+use pallet_game::{PropertyInfoDataV1, RoundNumber, VersionedProperty};
+use sp_core::{bytes::from_hex, Get};
+use sp_runtime::BoundedVec;
+use std::{collections::BTreeSet, fs::File, io::Write, path::PathBuf};
+
+/// The format the converted catalogue is written in.
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum CatalogueFormat {
+	/// A JSON list that can be used as the `properties` of the game genesis config.
+	Json,
+	/// The hex encoded SCALE argument of the `import_properties` call.
+	Scale,
+}
+
+/// Convert a CSV property catalogue for the game pallet.
+///
+/// The CSV file needs the columns `id`, `region`, `features`, `data`, `round` and `price`, where
+/// `price` is the hex encoded timelock ciphertext of the price.
+#[derive(Debug, clap::Parser)]
+pub struct PropertyCatalogueCmd {
+	/// The CSV file to read the catalogue from.
+	#[arg(long)]
+	pub input: PathBuf,
+
+	/// The file to write the converted catalogue to. Defaults to stdout.
+	#[arg(long)]
+	pub output: Option<PathBuf>,
+
+	/// The output format.
+	#[arg(long, value_enum, default_value_t = CatalogueFormat::Json)]
+	pub format: CatalogueFormat,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct CatalogueRow {
+	id: u32,
+	region: String,
+	features: String,
+	data: String,
+	round: RoundNumber,
+	price: String,
+}
+
+impl PropertyCatalogueCmd {
+	/// Run the command.
+	pub fn run(&self) -> sc_cli::Result<()> {
+		let properties = self.read_catalogue()?;
+		let output = match self.format {
+			CatalogueFormat::Json => serde_json::to_string_pretty(&properties)
+				.map_err(|e| format!("Failed to serialize the catalogue: {e}"))?,
+			CatalogueFormat::Scale => sp_core::bytes::to_hex(&properties.encode(), false),
+		};
+		match &self.output {
+			Some(path) => File::create(path)
+				.and_then(|mut file| writeln!(file, "{output}"))
+				.map_err(|e| format!("Failed to write {}: {e}", path.display()))?,
+			None => println!("{output}"),
+		}
+		Ok(())
+	}
+
+	/// Reads and validates the catalogue against the limits of the runtime.
+	fn read_catalogue(
+		&self,
+	) -> Result<
+		BoundedVec<VersionedProperty<Runtime>, <Runtime as pallet_game::Config>::MaxProperty>,
+		String,
+	> {
+		let mut reader = csv::Reader::from_path(&self.input)
+			.map_err(|e| format!("Failed to open {}: {e}", self.input.display()))?;
+		let mut ids = BTreeSet::new();
+		let mut properties = Vec::new();
+		for (line, row) in reader.deserialize::<CatalogueRow>().enumerate() {
+			let row = row.map_err(|e| format!("Invalid row {}: {e}", line + 1))?;
+			if !ids.insert(row.id) {
+				return Err(format!("Property id {} is used more than once", row.id));
+			}
+			properties.push(VersionedProperty::V1(to_property(row)?));
+		}
+		let max_properties = <Runtime as pallet_game::Config>::MaxProperty::get();
+		properties.try_into().map_err(|properties: Vec<_>| {
+			format!(
+				"The catalogue has {} properties, at most {max_properties} are allowed",
+				properties.len()
+			)
+		})
+	}
+}
+
+fn to_property(row: CatalogueRow) -> Result<PropertyInfoDataV1<Runtime>, String> {
+	let price =
+		from_hex(&row.price).map_err(|e| format!("Invalid price of property {}: {e:?}", row.id))?;
+	Ok(PropertyInfoDataV1 {
+		id: row.id,
+		data: bounded(row.id, "data", row.data.into_bytes())?,
+		region: bounded(row.id, "region", row.region.into_bytes())?,
+		features: bounded(row.id, "features", row.features.into_bytes())?,
+		round: row.round,
+		price: bounded(row.id, "price", price)?,
+	})
+}
+
+fn bounded<S: Get<u32>>(id: u32, field: &str, value: Vec<u8>) -> Result<BoundedVec<u8, S>, String> {
+	value
+		.try_into()
+		.map_err(|_| format!("The {field} of property {id} is longer than {} bytes", S::get()))
+}
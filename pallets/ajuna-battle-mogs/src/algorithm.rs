@@ -14,9 +14,7 @@
 // You should have received a copy of the GNU Affero General Public License
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
-use crate::{BreedType, MogwaiGeneration, RarityType};
-
-use sp_std::{mem::MaybeUninit, ptr::copy_nonoverlapping};
+use crate::{array_at, BreedType, Dna, HalfSegment, MogwaiGeneration, RarityType, Segment};
 
 struct Binary;
 
@@ -80,10 +78,10 @@ impl Breeding {
 	pub fn sacrifice(
 		input_generation_1: MogwaiGeneration,
 		input_rarity_1: RarityType,
-		input_dna_1: &[[u8; 32]; 2],
+		input_dna_1: &Dna,
 		input_generation_2: MogwaiGeneration,
 		input_rarity_2: RarityType,
-		input_dna_2: &[[u8; 32]; 2],
+		input_dna_2: &Dna,
 	) -> MogwaiGeneration {
 		let input_generation_1 = input_generation_1 as u16;
 		let input_generation_2 = input_generation_2 as u16;
@@ -133,11 +131,11 @@ impl Breeding {
 			};
 
 			let gen_add = input_generation_1 + input_generation_2;
-			let pos1 = input_dna_1[0][((gen_add + input_rarity_2) % 32) as usize];
-			let pos2 = input_dna_2[0][((gen_add + input_rarity_1) % 32) as usize];
+			let pos1 = input_dna_1.stats()[((gen_add + input_rarity_2) % 32) as usize];
+			let pos2 = input_dna_2.stats()[((gen_add + input_rarity_1) % 32) as usize];
 
-			let val1 = input_dna_1[0][(pos2 % 32) as usize];
-			let val2 = input_dna_2[0][(pos1 % 32) as usize];
+			let val1 = input_dna_1.stats()[(pos2 % 32) as usize];
+			let val2 = input_dna_2.stats()[(pos1 % 32) as usize];
 
 			if val1 < final_prob && val2 < final_prob {
 				MogwaiGeneration::coerce_from((val1 as u16 + val2 as u16) % max_generation + 1)
@@ -151,10 +149,10 @@ impl Breeding {
 
 	pub fn morph(
 		breed_type: BreedType,
-		left_source_dna: &[u8; 16],
-		right_source_dna: &[u8; 16],
-	) -> [u8; 32] {
-		let mut final_dna: MaybeUninit<[u8; 32]> = MaybeUninit::uninit();
+		left_source_dna: &HalfSegment,
+		right_source_dna: &HalfSegment,
+	) -> Segment {
+		let mut final_dna = Segment::default();
 
 		let (left_indexes, right_indexes) = match breed_type {
 			BreedType::DomDom => ((0..8, 8..16), (0..8, 8..16)),
@@ -163,49 +161,42 @@ impl Breeding {
 			BreedType::RezRez => ((8..16, 0..8), (0..8, 8..16)),
 		};
 
-		unsafe {
-			let dna_ptr = final_dna.as_mut_ptr() as *mut u8;
-
-			copy_nonoverlapping(left_source_dna[left_indexes.0].as_ptr(), dna_ptr, 8);
-			copy_nonoverlapping(left_source_dna[left_indexes.1].as_ptr(), dna_ptr.add(8), 8);
-			copy_nonoverlapping(right_source_dna[right_indexes.0].as_ptr(), dna_ptr.add(16), 8);
-			copy_nonoverlapping(right_source_dna[right_indexes.1].as_ptr(), dna_ptr.add(24), 8);
+		final_dna[0..8].copy_from_slice(&left_source_dna[left_indexes.0]);
+		final_dna[8..16].copy_from_slice(&left_source_dna[left_indexes.1]);
+		final_dna[16..24].copy_from_slice(&right_source_dna[right_indexes.0]);
+		final_dna[24..32].copy_from_slice(&right_source_dna[right_indexes.1]);
 
-			final_dna.assume_init()
-		}
+		final_dna
 	}
 
 	pub fn pairing(
 		breed_type: BreedType,
-		left_source_dna: &[u8; 32],
-		right_source_dna: &[u8; 32],
-	) -> [[u8; 32]; 2] {
-		let mut left_dna: MaybeUninit<[u8; 32]> = MaybeUninit::uninit();
-		let mut right_dna: MaybeUninit<[u8; 32]> = MaybeUninit::uninit();
-
+		left_source_dna: &Segment,
+		right_source_dna: &Segment,
+	) -> Dna {
+		let mut left_dna = Segment::default();
+		let mut right_dna = Segment::default();
+
+		// The second right range used to be the empty `16..16`, from which 16 bytes were copied
+		// regardless, reading the bytes of `16..32`.
 		let (left_indexes, right_indexes) = match breed_type {
-			BreedType::DomDom => ((0..16, 16..32), (0..16, 16..16)),
+			BreedType::DomDom => ((0..16, 16..32), (0..16, 16..32)),
 			BreedType::DomRez => ((0..16, 16..32), (16..32, 0..16)),
 			BreedType::RezDom => ((16..32, 0..16), (16..32, 0..16)),
 			BreedType::RezRez => ((16..32, 0..16), (0..16, 16..32)),
 		};
 
-		unsafe {
-			let l_dna_ptr = left_dna.as_mut_ptr() as *mut u8;
-			let r_dna_ptr = right_dna.as_mut_ptr() as *mut u8;
+		left_dna[0..16].copy_from_slice(&left_source_dna[left_indexes.0]);
+		left_dna[16..32].copy_from_slice(&left_source_dna[left_indexes.1]);
+		right_dna[0..16].copy_from_slice(&right_source_dna[right_indexes.0]);
+		right_dna[16..32].copy_from_slice(&right_source_dna[right_indexes.1]);
 
-			copy_nonoverlapping(left_source_dna[left_indexes.0].as_ptr(), l_dna_ptr, 16);
-			copy_nonoverlapping(left_source_dna[left_indexes.1].as_ptr(), l_dna_ptr.add(16), 16);
-			copy_nonoverlapping(right_source_dna[right_indexes.0].as_ptr(), r_dna_ptr, 16);
-			copy_nonoverlapping(right_source_dna[right_indexes.1].as_ptr(), r_dna_ptr.add(16), 16);
-
-			[left_dna.assume_init(), right_dna.assume_init()]
-		}
+		Dna::new(left_dna, right_dna)
 	}
 
-	pub fn segmenting(input_dna: [[u8; 32]; 2], block_hash: [u8; 32]) -> [[u8; 32]; 2] {
-		let stats_segment = &input_dna[0];
-		let visuals_segment = &input_dna[1];
+	pub fn segmenting(input_dna: Dna, block_hash: Segment) -> Dna {
+		let stats_segment = input_dna.stats();
+		let visuals_segment = input_dna.visuals();
 
 		let _stats_segment_1 = &stats_segment[0..16]; // Unused for now
 		let stats_segment_2 = &stats_segment[16..32];
@@ -361,10 +352,10 @@ impl Breeding {
 			mask_side = mask_side.flip();
 		}
 
-		[output_stats, output_visuals]
+		Dna::new(output_stats, output_visuals)
 	}
 
-	pub fn bake(rarity: RarityType, blk: [u8; 32]) -> RarityType {
+	pub fn bake(rarity: RarityType, blk: Segment) -> RarityType {
 		let prob: u16 = 250;
 
 		let rarity = rarity as u8;
@@ -440,16 +431,20 @@ impl Generation {
 		let mut resulting_gen = MogwaiGeneration::default();
 		let mut resulting_rarity = RarityType::default();
 
-		if random_hash.len() >= 12 {
+		if let (Some(hash_1), Some(hash_2)) = (array_at(random_hash, 0), array_at(random_hash, 6)) {
 			let base_rarity = (input_rarity_1 as u16 + input_rarity_2 as u16).saturating_sub(2) / 2;
 
-			let slice = unsafe { &*(&random_hash[0..6] as *const [u8] as *const [u8; 6]) };
-			let (out_rarity_1, out_gen_1) =
-				Self::compute_next_generation_and_rarity(input_generation_1, input_rarity_1, slice);
+			let (out_rarity_1, out_gen_1) = Self::compute_next_generation_and_rarity(
+				input_generation_1,
+				input_rarity_1,
+				&hash_1,
+			);
 
-			let slice = unsafe { &*(&random_hash[6..12] as *const [u8] as *const [u8; 6]) };
-			let (out_rarity_2, out_gen_2) =
-				Self::compute_next_generation_and_rarity(input_generation_2, input_rarity_2, slice);
+			let (out_rarity_2, out_gen_2) = Self::compute_next_generation_and_rarity(
+				input_generation_2,
+				input_rarity_2,
+				&hash_2,
+			);
 
 			resulting_gen = MogwaiGeneration::coerce_from(
 				(out_gen_1 as u16 + out_gen_2 as u16 + base_rarity) / 2,
@@ -457,7 +452,8 @@ impl Generation {
 
 			resulting_rarity = RarityType::from(
 				((out_rarity_1 as u16 +
-					out_rarity_2 as u16 + ((input_rarity_1 as u16 + input_rarity_2 as u16) / 2)) /
+					out_rarity_2 as u16 +
+					((input_rarity_1 as u16 + input_rarity_2 as u16) / 2)) /
 					2) % 5,
 			)
 		}
@@ -483,9 +479,9 @@ mod test {
 			let hash: [u8; 32] = [0; 32];
 
 			let expected_output = [[0; 32], [0x33; 32]];
-			let output_dna = Breeding::segmenting(dna, hash);
+			let output_dna = Breeding::segmenting(dna.into(), hash);
 
-			assert_eq!(output_dna, expected_output);
+			assert_eq!(output_dna, Dna::from(expected_output));
 		}
 
 		#[test]
@@ -494,9 +490,9 @@ mod test {
 			let hash: [u8; 32] = [0xFF; 32];
 
 			let expected_output = [[0; 32], [0x33; 32]];
-			let output_dna = Breeding::segmenting(dna, hash);
+			let output_dna = Breeding::segmenting(dna.into(), hash);
 
-			assert_eq!(output_dna, expected_output);
+			assert_eq!(output_dna, Dna::from(expected_output));
 		}
 
 		#[test]
@@ -505,9 +501,9 @@ mod test {
 			let hash: [u8; 32] = [0; 32];
 
 			let expected_output = [[0; 32], [0x33; 32]];
-			let output_dna = Breeding::segmenting(dna, hash);
+			let output_dna = Breeding::segmenting(dna.into(), hash);
 
-			assert_eq!(output_dna, expected_output);
+			assert_eq!(output_dna, Dna::from(expected_output));
 		}
 
 		#[test]
@@ -516,9 +512,9 @@ mod test {
 			let hash: [u8; 32] = [0xFF; 32];
 
 			let expected_output = [[0xFF; 32], [0x33; 32]];
-			let output_dna = Breeding::segmenting(dna, hash);
+			let output_dna = Breeding::segmenting(dna.into(), hash);
 
-			assert_eq!(output_dna, expected_output);
+			assert_eq!(output_dna, Dna::from(expected_output));
 		}
 
 		#[test]
@@ -527,9 +523,9 @@ mod test {
 			let hash: [u8; 32] = [0; 32];
 
 			let expected_output = [[0; 32], [0x33; 32]];
-			let output_dna = Breeding::segmenting(dna, hash);
+			let output_dna = Breeding::segmenting(dna.into(), hash);
 
-			assert_eq!(output_dna, expected_output);
+			assert_eq!(output_dna, Dna::from(expected_output));
 		}
 
 		#[test]
@@ -538,9 +534,9 @@ mod test {
 			let hash: [u8; 32] = [0xFF; 32];
 
 			let expected_output = [[0xFF; 32], [0x33; 32]];
-			let output_dna = Breeding::segmenting(dna, hash);
+			let output_dna = Breeding::segmenting(dna.into(), hash);
 
-			assert_eq!(output_dna, expected_output);
+			assert_eq!(output_dna, Dna::from(expected_output));
 		}
 
 		#[test]
@@ -549,9 +545,9 @@ mod test {
 			let hash: [u8; 32] = [0; 32];
 
 			let expected_output = [[0; 32], [0x33; 32]];
-			let output_dna = Breeding::segmenting(dna, hash);
+			let output_dna = Breeding::segmenting(dna.into(), hash);
 
-			assert_eq!(output_dna, expected_output);
+			assert_eq!(output_dna, Dna::from(expected_output));
 		}
 
 		#[test]
@@ -560,9 +556,9 @@ mod test {
 			let hash: [u8; 32] = [0xFF; 32];
 
 			let expected_output = [[0xFF; 32], [0x33; 32]];
-			let output_dna = Breeding::segmenting(dna, hash);
+			let output_dna = Breeding::segmenting(dna.into(), hash);
 
-			assert_eq!(output_dna, expected_output);
+			assert_eq!(output_dna, Dna::from(expected_output));
 		}
 
 		#[test]
@@ -598,9 +594,148 @@ mod test {
 					0x7F, 0xE7, 0xEB, 0xBC, 0xBB, 0xA8,
 				],
 			];
-			let output_dna = Breeding::segmenting(dna, hash);
+			let output_dna = Breeding::segmenting(dna.into(), hash);
+
+			assert_eq!(output_dna, Dna::from(expected_output));
+		}
+	}
+
+	/// The former pointer based implementations, used as reference for the safe ones.
+	mod legacy {
+		use super::*;
+		use core::{mem::MaybeUninit, ops::Range, ptr::copy_nonoverlapping};
 
-			assert_eq!(output_dna, expected_output);
+		/// Copy `len` bytes from `source` starting at the start of `range`, ignoring its end
+		/// just like the former implementations did.
+		unsafe fn copy_from(source: &[u8], range: Range<usize>, dest: *mut u8, len: usize) {
+			assert!(range.start + len <= source.len());
+			copy_nonoverlapping(source.as_ptr().add(range.start), dest, len);
+		}
+
+		pub fn morph(breed_type: BreedType, left: &[u8; 16], right: &[u8; 16]) -> [u8; 32] {
+			let mut final_dna: MaybeUninit<[u8; 32]> = MaybeUninit::uninit();
+
+			let (left_indexes, right_indexes) = match breed_type {
+				BreedType::DomDom => ((0..8, 8..16), (0..8, 8..16)),
+				BreedType::DomRez => ((0..8, 8..16), (8..16, 0..8)),
+				BreedType::RezDom => ((8..16, 0..8), (8..16, 0..8)),
+				BreedType::RezRez => ((8..16, 0..8), (0..8, 8..16)),
+			};
+
+			unsafe {
+				let dna_ptr = final_dna.as_mut_ptr() as *mut u8;
+
+				copy_from(left, left_indexes.0, dna_ptr, 8);
+				copy_from(left, left_indexes.1, dna_ptr.add(8), 8);
+				copy_from(right, right_indexes.0, dna_ptr.add(16), 8);
+				copy_from(right, right_indexes.1, dna_ptr.add(24), 8);
+
+				final_dna.assume_init()
+			}
+		}
+
+		pub fn pairing(breed_type: BreedType, left: &[u8; 32], right: &[u8; 32]) -> [[u8; 32]; 2] {
+			let mut left_dna: MaybeUninit<[u8; 32]> = MaybeUninit::uninit();
+			let mut right_dna: MaybeUninit<[u8; 32]> = MaybeUninit::uninit();
+
+			let (left_indexes, right_indexes) = match breed_type {
+				BreedType::DomDom => ((0..16, 16..32), (0..16, 16..16)),
+				BreedType::DomRez => ((0..16, 16..32), (16..32, 0..16)),
+				BreedType::RezDom => ((16..32, 0..16), (16..32, 0..16)),
+				BreedType::RezRez => ((16..32, 0..16), (0..16, 16..32)),
+			};
+
+			unsafe {
+				let l_dna_ptr = left_dna.as_mut_ptr() as *mut u8;
+				let r_dna_ptr = right_dna.as_mut_ptr() as *mut u8;
+
+				copy_from(left, left_indexes.0, l_dna_ptr, 16);
+				copy_from(left, left_indexes.1, l_dna_ptr.add(16), 16);
+				copy_from(right, right_indexes.0, r_dna_ptr, 16);
+				copy_from(right, right_indexes.1, r_dna_ptr.add(16), 16);
+
+				[left_dna.assume_init(), right_dna.assume_init()]
+			}
+		}
+
+		pub fn next_gen_hashes(random_hash: &[u8]) -> Option<([u8; 6], [u8; 6])> {
+			(random_hash.len() >= 12).then(|| unsafe {
+				(
+					*(&random_hash[0..6] as *const [u8] as *const [u8; 6]),
+					*(&random_hash[6..12] as *const [u8] as *const [u8; 6]),
+				)
+			})
+		}
+	}
+
+	mod safe_dna {
+		use super::*;
+		use proptest::prelude::*;
+
+		fn breed_type() -> impl Strategy<Value = BreedType> {
+			prop_oneof![
+				Just(BreedType::DomDom),
+				Just(BreedType::DomRez),
+				Just(BreedType::RezDom),
+				Just(BreedType::RezRez),
+			]
+		}
+
+		fn rarity() -> impl Strategy<Value = RarityType> {
+			(0..=5_u8).prop_map(RarityType::from)
+		}
+
+		fn generation() -> impl Strategy<Value = MogwaiGeneration> {
+			(1..=16_u16).prop_map(MogwaiGeneration::coerce_from)
+		}
+
+		proptest! {
+			#[test]
+			fn morph_matches_legacy(
+				breed_type in breed_type(),
+				left in any::<[u8; 16]>(),
+				right in any::<[u8; 16]>(),
+			) {
+				prop_assert_eq!(
+					Breeding::morph(breed_type, &left, &right),
+					legacy::morph(breed_type, &left, &right)
+				);
+			}
+
+			#[test]
+			fn pairing_matches_legacy(
+				breed_type in breed_type(),
+				left in any::<[u8; 32]>(),
+				right in any::<[u8; 32]>(),
+			) {
+				prop_assert_eq!(
+					Breeding::pairing(breed_type, &left, &right).into_inner(),
+					legacy::pairing(breed_type, &left, &right)
+				);
+			}
+
+			#[test]
+			fn next_gen_hashes_match_legacy(random_hash in any::<Vec<u8>>()) {
+				let hashes = array_at::<6>(&random_hash, 0).zip(array_at::<6>(&random_hash, 6));
+				prop_assert_eq!(hashes, legacy::next_gen_hashes(&random_hash));
+			}
+
+			#[test]
+			fn next_gen_accepts_any_hash(
+				generation_1 in generation(),
+				rarity_1 in rarity(),
+				generation_2 in generation(),
+				rarity_2 in rarity(),
+				random_hash in any::<Vec<u8>>(),
+			) {
+				let (rarity, generation, _) =
+					Generation::next_gen(generation_1, rarity_1, generation_2, rarity_2, &random_hash);
+
+				if random_hash.len() < 12 {
+					prop_assert_eq!(rarity, RarityType::default());
+					prop_assert_eq!(generation, MogwaiGeneration::default());
+				}
+			}
 		}
 	}
 }
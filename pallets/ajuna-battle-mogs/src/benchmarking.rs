@@ -19,7 +19,8 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use crate::{Config as BattleMogsConfig, *};
-use frame_benchmarking::{benchmarks, whitelist_account, whitelisted_caller};
+use frame_benchmarking::{benchmarks, whitelist_account, whitelisted_caller, BenchmarkError};
+use frame_support::traits::EnsureOrigin;
 use frame_system::RawOrigin;
 use sp_runtime::traits::UniqueSaturatedInto;
 
@@ -47,19 +48,66 @@ fn force_mogwai_rarity<T: Config>(mogwai_id: &MogwaiIdOf<T>, rarity: RarityType)
 	});
 }
 
+fn create_mogwais<T: Config>(
+	owner: &T::AccountId,
+	count: u32,
+) -> Result<Vec<MogwaiIdOf<T>>, &'static str> {
+	// Raise the account's mogwai limit to its maximum to fit a full batch.
+	AccountConfig::<T>::insert(owner, [0, 7, 0, 0, 0, 0, 0, 0, 0, 0]);
+	for _ in 0..count {
+		Pallet::<T>::create_mogwai(RawOrigin::Signed(owner.clone()).into())?;
+	}
+	Ok(Owners::<T>::get(owner).into_iter().collect())
+}
+
+/// A mogwai of `seller` and the largest offer `buyer` can make for it.
+fn trade<T: Config>(
+	seller: &T::AccountId,
+	buyer: &T::AccountId,
+) -> Result<(MogwaiIdOf<T>, TradeTermsOf<T>), &'static str> {
+	let mogwai_id = create_mogwais::<T>(seller, 1)?[0];
+	let mogwais = create_mogwais::<T>(buyer, T::MaxOfferedMogwais::get())?;
+	let terms = TradeTerms {
+		amount: T::Currency::minimum_balance(),
+		mogwais: BoundedVec::truncate_from(mogwais),
+	};
+	Ok((mogwai_id, terms))
+}
+
+/// Fill up the offers for `mogwai_id` but one, so that settling an offer invalidates the most.
+fn competing_offers<T: Config>(mogwai_id: MogwaiIdOf<T>) -> Result<(), &'static str> {
+	for index in 1..T::MaxOffersPerMogwai::get() {
+		let bidder: T::AccountId = frame_benchmarking::account("bidder", index, 0);
+		CurrencyOf::<T>::make_free_balance_be(&bidder, 999_999_999_u64.unique_saturated_into());
+		let terms =
+			TradeTerms { amount: T::Currency::minimum_balance(), mogwais: BoundedVec::default() };
+		Pallet::<T>::make_offer(RawOrigin::Signed(bidder).into(), mogwai_id, terms)?;
+	}
+	Ok(())
+}
+
+fn lending_terms<T: Config>(borrower: T::AccountId) -> LendingTermsOf<T> {
+	LendingTerms {
+		borrower,
+		duration: T::MaxLendingDuration::get(),
+		fee: T::Currency::minimum_balance(),
+		permissions: LendingPermissions { breed: true, battle: true },
+	}
+}
+
 benchmarks! {
-	set_organizer {
-		let origin: T::AccountId = whitelisted_caller();
-		T::Currency::make_free_balance_be(&origin, T::Currency::minimum_balance() * 20_000_000_u32.into());
-	}: _(RawOrigin::Root, origin.clone())
+	set_mint_policy {
+		let origin = T::AdminOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let mint_policy = MintPolicy::Open { price: T::Currency::minimum_balance() };
+	}: _<T::RuntimeOrigin>(origin, mint_policy.clone())
 	verify {
-		assert_eq!(Pallet::<T>::organizer(), Some(origin))
+		assert_eq!(Pallet::<T>::mint_policy(), mint_policy);
 	}
 
 	update_config {
 		let origin: T::AccountId = whitelisted_caller();
 		T::Currency::make_free_balance_be(&origin, T::Currency::minimum_balance() * 20_000_000_u32.into());
-		Pallet::<T>::set_organizer(RawOrigin::Root.into(), origin.clone())?;
 		let expected_config: [u8; 10] = [0, 1, 0, 0, 0, 0, 0, 0, 0, 0];
 	}: _(RawOrigin::Signed(origin.clone()), 1, Some(1))
 	verify {
@@ -69,7 +117,6 @@ benchmarks! {
 	set_price {
 		let origin: T::AccountId = whitelisted_caller();
 		T::Currency::make_free_balance_be(&origin, T::Currency::minimum_balance() * 20_000_000_u32.into());
-		Pallet::<T>::set_organizer(RawOrigin::Root.into(), origin.clone())?;
 
 		Pallet::<T>::create_mogwai(RawOrigin::Signed(origin.clone()).into())?;
 		let mogwai_id = Mogwais::<T>::iter_values().next().unwrap().id;
@@ -83,7 +130,6 @@ benchmarks! {
 	remove_price {
 		let origin: T::AccountId = whitelisted_caller();
 		T::Currency::make_free_balance_be(&origin, T::Currency::minimum_balance() * 20_000_000_u32.into());
-		Pallet::<T>::set_organizer(RawOrigin::Root.into(), origin.clone())?;
 
 		Pallet::<T>::create_mogwai(RawOrigin::Signed(origin.clone()).into())?;
 		let mogwai_id = Mogwais::<T>::iter_values().next().unwrap().id;
@@ -97,7 +143,7 @@ benchmarks! {
 	create_mogwai {
 		let origin: T::AccountId = whitelisted_caller();
 		T::Currency::make_free_balance_be(&origin, T::Currency::minimum_balance() * 20_000_000_u32.into());
-		Pallet::<T>::set_organizer(RawOrigin::Root.into(), origin.clone())?;
+		CurrentMintPolicy::<T>::put(MintPolicy::Open { price: T::Currency::minimum_balance() });
 	}: _(RawOrigin::Signed(origin.clone()))
 	verify {
 		assert_eq!(Pallet::<T>::owned_mogwais_count(origin), 1_u64);
@@ -106,11 +152,12 @@ benchmarks! {
 	remove_mogwai {
 		let origin: T::AccountId = whitelisted_caller();
 		T::Currency::make_free_balance_be(&origin, T::Currency::minimum_balance() * 20_000_000_u32.into());
-		Pallet::<T>::set_organizer(RawOrigin::Root.into(), origin.clone())?;
 
 		Pallet::<T>::create_mogwai(RawOrigin::Signed(origin.clone()).into())?;
 		let mogwai_id = Mogwais::<T>::iter_values().next().unwrap().id;
-	}: _(RawOrigin::Signed(origin), mogwai_id)
+		let admin = T::AdminOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(admin, mogwai_id)
 	verify {
 		assert_eq!(Pallet::<T>::all_mogwais_count(), 0_u64);
 	}
@@ -118,13 +165,14 @@ benchmarks! {
 	transfer {
 		let origin_1: T::AccountId = whitelisted_caller();
 		T::Currency::make_free_balance_be(&origin_1, T::Currency::minimum_balance() * 20_000_000_u32.into());
-		Pallet::<T>::set_organizer(RawOrigin::Root.into(), origin_1.clone())?;
 		let origin_2: T::AccountId = account::<T>("origin_2");
 		whitelist_account!(origin_2);
 
 		Pallet::<T>::create_mogwai(RawOrigin::Signed(origin_1.clone()).into())?;
 		let mogwai_id = Mogwais::<T>::iter_values().next().unwrap().id;
-	}: _(RawOrigin::Signed(origin_1.clone()), origin_2.clone(), mogwai_id)
+		let admin = T::AdminOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(admin, origin_2.clone(), mogwai_id)
 	verify {
 		assert_eq!(Pallet::<T>::owned_mogwais_count(origin_1), 0_u64);
 		assert_eq!(Pallet::<T>::owned_mogwais_count(origin_2), 1_u64);
@@ -133,7 +181,6 @@ benchmarks! {
 	hatch_mogwai {
 		let origin: T::AccountId = whitelisted_caller();
 		T::Currency::make_free_balance_be(&origin, T::Currency::minimum_balance() * 20_000_000_u32.into());
-		Pallet::<T>::set_organizer(RawOrigin::Root.into(), origin.clone())?;
 
 		Pallet::<T>::create_mogwai(RawOrigin::Signed(origin.clone()).into())?;
 		let mogwai_id = Mogwais::<T>::iter_values().next().unwrap().id;
@@ -147,7 +194,6 @@ benchmarks! {
 	sacrifice {
 		let origin: T::AccountId = whitelisted_caller();
 		T::Currency::make_free_balance_be(&origin, T::Currency::minimum_balance() * 20_000_000_u32.into());
-		Pallet::<T>::set_organizer(RawOrigin::Root.into(), origin.clone())?;
 
 		Pallet::<T>::create_mogwai(RawOrigin::Signed(origin.clone()).into())?;
 		let mogwai_id = Mogwais::<T>::iter_values().next().unwrap().id;
@@ -160,7 +206,6 @@ benchmarks! {
 	sacrifice_into {
 		let origin: T::AccountId = whitelisted_caller();
 		T::Currency::make_free_balance_be(&origin, T::Currency::minimum_balance() * 20_000_000_u32.into());
-		Pallet::<T>::set_organizer(RawOrigin::Root.into(), origin.clone())?;
 
 		Pallet::<T>::create_mogwai(RawOrigin::Signed(origin.clone()).into())?;
 		Pallet::<T>::create_mogwai(RawOrigin::Signed(origin.clone()).into())?;
@@ -180,7 +225,6 @@ benchmarks! {
 	buy_mogwai {
 		let origin_1: T::AccountId = whitelisted_caller();
 		T::Currency::make_free_balance_be(&origin_1, T::Currency::minimum_balance() * 20_000_000_u32.into());
-		Pallet::<T>::set_organizer(RawOrigin::Root.into(), origin_1.clone())?;
 		let origin_2: T::AccountId = account::<T>("origin_2");
 		whitelist_account!(origin_2);
 
@@ -198,7 +242,6 @@ benchmarks! {
 	morph_mogwai {
 		let origin: T::AccountId = whitelisted_caller();
 		T::Currency::make_free_balance_be(&origin, T::Currency::minimum_balance() * 10_000_000_000_u64.unique_saturated_into());
-		Pallet::<T>::set_organizer(RawOrigin::Root.into(), origin.clone())?;
 
 		Pallet::<T>::create_mogwai(RawOrigin::Signed(origin.clone()).into())?;
 		let mogwai_id = Mogwais::<T>::iter_values().next().unwrap().id;
@@ -208,7 +251,6 @@ benchmarks! {
 	breed_mogwai {
 		let origin: T::AccountId = whitelisted_caller();
 		T::Currency::make_free_balance_be(&origin, T::Currency::minimum_balance() * 10_000_000_000_u64.unique_saturated_into());
-		Pallet::<T>::set_organizer(RawOrigin::Root.into(), origin.clone())?;
 
 		Pallet::<T>::create_mogwai(RawOrigin::Signed(origin.clone()).into())?;
 		Pallet::<T>::create_mogwai(RawOrigin::Signed(origin.clone()).into())?;
@@ -222,5 +264,208 @@ benchmarks! {
 		assert_eq!(Pallet::<T>::all_mogwais_count(), 3_u64);
 	}
 
+	claim_achievement {
+		let origin: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&origin, T::Currency::minimum_balance() * 20_000_000_u32.into());
+
+		let achievement = AccountAchievement::EggHatcher;
+		AccountAchievements::<T>::insert(&origin, achievement, AchievementState::Completed);
+	}: _(RawOrigin::Signed(origin.clone()), achievement)
+	verify {
+		assert!(Pallet::<T>::claimed_achievements(origin, achievement).is_some());
+	}
+
+	offer_lending {
+		let origin: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&origin, T::Currency::minimum_balance() * 20_000_000_u32.into());
+		let borrower: T::AccountId = account::<T>("borrower");
+
+		Pallet::<T>::create_mogwai(RawOrigin::Signed(origin.clone()).into())?;
+		let mogwai_id = Mogwais::<T>::iter_values().next().unwrap().id;
+		let terms = lending_terms::<T>(borrower);
+	}: _(RawOrigin::Signed(origin), mogwai_id, terms.clone())
+	verify {
+		assert_eq!(Pallet::<T>::lending_offers(mogwai_id), Some(terms));
+	}
+
+	cancel_lending_offer {
+		let origin: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&origin, T::Currency::minimum_balance() * 20_000_000_u32.into());
+		let borrower: T::AccountId = account::<T>("borrower");
+
+		Pallet::<T>::create_mogwai(RawOrigin::Signed(origin.clone()).into())?;
+		let mogwai_id = Mogwais::<T>::iter_values().next().unwrap().id;
+		Pallet::<T>::offer_lending(RawOrigin::Signed(origin.clone()).into(), mogwai_id, lending_terms::<T>(borrower))?;
+	}: _(RawOrigin::Signed(origin), mogwai_id)
+	verify {
+		assert_eq!(Pallet::<T>::lending_offers(mogwai_id), None);
+	}
+
+	borrow_mogwai {
+		let origin: T::AccountId = account::<T>("lender");
+		let borrower: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&borrower, T::Currency::minimum_balance() * 20_000_000_u32.into());
+
+		Pallet::<T>::create_mogwai(RawOrigin::Signed(origin.clone()).into())?;
+		let mogwai_id = Mogwais::<T>::iter_values().next().unwrap().id;
+		Pallet::<T>::offer_lending(RawOrigin::Signed(origin).into(), mogwai_id, lending_terms::<T>(borrower.clone()))?;
+	}: _(RawOrigin::Signed(borrower), mogwai_id)
+	verify {
+		assert!(Pallet::<T>::lendings(mogwai_id).is_some());
+	}
+
+	return_mogwai {
+		let origin: T::AccountId = account::<T>("lender");
+		let borrower: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&borrower, T::Currency::minimum_balance() * 20_000_000_u32.into());
+
+		Pallet::<T>::create_mogwai(RawOrigin::Signed(origin.clone()).into())?;
+		let mogwai_id = Mogwais::<T>::iter_values().next().unwrap().id;
+		Pallet::<T>::offer_lending(RawOrigin::Signed(origin).into(), mogwai_id, lending_terms::<T>(borrower.clone()))?;
+		Pallet::<T>::borrow_mogwai(RawOrigin::Signed(borrower.clone()).into(), mogwai_id)?;
+	}: _(RawOrigin::Signed(borrower), mogwai_id)
+	verify {
+		assert_eq!(Pallet::<T>::lendings(mogwai_id), None);
+	}
+
+	hatch_mogwais {
+		let n in 1 .. T::MaxBatchSize::get();
+
+		let origin: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&origin, T::Currency::minimum_balance() * 20_000_000_u32.into());
+
+		let mogwai_ids = create_mogwais::<T>(&origin, n)?;
+		frame_system::Pallet::<T>::set_block_number(1000_u32.into());
+	}: _(RawOrigin::Signed(origin), BoundedVec::truncate_from(mogwai_ids.clone()))
+	verify {
+		for mogwai_id in mogwai_ids {
+			assert_eq!(Pallet::<T>::mogwai(mogwai_id).unwrap().phase, PhaseType::Hatched);
+		}
+	}
+
+	set_prices {
+		let n in 1 .. T::MaxBatchSize::get();
+
+		let origin: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&origin, T::Currency::minimum_balance() * 20_000_000_u32.into());
+
+		let mogwai_ids = create_mogwais::<T>(&origin, n)?;
+		let price: BalanceOf<T> = 1000_u32.into();
+		let prices = mogwai_ids.iter().map(|mogwai_id| (*mogwai_id, price)).collect::<Vec<_>>();
+	}: _(RawOrigin::Signed(origin), BoundedVec::truncate_from(prices))
+	verify {
+		for mogwai_id in mogwai_ids {
+			assert_eq!(Pallet::<T>::mogwai_prices(mogwai_id), Some(price));
+		}
+	}
+
+	remove_prices {
+		let n in 1 .. T::MaxBatchSize::get();
+
+		let origin: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&origin, T::Currency::minimum_balance() * 20_000_000_u32.into());
+
+		let mogwai_ids = create_mogwais::<T>(&origin, n)?;
+		for mogwai_id in &mogwai_ids {
+			Pallet::<T>::set_price(RawOrigin::Signed(origin.clone()).into(), *mogwai_id, 1000_u32.into())?;
+		}
+	}: _(RawOrigin::Signed(origin), BoundedVec::truncate_from(mogwai_ids.clone()))
+	verify {
+		for mogwai_id in mogwai_ids {
+			assert_eq!(Pallet::<T>::mogwai_prices(mogwai_id), None);
+		}
+	}
+
+	sacrifice_mogwais {
+		let n in 1 .. T::MaxBatchSize::get();
+
+		let origin: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&origin, T::Currency::minimum_balance() * 20_000_000_u32.into());
+
+		let mogwai_ids = create_mogwais::<T>(&origin, n)?;
+		for mogwai_id in &mogwai_ids {
+			force_hatch_mogwai::<T>(mogwai_id);
+		}
+	}: _(RawOrigin::Signed(origin.clone()), BoundedVec::truncate_from(mogwai_ids))
+	verify {
+		assert_eq!(Pallet::<T>::owned_mogwais_count(origin), 0_u64);
+	}
+
+	make_offer {
+		let seller: T::AccountId = account::<T>("seller");
+		let buyer: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&buyer, T::Currency::minimum_balance() * 20_000_000_u32.into());
+
+		let (mogwai_id, terms) = trade::<T>(&seller, &buyer)?;
+	}: _(RawOrigin::Signed(buyer), mogwai_id, terms)
+	verify {
+		assert_eq!(Pallet::<T>::trade_offers(mogwai_id).len(), 1);
+	}
+
+	withdraw_offer {
+		let seller: T::AccountId = account::<T>("seller");
+		let buyer: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&buyer, T::Currency::minimum_balance() * 20_000_000_u32.into());
+
+		let (mogwai_id, terms) = trade::<T>(&seller, &buyer)?;
+		Pallet::<T>::make_offer(RawOrigin::Signed(buyer.clone()).into(), mogwai_id, terms)?;
+	}: _(RawOrigin::Signed(buyer), mogwai_id)
+	verify {
+		assert!(Pallet::<T>::trade_offers(mogwai_id).is_empty());
+	}
+
+	reject_offer {
+		let seller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&seller, T::Currency::minimum_balance() * 20_000_000_u32.into());
+		let buyer: T::AccountId = account::<T>("buyer");
+
+		let (mogwai_id, terms) = trade::<T>(&seller, &buyer)?;
+		Pallet::<T>::make_offer(RawOrigin::Signed(buyer.clone()).into(), mogwai_id, terms)?;
+	}: _(RawOrigin::Signed(seller), mogwai_id, buyer)
+	verify {
+		assert!(Pallet::<T>::trade_offers(mogwai_id).is_empty());
+	}
+
+	counter_offer {
+		let seller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&seller, T::Currency::minimum_balance() * 20_000_000_u32.into());
+		let buyer: T::AccountId = account::<T>("buyer");
+
+		let (mogwai_id, terms) = trade::<T>(&seller, &buyer)?;
+		Pallet::<T>::make_offer(RawOrigin::Signed(buyer.clone()).into(), mogwai_id, terms.clone())?;
+	}: _(RawOrigin::Signed(seller), mogwai_id, buyer, terms)
+	verify {
+		assert!(Pallet::<T>::trade_offers(mogwai_id)[0].counter.is_some());
+	}
+
+	accept_offer {
+		let seller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&seller, T::Currency::minimum_balance() * 20_000_000_u32.into());
+		let buyer: T::AccountId = account::<T>("buyer");
+
+		let (mogwai_id, terms) = trade::<T>(&seller, &buyer)?;
+		competing_offers::<T>(mogwai_id)?;
+		Pallet::<T>::make_offer(RawOrigin::Signed(buyer.clone()).into(), mogwai_id, terms)?;
+	}: _(RawOrigin::Signed(seller), mogwai_id, buyer.clone())
+	verify {
+		assert_eq!(Pallet::<T>::mogwai(mogwai_id).unwrap().owner, buyer);
+		assert!(Pallet::<T>::trade_offers(mogwai_id).is_empty());
+	}
+
+	accept_counter_offer {
+		let seller: T::AccountId = account::<T>("seller");
+		let buyer: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&buyer, T::Currency::minimum_balance() * 20_000_000_u32.into());
+
+		let (mogwai_id, terms) = trade::<T>(&seller, &buyer)?;
+		competing_offers::<T>(mogwai_id)?;
+		Pallet::<T>::make_offer(RawOrigin::Signed(buyer.clone()).into(), mogwai_id, terms.clone())?;
+		let counter = TradeTerms { amount: terms.amount * 2_u32.into(), mogwais: terms.mogwais };
+		Pallet::<T>::counter_offer(RawOrigin::Signed(seller).into(), mogwai_id, buyer.clone(), counter)?;
+	}: _(RawOrigin::Signed(buyer.clone()), mogwai_id)
+	verify {
+		assert_eq!(Pallet::<T>::mogwai(mogwai_id).unwrap().owner, buyer);
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::ExtBuilder.build(), crate::mock::Test);
 }
@@ -0,0 +1,96 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runs the battle-mogs breeding simulator and writes its report as CSV or JSON.
+//!
+//! ```text
+//! cargo run -p pallet-ajuna-battle-mogs --features simulator --bin mogwai-sim -- \
+//!     --seed 7 --trials 20 --rounds 500 --format csv --output report.csv
+//! ```
+
+use pallet_ajuna_battle_mogs::simulator::{simulate, SimulationConfig};
+use std::{env, fs, process};
+
+const USAGE: &str = "usage: mogwai-sim [--seed N] [--trials N] [--population N] \
+	[--max-population N] [--rounds N] [--breeds N] [--format csv|json] [--output FILE]";
+
+enum Format {
+	Csv,
+	Json,
+}
+
+fn parse<T: std::str::FromStr>(flag: &str, value: Option<String>) -> Result<T, String> {
+	let value = value.ok_or_else(|| format!("missing value for {}", flag))?;
+	value.parse().map_err(|_| format!("invalid value for {}: {}", flag, value))
+}
+
+fn run() -> Result<(), String> {
+	let mut config = SimulationConfig::default();
+	let mut format = Format::Csv;
+	let mut output = None;
+
+	let mut args = env::args().skip(1);
+	while let Some(flag) = args.next() {
+		match flag.as_str() {
+			"--seed" => config.seed = parse(&flag, args.next())?,
+			"--trials" => config.trials = parse(&flag, args.next())?,
+			"--population" => config.initial_population = parse(&flag, args.next())?,
+			"--max-population" => config.max_population = parse(&flag, args.next())?,
+			"--rounds" => config.rounds = parse(&flag, args.next())?,
+			"--breeds" => config.breeds_per_round = parse(&flag, args.next())?,
+			"--format" =>
+				format = match parse::<String>(&flag, args.next())?.as_str() {
+					"csv" => Format::Csv,
+					"json" => Format::Json,
+					other => return Err(format!("unknown format: {}", other)),
+				},
+			"--output" => output = Some(parse::<String>(&flag, args.next())?),
+			"--help" | "-h" => {
+				println!("{}", USAGE);
+				return Ok(())
+			},
+			other => return Err(format!("unknown argument: {}", other)),
+		}
+	}
+
+	let report = simulate(&config);
+	let rendered = match format {
+		Format::Csv => report.to_csv(),
+		Format::Json => report.to_json(),
+	};
+
+	match output {
+		Some(path) => fs::write(&path, rendered).map_err(|e| format!("{}: {}", path, e))?,
+		None => print!("{}", rendered),
+	}
+
+	eprintln!(
+		"mythical reached in {}/{} trials, expected round {:?}, expected pairing cost {:?}",
+		report.mythical_reached,
+		config.trials,
+		report.expected_mythical_round,
+		report.expected_mythical_cost,
+	);
+
+	Ok(())
+}
+
+fn main() {
+	if let Err(error) = run() {
+		eprintln!("{}\n{}", error, USAGE);
+		process::exit(1);
+	}
+}
@@ -0,0 +1,159 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! The DNA of a mogwai, made of a stats and a visuals segment.
+
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::{Type, TypeInfo};
+
+/// Length in bytes of a DNA segment.
+pub const SEGMENT_LEN: usize = 32;
+/// Length in bytes of half a DNA segment.
+pub const HALF_SEGMENT_LEN: usize = SEGMENT_LEN / 2;
+
+/// A DNA segment.
+pub type Segment = [u8; SEGMENT_LEN];
+/// One half of a DNA segment.
+pub type HalfSegment = [u8; HALF_SEGMENT_LEN];
+
+/// Copy `N` bytes starting at `offset` out of `bytes`, if there are enough of them.
+pub fn array_at<const N: usize>(bytes: &[u8], offset: usize) -> Option<[u8; N]> {
+	bytes.get(offset..offset.checked_add(N)?)?.try_into().ok()
+}
+
+/// The DNA of a mogwai.
+///
+/// Encodes and describes itself exactly like the `[[u8; 32]; 2]` it wraps, so that stored
+/// mogwais and metadata are unaffected by its introduction.
+#[derive(Encode, Decode, Debug, Default, Copy, Clone, PartialEq, Eq, MaxEncodedLen)]
+pub struct Dna([Segment; 2]);
+
+impl TypeInfo for Dna {
+	type Identity = [Segment; 2];
+
+	fn type_info() -> Type {
+		<[Segment; 2]>::type_info()
+	}
+}
+
+impl Dna {
+	pub const fn new(stats: Segment, visuals: Segment) -> Self {
+		Self([stats, visuals])
+	}
+
+	/// Take a segment out of a hash, shorter hashes are padded with zeros.
+	pub fn segment_from_hash(hash: &[u8]) -> Segment {
+		let mut segment = Segment::default();
+		let len = hash.len().min(SEGMENT_LEN);
+		segment[..len].copy_from_slice(&hash[..len]);
+		segment
+	}
+
+	/// The segment determining the stats of a mogwai.
+	pub fn stats(&self) -> &Segment {
+		&self.0[0]
+	}
+
+	/// The segment determining the looks of a mogwai.
+	pub fn visuals(&self) -> &Segment {
+		&self.0[1]
+	}
+
+	pub fn set_stats(&mut self, stats: Segment) {
+		self.0[0] = stats;
+	}
+
+	/// The dominant and recessive halves of the stats segment.
+	pub fn stats_halves(&self) -> (HalfSegment, HalfSegment) {
+		Self::halves(self.stats())
+	}
+
+	/// The dominant and recessive halves of the visuals segment.
+	pub fn visuals_halves(&self) -> (HalfSegment, HalfSegment) {
+		Self::halves(self.visuals())
+	}
+
+	fn halves(segment: &Segment) -> (HalfSegment, HalfSegment) {
+		let mut left = HalfSegment::default();
+		let mut right = HalfSegment::default();
+		left.copy_from_slice(&segment[..HALF_SEGMENT_LEN]);
+		right.copy_from_slice(&segment[HALF_SEGMENT_LEN..]);
+		(left, right)
+	}
+
+	pub fn into_inner(self) -> [Segment; 2] {
+		self.0
+	}
+}
+
+impl From<[Segment; 2]> for Dna {
+	fn from(segments: [Segment; 2]) -> Self {
+		Self(segments)
+	}
+}
+
+impl From<Dna> for [Segment; 2] {
+	fn from(dna: Dna) -> Self {
+		dna.0
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use proptest::prelude::*;
+
+	proptest! {
+		#[test]
+		fn encoding_matches_raw_segments(stats in any::<Segment>(), visuals in any::<Segment>()) {
+			let raw = [stats, visuals];
+			let dna = Dna::from(raw);
+
+			prop_assert_eq!(dna.encode(), raw.encode());
+			prop_assert_eq!(Dna::decode(&mut &raw.encode()[..]).unwrap(), dna);
+			prop_assert_eq!(dna.stats(), &stats);
+			prop_assert_eq!(dna.visuals(), &visuals);
+
+			let (left, right) = dna.stats_halves();
+			prop_assert_eq!([left, right].concat(), stats.to_vec());
+		}
+
+		#[test]
+		fn array_at_never_reads_out_of_bounds(bytes in any::<Vec<u8>>(), offset in 0..64_usize) {
+			let array = array_at::<6>(&bytes, offset);
+
+			prop_assert_eq!(array.is_some(), offset + 6 <= bytes.len());
+			if let Some(array) = array {
+				prop_assert_eq!(&array[..], &bytes[offset..offset + 6]);
+			}
+		}
+	}
+
+	#[test]
+	fn type_info_matches_raw_segments() {
+		assert_eq!(Dna::type_info(), <[Segment; 2]>::type_info());
+		assert_eq!(Dna::max_encoded_len(), 64);
+	}
+
+	#[test]
+	fn segment_from_hash_pads_short_hashes() {
+		assert_eq!(Dna::segment_from_hash(&[1; 40]), [1; 32]);
+
+		let mut expected = [0; 32];
+		expected[..4].copy_from_slice(&[7; 4]);
+		assert_eq!(Dna::segment_from_hash(&[7; 4]), expected);
+	}
+}
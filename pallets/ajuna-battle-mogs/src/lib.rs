@@ -21,14 +21,19 @@ pub use pallet::*;
 use frame_support::{
 	ensure,
 	pallet_prelude::*,
-	traits::{Currency, ExistenceRequirement, Randomness, ReservableCurrency, WithdrawReasons},
+	storage::with_storage_layer,
+	traits::{
+		BalanceStatus, Currency, ExistenceRequirement, OnUnbalanced, Randomness,
+		ReservableCurrency, WithdrawReasons,
+	},
+	PalletId,
 };
 use frame_system::pallet_prelude::*;
 use sp_runtime::{
-	traits::{Hash, Saturating, TrailingZeroInput, Zero},
-	DispatchResult, SaturatedConversion,
+	traits::{AccountIdConversion, Convert, Hash, Saturating, TrailingZeroInput, Zero},
+	ArithmeticError, DispatchError, DispatchResult, SaturatedConversion,
 };
-use sp_std::{mem::MaybeUninit, prelude::*, ptr::copy_nonoverlapping, vec::Vec};
+use sp_std::{prelude::*, vec::Vec};
 
 #[cfg(test)]
 mod mock;
@@ -39,17 +44,26 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
-mod migration;
+pub mod migration;
 
 mod algorithm;
+mod dna;
+pub mod nft;
+#[cfg(feature = "simulator")]
+pub mod simulator;
 mod types;
 pub mod weights;
 
 pub use algorithm::*;
+pub use dna::*;
+pub use nft::*;
 pub use types::*;
 
 type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::NegativeImbalance;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -69,11 +83,26 @@ pub mod pallet {
 		PhaseType,
 		<T as frame_system::Config>::AccountId,
 	>;
+	pub(crate) type LendingTermsOf<T> =
+		LendingTerms<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
+	pub(crate) type LendingAgreementOf<T> =
+		LendingAgreement<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
 	pub(crate) type BoundedMogwaiIdsOf<T> =
 		BoundedBTreeSet<MogwaiIdOf<T>, ConstU32<MAX_MOGWAIS_PER_PLAYER>>;
 	pub(crate) type MogwaiCount = u64;
+	pub(crate) type BatchOf<T, Item> = BoundedVec<Item, <T as Config>::MaxBatchSize>;
+	pub(crate) type BatchResultsOf<T> = BatchOf<T, (MogwaiIdOf<T>, DispatchResult)>;
+	pub(crate) type OfferedMogwaisOf<T> =
+		BoundedVec<MogwaiIdOf<T>, <T as Config>::MaxOfferedMogwais>;
+	pub(crate) type TradeTermsOf<T> = TradeTerms<BalanceOf<T>, OfferedMogwaisOf<T>>;
+	pub(crate) type TradeOfferOf<T> = TradeOffer<
+		<T as frame_system::Config>::AccountId,
+		BalanceOf<T>,
+		BlockNumberFor<T>,
+		OfferedMogwaisOf<T>,
+	>;
 
-	pub(crate) const MAX_MOGWAIS_PER_PLAYER: u32 = 24;
+	pub(crate) const MAX_MOGWAIS_PER_PLAYER: u32 = GameConfig::max_mogwais();
 
 	/// Configure the pallet by specifying the parameters and types on which it depends.
 	#[pallet::config]
@@ -87,6 +116,73 @@ pub mod pallet {
 		/// Something that provides randomness in the runtime.
 		type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
 
+		/// The origin allowed to mint new mogwais while minting is restricted, the
+		/// successful origin receives the minted egg.
+		type MintOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+
+		/// The origin allowed to remove or transfer any mogwai and to set the mint policy.
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Where the fees paid to this pallet end up.
+		type FeeDestination: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// The pallet's id, used for deriving the account that pays out achievement rewards.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Minimum number of blocks a world event is announced before it starts.
+		#[pallet::constant]
+		type WorldEventNotice: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of random blocks added on top of the notice of a world event.
+		#[pallet::constant]
+		type WorldEventMaxDelay: Get<BlockNumberFor<Self>>;
+
+		/// Number of blocks a world event stays active.
+		#[pallet::constant]
+		type WorldEventDuration: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of blocks a mogwai can be lent for.
+		#[pallet::constant]
+		type MaxLendingDuration: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of lendings expiring in the same block.
+		#[pallet::constant]
+		type MaxLendingExpiriesPerBlock: Get<u32>;
+
+		/// Maximum number of mogwais handled by a single batch call.
+		#[pallet::constant]
+		type MaxBatchSize: Get<u32>;
+
+		/// Number of blocks a trade offer stays open before its amount is unreserved.
+		#[pallet::constant]
+		type OfferDuration: Get<BlockNumberFor<Self>>;
+
+		/// Maximum number of open trade offers for a single mogwai.
+		#[pallet::constant]
+		type MaxOffersPerMogwai: Get<u32>;
+
+		/// Maximum number of mogwais a buyer can include in a trade offer.
+		#[pallet::constant]
+		type MaxOfferedMogwais: Get<u32>;
+
+		/// Maximum number of trade offers expiring in the same block.
+		#[pallet::constant]
+		type MaxOfferExpiriesPerBlock: Get<u32>;
+
+		/// Maximum number of existing mogwais mirrored as NFTs in a single block.
+		#[pallet::constant]
+		type MaxNftImportsPerBlock: Get<u32>;
+
+		/// Mirrors mogwais as NFTs in a dedicated collection, `()` disables mirroring.
+		type NftHandler: MogwaiNftHandler<Self::AccountId, MogwaiOf<Self>>;
+
+		/// The reward paid out for each completed achievement, if any.
+		type AchievementRewards: Convert<
+			AccountAchievement,
+			Option<AchievementReward<BalanceOf<Self>>>,
+		>;
+
 		/// The weight information of this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -96,8 +192,9 @@ pub mod pallet {
 	pub struct Pallet<T>(_);
 
 	#[pallet::storage]
-	#[pallet::getter(fn organizer)]
-	pub type Organizer<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
+	#[pallet::getter(fn mint_policy)]
+	/// Who can mint new mogwais, and at which price.
+	pub type CurrentMintPolicy<T: Config> = StorageValue<_, MintPolicy<BalanceOf<T>>, ValueQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn account_config)]
@@ -117,16 +214,88 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn claimed_achievements)]
+	/// A map of the achievements an account has claimed, and the block it claimed them at.
+	pub type ClaimedAchievements<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		T::AccountId,
+		Identity,
+		AccountAchievement,
+		BlockNumberFor<T>,
+		OptionQuery,
+	>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn mogwai)]
 	/// A map of mogwais accessible by the mogwai hash.
 	pub type Mogwais<T: Config> = StorageMap<_, Identity, MogwaiIdOf<T>, MogwaiOf<T>, OptionQuery>;
+
+	/// The NFT item mirroring each mogwai, only populated while mirroring is enabled.
+	#[pallet::storage]
+	#[pallet::getter(fn mogwai_nft_item)]
+	pub type MogwaiNftItems<T: Config> =
+		StorageMap<_, Identity, MogwaiIdOf<T>, NftItemId, OptionQuery>;
+
+	/// The item id used for the next mirrored mogwai.
+	#[pallet::storage]
+	pub type NextNftItemId<T: Config> = StorageValue<_, NftItemId, ValueQuery>;
+
+	/// The progress of mirroring the mogwais created before mirroring was enabled, a few
+	/// mogwais are imported each block until all of them are mirrored.
+	#[pallet::storage]
+	pub type NftImport<T: Config> = StorageValue<_, NftImportProgress<MogwaiIdOf<T>>, OptionQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn mogwai_prices)]
 	/// A map of mogwais that are up for sale.
 	pub type MogwaiPrices<T: Config> =
 		StorageMap<_, Identity, MogwaiIdOf<T>, BalanceOf<T>, OptionQuery>;
 
+	/// Lending offers made by mogwai owners, waiting to be accepted by the borrower.
+	#[pallet::storage]
+	#[pallet::getter(fn lending_offers)]
+	pub type LendingOffers<T: Config> =
+		StorageMap<_, Identity, MogwaiIdOf<T>, LendingTermsOf<T>, OptionQuery>;
+
+	/// Mogwais currently lent to a borrower.
+	#[pallet::storage]
+	#[pallet::getter(fn lendings)]
+	pub type Lendings<T: Config> =
+		StorageMap<_, Identity, MogwaiIdOf<T>, LendingAgreementOf<T>, OptionQuery>;
+
+	/// The lent mogwais to return to their lenders at each block.
+	#[pallet::storage]
+	pub type LendingExpiries<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<MogwaiIdOf<T>, T::MaxLendingExpiriesPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Open trade offers for each mogwai, at most one per buyer.
+	#[pallet::storage]
+	#[pallet::getter(fn trade_offers)]
+	pub type TradeOffers<T: Config> = StorageMap<
+		_,
+		Identity,
+		MogwaiIdOf<T>,
+		BoundedVec<TradeOfferOf<T>, T::MaxOffersPerMogwai>,
+		ValueQuery,
+	>;
+
+	/// The trade offers to unreserve at each block, by mogwai and buyer.
+	#[pallet::storage]
+	pub type TradeOfferExpiries<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<(MogwaiIdOf<T>, T::AccountId), T::MaxOfferExpiriesPerBlock>,
+		ValueQuery,
+	>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn all_mogwais_count)]
 	/// A count over all existing mogwais in the system.
@@ -152,11 +321,36 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type Nonce<T: Config> = StorageValue<_, u64, ValueQuery, NonceDefault<T>>;
 
+	/// The next world event, announced ahead of its start.
+	#[pallet::storage]
+	#[pallet::getter(fn upcoming_world_event)]
+	pub type UpcomingWorldEvent<T: Config> =
+		StorageValue<_, WorldEvent<BlockNumberFor<T>>, OptionQuery>;
+
+	/// The world event currently affecting the game, if any.
+	#[pallet::storage]
+	#[pallet::getter(fn active_world_event)]
+	pub type ActiveWorldEvent<T: Config> =
+		StorageValue<_, WorldEvent<BlockNumberFor<T>>, OptionQuery>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		pub mint_policy: MintPolicy<BalanceOf<T>>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			CurrentMintPolicy::<T>::put(self.mint_policy.clone());
+		}
+	}
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		/// A new organizer has been set.
-		OrganizerSet(T::AccountId),
+		/// A new mint policy has been set.
+		MintPolicySet(MintPolicy<BalanceOf<T>>),
 
 		/// A account configuration has been changed.
 		AccountConfigChanged(T::AccountId, [u8; GameConfig::PARAM_COUNT]),
@@ -167,13 +361,13 @@ pub mod pallet {
 		/// A price has been unset for a mogwai.
 		RemovedFromSale(T::AccountId, T::Hash),
 
-		/// A mogwai was created, by the Emperor himself!.
+		/// A mogwai was created.
 		MogwaiCreated(T::AccountId, T::Hash),
 
-		/// A mogwai was removed, by the Emperor himself!
+		/// A mogwai was removed from its owner, by the Emperor himself!
 		MogwaiRemoved(T::AccountId, T::Hash),
 
-		/// A mogwai was transfered, by the Emperor himself!
+		/// A mogwai was transfered from its owner, by the Emperor himself!
 		MogwaiTransfered(T::AccountId, T::AccountId, T::Hash),
 
 		/// A mogwai has been bought.
@@ -193,13 +387,61 @@ pub mod pallet {
 
 		/// A mogwai has been bred.
 		MogwaiBred(T::Hash),
+
+		/// An account has completed an achievement.
+		AchievementCompleted(T::AccountId, AccountAchievement),
+
+		/// An account has claimed the reward of a completed achievement.
+		AchievementClaimed(T::AccountId, AccountAchievement),
+
+		/// A mogwai owner offered to lend a mogwai.
+		LendingOffered(T::AccountId, T::Hash, LendingTermsOf<T>),
+
+		/// A lending offer has been withdrawn.
+		LendingOfferCanceled(T::AccountId, T::Hash),
+
+		/// A mogwai has been lent, it returns to the lender at the given block.
+		MogwaiLent(T::AccountId, T::AccountId, T::Hash, BlockNumberFor<T>),
+
+		/// A lent mogwai has returned to its lender.
+		MogwaiReturned(T::AccountId, T::AccountId, T::Hash),
+
+		/// A world event has been announced.
+		WorldEventScheduled(WorldEvent<BlockNumberFor<T>>),
+
+		/// A world event has started.
+		WorldEventStarted(WorldEventKind),
+
+		/// A world event has ended.
+		WorldEventEnded(WorldEventKind),
+
+		/// A batch call has been executed, with the result for each of its mogwais.
+		BatchExecuted(T::AccountId, BatchAction, BatchResultsOf<T>),
+
+		/// A buyer made an offer for a mogwai.
+		OfferMade(T::AccountId, T::Hash, TradeTermsOf<T>),
+
+		/// A buyer withdrew an offer.
+		OfferWithdrawn(T::AccountId, T::Hash),
+
+		/// The owner of a mogwai rejected the offer of a buyer.
+		OfferRejected(T::AccountId, T::AccountId, T::Hash),
+
+		/// The owner of a mogwai answered the offer of a buyer with other terms.
+		OfferCountered(T::AccountId, T::AccountId, T::Hash, TradeTermsOf<T>),
+
+		/// A mogwai has been traded from its owner to a buyer on the given terms.
+		OfferAccepted(T::AccountId, T::AccountId, T::Hash, TradeTermsOf<T>),
+
+		/// An offer has expired and its amount has been unreserved.
+		OfferExpired(T::AccountId, T::Hash),
+
+		/// An offer has been dropped because its mogwai changed or was removed.
+		OfferInvalidated(T::AccountId, T::Hash),
 	}
 
 	#[pallet::error]
 	pub enum Error<T> {
-		/// No organizer set.
-		NoOrganizer,
-
 		/// The submitted index is out of range.
 		ConfigIndexOutOfRange,
 
@@ -209,9 +451,6 @@ pub mod pallet {
 		/// Price for config updated not set.
 		PriceInvalid,
 
-		/// Founder action only.
-		FounderAction,
-
 		/// Mogwai is not for sale
 		MogwaiNotForSale,
 
@@ -244,24 +483,71 @@ pub mod pallet {
 
 		/// The specified mogwai sells for more than what the sender wants to pay.
 		MogwaiNotAffordable,
-	}
 
-	#[pallet::call]
-	impl<T: Config> Pallet<T> {
-		/// Set organizer, this is a sudo call.
-		#[pallet::call_index(0)]
-		#[pallet::weight(T::WeightInfo::set_organizer())]
-		pub fn set_organizer(origin: OriginFor<T>, organizer: T::AccountId) -> DispatchResult {
-			ensure_root(origin)?;
+		/// The achievement hasn't been completed yet.
+		AchievementNotCompleted,
 
-			Organizer::<T>::put(&organizer);
+		/// The achievement has already been claimed.
+		AchievementAlreadyClaimed,
 
-			// Emit an event.
-			Self::deposit_event(Event::OrganizerSet(organizer));
+		/// There is no reward configured for this achievement.
+		AchievementNoReward,
 
-			Ok(())
+		/// The mogwai is lent out, its owner can't use it until it returns.
+		MogwaiLent,
+
+		/// The mogwai is not lent out.
+		MogwaiNotLent,
+
+		/// The borrower isn't permitted to perform this action with the lent mogwai.
+		LendingActionNotPermitted,
+
+		/// There is no lending offer for this mogwai.
+		LendingOfferNotFound,
+
+		/// The lending offer was made to another account.
+		NotTheBorrower,
+
+		/// The lending duration is zero or above the maximum.
+		LendingDurationInvalid,
+
+		/// Too many lendings expire in the same block already.
+		TooManyLendingExpiries,
+
+		/// A batch call needs at least one mogwai.
+		BatchEmpty,
+
+		/// An offer needs an amount or at least one mogwai.
+		OfferEmpty,
+
+		/// The buyer already made an offer for this mogwai.
+		OfferAlreadyMade,
+
+		/// There is no offer of this buyer for this mogwai.
+		OfferNotFound,
+
+		/// The offer hasn't been countered by the owner.
+		CounterOfferNotFound,
+
+		/// The mogwai has reached the maximum number of open offers.
+		TooManyOffers,
+
+		/// Too many offers expire in the same block already.
+		TooManyOfferExpiries,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			Self::progress_world_events(now)
+				.saturating_add(Self::return_expired_lendings(now))
+				.saturating_add(Self::expire_trade_offers(now))
+				.saturating_add(Self::import_nfts())
 		}
+	}
 
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
 		/// Update configuration of this sender
 		#[pallet::call_index(1)]
 		#[pallet::weight(T::WeightInfo::update_config())]
@@ -272,38 +558,20 @@ pub mod pallet {
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 
-			ensure!(
-				usize::from(index) < GameConfig::PARAM_COUNT,
-				Error::<T>::ConfigIndexOutOfRange
-			);
-
-			let mut game_config = GameConfig::new();
-
-			if let Some(config) = AccountConfig::<T>::get(&sender) {
-				game_config.parameters = config;
-			}
-
-			// TODO: add rules (min, max) for different configurations
-			let update_value: u8 = GameConfig::verify_update(
-				index,
-				game_config.parameters[usize::from(index)],
-				value_opt,
-			);
-			ensure!(update_value > 0, Error::<T>::ConfigUpdateInvalid);
+			let parameters = Self::upgrade_config(&sender, index, value_opt)?;
 
-			let price = Pricing::config_update_price(index, update_value);
+			let price = GameConfig::rule(index)
+				.map(|rule| rule.update_price(parameters[usize::from(index)]))
+				.unwrap_or_default();
 			ensure!(price > 0, Error::<T>::PriceInvalid);
 
-			let organizer = Self::organizer().ok_or(Error::<T>::NoOrganizer)?;
-			Self::pay_founder(sender.clone(), organizer, price.saturated_into())?;
-
-			game_config.parameters[usize::from(index)] = update_value;
+			Self::pay_fee(&sender, price.saturated_into())?;
 
 			// updating to the new configuration
-			AccountConfig::<T>::insert(&sender, game_config.parameters);
+			AccountConfig::<T>::insert(&sender, parameters);
 
 			// Emit an event.
-			Self::deposit_event(Event::AccountConfigChanged(sender, game_config.parameters));
+			Self::deposit_event(Event::AccountConfigChanged(sender, parameters));
 
 			Ok(())
 		}
@@ -317,14 +585,7 @@ pub mod pallet {
 			new_price: BalanceOf<T>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-			let mogwai: MogwaiOf<T> =
-				Self::mogwai(mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
-			ensure!(mogwai.owner == sender, Error::<T>::MogwaiNotOwned);
-
-			MogwaiPrices::<T>::insert(mogwai_id, new_price);
-			Self::deposit_event(Event::ForSale(sender, mogwai_id, new_price));
-
-			Ok(())
+			Self::do_set_price(&sender, mogwai_id, new_price)
 		}
 
 		/// Clear previously set mogwai price.
@@ -332,86 +593,55 @@ pub mod pallet {
 		#[pallet::call_index(3)]
 		pub fn remove_price(origin: OriginFor<T>, mogwai_id: MogwaiIdOf<T>) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-
-			let mogwai: MogwaiOf<T> =
-				Self::mogwai(mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
-			ensure!(mogwai.owner == sender, Error::<T>::MogwaiNotOwned);
-			ensure!(MogwaiPrices::<T>::contains_key(mogwai_id), Error::<T>::MogwaiNotForSale);
-
-			MogwaiPrices::<T>::remove(mogwai_id);
-			Self::deposit_event(Event::RemovedFromSale(sender, mogwai_id));
-
-			Ok(())
+			Self::do_remove_price(&sender, mogwai_id)
 		}
 
-		/// Create a new mogwai egg.
+		/// Create a new mogwai egg, who can do so and for what price depends on the mint policy.
 		#[pallet::call_index(4)]
 		#[pallet::weight(T::WeightInfo::create_mogwai())]
 		pub fn create_mogwai(origin: OriginFor<T>) -> DispatchResult {
-			let sender = ensure_signed(origin)?;
-
-			// TODO Add for production!
-			//ensure!(sender == Self::organizer().unwrap(), Error::<T>::FounderAction);
+			let (sender, price) = match Self::mint_policy() {
+				MintPolicy::Open { price } => (ensure_signed(origin)?, price),
+				MintPolicy::Restricted => (T::MintOrigin::ensure_origin(origin)?, Zero::zero()),
+			};
 
 			// ensure that we have enough space
 			ensure!(Self::ensure_not_max_mogwais(sender.clone()), Error::<T>::MaxMogwaisInAccount);
 
-			let random_hash_1 = Self::generate_random_hash(b"create_mogwai", sender.clone());
-			let random_hash_2 = Self::generate_random_hash(b"extend_mogwai", sender.clone());
-
-			let (rarity, next_gen, max_rarity) = Generation::next_gen(
-				MogwaiGeneration::First,
-				RarityType::Common,
-				MogwaiGeneration::First,
-				RarityType::Common,
-				random_hash_1.as_ref(),
-			);
-
-			let block_number = <frame_system::Pallet<T>>::block_number();
-			let breed_type: BreedType = Self::calculate_breedtype(block_number);
-
-			let dx =
-				unsafe { &*(&random_hash_1.as_ref()[0..32] as *const [u8] as *const [u8; 32]) };
-			let dy =
-				unsafe { &*(&random_hash_2.as_ref()[0..32] as *const [u8] as *const [u8; 32]) };
-
-			let final_dna = Breeding::pairing(breed_type, dx, dy);
+			if !price.is_zero() {
+				Self::pay_fee(&sender, price)?;
+			}
 
-			let new_mogwai = MogwaiStruct {
-				id: random_hash_1,
-				dna: final_dna,
-				genesis: block_number,
-				intrinsic: Zero::zero(),
-				generation: next_gen,
-				rarity: RarityType::from(((max_rarity as u8) << 4) + rarity as u8),
-				phase: PhaseType::Bred,
-				owner: sender.clone(),
-			};
+			let new_mogwai = Self::new_egg(&sender);
+			let mogwai_id = new_mogwai.id;
 
-			Self::mint(&sender, random_hash_1, new_mogwai)?;
+			Self::mint(&sender, mogwai_id, new_mogwai)?;
 
 			// Emit an event.
-			Self::deposit_event(Event::MogwaiCreated(sender, random_hash_1));
+			Self::deposit_event(Event::MogwaiCreated(sender, mogwai_id));
 
 			Ok(())
 		}
 
-		/// Remove a given mogwai.
+		/// Remove a given mogwai from its owner.
 		#[pallet::call_index(5)]
 		#[pallet::weight(T::WeightInfo::remove_mogwai())]
 		pub fn remove_mogwai(origin: OriginFor<T>, mogwai_id: MogwaiIdOf<T>) -> DispatchResult {
-			let sender = ensure_signed(origin)?;
-			ensure!(sender == Self::organizer().unwrap(), Error::<T>::FounderAction);
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let mogwai: MogwaiOf<T> =
+				Self::mogwai(mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
 
-			Self::remove(sender.clone(), mogwai_id)?;
+			Self::remove(mogwai.owner.clone(), mogwai_id)?;
 
 			// Emit an event.
-			Self::deposit_event(Event::MogwaiRemoved(sender, mogwai_id));
+			Self::deposit_event(Event::MogwaiRemoved(mogwai.owner, mogwai_id));
 
 			Ok(())
 		}
 
-		/// Transfer mogwai to another account. Mogwais on sale will be unlisted after transfer.
+		/// Transfer mogwai from its owner to another account. Mogwais on sale will be unlisted
+		/// after transfer.
 		#[pallet::call_index(6)]
 		#[pallet::weight(T::WeightInfo::transfer())]
 		pub fn transfer(
@@ -419,20 +649,23 @@ pub mod pallet {
 			to: T::AccountId,
 			mogwai_id: MogwaiIdOf<T>,
 		) -> DispatchResult {
-			let sender = ensure_signed(origin)?;
-			ensure!(sender == Self::organizer().unwrap(), Error::<T>::FounderAction);
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let mogwai: MogwaiOf<T> =
+				Self::mogwai(mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
+			ensure!(mogwai.owner != to, Error::<T>::MogwaiAlreadyOwned);
 
 			// ensure that we have enough space
 			ensure!(Self::ensure_not_max_mogwais(to.clone()), Error::<T>::MaxMogwaisInAccount);
 
-			Self::transfer_unchecked(sender.clone(), to.clone(), mogwai_id)?;
+			Self::transfer_unchecked(mogwai.owner.clone(), to.clone(), mogwai_id)?;
 
 			if MogwaiPrices::<T>::contains_key(mogwai_id) {
 				MogwaiPrices::<T>::remove(mogwai_id);
 			}
 
 			// Emit an event.
-			Self::deposit_event(Event::MogwaiTransfered(sender, to, mogwai_id));
+			Self::deposit_event(Event::MogwaiTransfered(mogwai.owner, to, mogwai_id));
 
 			Ok(())
 		}
@@ -442,36 +675,7 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::hatch_mogwai())]
 		pub fn hatch_mogwai(origin: OriginFor<T>, mogwai_id: MogwaiIdOf<T>) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-
-			let mut mogwai: MogwaiOf<T> =
-				Self::mogwai(mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
-			ensure!(mogwai.owner == sender, Error::<T>::MogwaiNotOwned);
-
-			let block_number = <frame_system::Pallet<T>>::block_number();
-
-			ensure!(
-				block_number - mogwai.genesis >=
-					GameEventType::time_till(GameEventType::Hatch).into(),
-				Error::<T>::MogwaiNoHatch
-			);
-
-			let block_hash = <frame_system::Pallet<T>>::block_hash(block_number);
-
-			let (dna, rarity) = Self::segment_and_bake(mogwai.clone(), block_hash);
-
-			mogwai.phase = PhaseType::Hatched;
-			mogwai.rarity = rarity;
-			mogwai.dna = dna;
-
-			Mogwais::<T>::insert(mogwai_id, mogwai);
-
-			// TODO: Do something with the result
-			let _ = Self::update_achievement_for(&sender, AccountAchievement::EggHatcher, 1);
-
-			// Emit an event.
-			Self::deposit_event(Event::MogwaiHatched(sender, mogwai_id));
-
-			Ok(())
+			Self::do_hatch(&sender, mogwai_id)
 		}
 
 		/// Sacrifice mogwai to get some currency
@@ -479,36 +683,7 @@ pub mod pallet {
 		#[pallet::weight(T::WeightInfo::sacrifice())]
 		pub fn sacrifice(origin: OriginFor<T>, mogwai_id: MogwaiIdOf<T>) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
-
-			// TODO this needs to be check, reworked and corrected, add dynasty feature !!!
-			let mogwai: MogwaiOf<T> =
-				Self::mogwai(mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
-			ensure!(mogwai.owner == sender, Error::<T>::MogwaiNotOwned);
-			ensure!(!MogwaiPrices::<T>::contains_key(mogwai_id), Error::<T>::MogwaiIsOnSale);
-			ensure!(mogwai.phase != PhaseType::Bred, Error::<T>::MogwaiNoHatch);
-
-			let intrinsic_to_deposit = {
-				let computed_intrinsic =
-					mogwai.intrinsic / Pricing::intrinsic_return(mogwai.phase).saturated_into();
-
-				let max_intrinsic =
-					BalanceOf::<T>::max_value() - T::Currency::free_balance(&sender);
-
-				sp_std::cmp::min(computed_intrinsic, max_intrinsic)
-			};
-
-			Self::remove(sender.clone(), mogwai_id)?;
-
-			// TODO check this function on return value
-			let _ = T::Currency::deposit_into_existing(&sender, intrinsic_to_deposit)?;
-
-			// TODO: Do something with the results
-			let _ = Self::update_achievement_for(&sender, AccountAchievement::Sacrificer, 1);
-
-			// Emit an event.
-			Self::deposit_event(Event::MogwaiSacrificed(sender, mogwai_id));
-
-			Ok(())
+			Self::do_sacrifice(&sender, mogwai_id)
 		}
 
 		/// Sacrifice mogwai to an other mogwai.
@@ -530,10 +705,8 @@ pub mod pallet {
 			let mut mogwai_2: MogwaiOf<T> =
 				Self::mogwai(mogwai_id_2).ok_or(Error::<T>::MogwaiDoesntExists)?;
 
-			ensure!(
-				(mogwai_1.owner == sender) && (mogwai_2.owner == sender),
-				Error::<T>::MogwaiNotOwned
-			);
+			Self::ensure_permitted(&sender, mogwai_id_1, &mogwai_1, MogwaiAction::Sacrifice)?;
+			Self::ensure_permitted(&sender, mogwai_id_2, &mogwai_2, MogwaiAction::Sacrifice)?;
 
 			ensure!(mogwai_1.rarity != RarityType::Common, Error::<T>::MogwaiBadRarity);
 			ensure!(mogwai_2.rarity != RarityType::Common, Error::<T>::MogwaiBadRarity);
@@ -557,13 +730,14 @@ pub mod pallet {
 				mogwai_2.intrinsic = mogwai_2.intrinsic.saturating_add(mogwai_1.intrinsic);
 				mogwai_2.generation =
 					MogwaiGeneration::coerce_from(mogwai_2.generation as u16 + gen_jump as u16);
+				Self::update_nft(mogwai_id_2, &mogwai_2)?;
 				Mogwais::<T>::insert(mogwai_id_2, mogwai_2);
+				Self::invalidate_offers(mogwai_id_2);
 			}
 
 			Self::remove(sender.clone(), mogwai_id_1)?;
 
-			// TODO: Do something with the results
-			let _ = Self::update_achievement_for(&sender, AccountAchievement::Sacrificer, 1);
+			Self::update_achievement_for(&sender, AccountAchievement::Sacrificer, 1);
 
 			// Emit an event.
 			Self::deposit_event(Event::MogwaiSacrificedInto(sender, mogwai_id_1, mogwai_id_2));
@@ -606,9 +780,8 @@ pub mod pallet {
 				MogwaiPrices::<T>::remove(mogwai_id);
 			}
 
-			// TODO: Do something with the results
-			let _ = Self::update_achievement_for(&sender, AccountAchievement::Buyer, 1);
-			let _ = Self::update_achievement_for(&mogwai.owner, AccountAchievement::Seller, 1);
+			Self::update_achievement_for(&sender, AccountAchievement::Buyer, 1);
+			Self::update_achievement_for(&mogwai.owner, AccountAchievement::Seller, 1);
 
 			// Emit an event.
 			Self::deposit_event(Event::MogwaiBought(sender, mogwai.owner, mogwai_id, mogwai_price));
@@ -625,13 +798,12 @@ pub mod pallet {
 			// check that the mogwai has an owner and that it is the one calling this extrinsic
 			let mut mogwai: MogwaiOf<T> =
 				Self::mogwai(mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
-			ensure!(mogwai.owner == sender, "You don't own this mogwai");
+			Self::ensure_permitted(&sender, mogwai_id, &mogwai, MogwaiAction::Morph)?;
 			ensure!(mogwai.phase != PhaseType::Bred, Error::<T>::MogwaiNoHatch);
 
 			ensure!(!MogwaiPrices::<T>::contains_key(mogwai_id), Error::<T>::MogwaiIsOnSale);
 
-			let pairing_price: BalanceOf<T> =
-				Pricing::pairing(mogwai.rarity, mogwai.rarity).saturated_into();
+			let pairing_price = Self::pairing_price(mogwai.rarity, mogwai.rarity);
 
 			Self::tip_mogwai(&sender, pairing_price, mogwai_id, &mut mogwai)?;
 
@@ -639,15 +811,13 @@ pub mod pallet {
 			let block_number = <frame_system::Pallet<T>>::block_number();
 			let breed_type: BreedType = Self::calculate_breedtype(block_number);
 
-			let dx = unsafe { &*(&mogwai.dna[0][0..16] as *const [u8] as *const [u8; 16]) };
-			let dy = unsafe { &*(&mogwai.dna[0][16..32] as *const [u8] as *const [u8; 16]) };
+			let (dx, dy) = mogwai.dna.stats_halves();
 
-			mogwai.dna[0] = Breeding::morph(breed_type, dx, dy);
+			mogwai.dna.set_stats(Breeding::morph(breed_type, &dx, &dy));
 
 			Mogwais::<T>::insert(mogwai_id, mogwai);
 
-			// TODO: Do something with the results
-			let _ = Self::update_achievement_for(&sender, AccountAchievement::Morpheus, 1);
+			Self::update_achievement_for(&sender, AccountAchievement::Morpheus, 1);
 
 			// Emit an event.
 			Self::deposit_event(Event::MogwaiMorphed(mogwai_id));
@@ -669,7 +839,7 @@ pub mod pallet {
 				Self::mogwai(mogwai_id_1).ok_or(Error::<T>::MogwaiDoesntExists)?;
 			let mut mogwai_2: MogwaiOf<T> =
 				Self::mogwai(mogwai_id_2).ok_or(Error::<T>::MogwaiDoesntExists)?;
-			ensure!(mogwai_1.owner == sender, Error::<T>::MogwaiNotOwned);
+			Self::ensure_permitted(&sender, mogwai_id_1, &mogwai_1, MogwaiAction::Breed)?;
 
 			// breeding into the same mogwai isn't allowed
 			ensure!(mogwai_id_1 != mogwai_id_2, Error::<T>::MogwaiSame);
@@ -696,11 +866,11 @@ pub mod pallet {
 			let breed_type: BreedType = Self::calculate_breedtype(block_number);
 
 			// add pairing price to mogwai intrinsic value TODO
-			let pairing_price: BalanceOf<T> =
-				Pricing::pairing(mogwai_1.rarity, mogwai_2.rarity).saturated_into();
+			let pairing_price = Self::pairing_price(mogwai_1.rarity, mogwai_2.rarity);
 			Self::tip_mogwai(&sender, pairing_price, mogwai_id_2, &mut mogwai_2)?;
 
-			let final_dna = Breeding::pairing(breed_type, &mogwai_1.dna[0], &mogwai_2.dna[0]);
+			let final_dna =
+				Breeding::pairing(breed_type, mogwai_1.dna.stats(), mogwai_2.dna.stats());
 			let mogwai_rarity = RarityType::from(((max_rarity as u8) << 4) + rarity as u8);
 
 			let new_mogwai = MogwaiStruct {
@@ -718,13 +888,11 @@ pub mod pallet {
 			Self::mint(&sender, mogwai_id, new_mogwai)?;
 
 			if mogwai_rarity == RarityType::Mythical {
-				// TODO: Do something with the results
-				let _ = Self::update_achievement_for(&sender, AccountAchievement::LegendBreeder, 1);
+				Self::update_achievement_for(&sender, AccountAchievement::LegendBreeder, 1);
 			}
 
 			if mogwai_1.owner != mogwai_2.owner {
-				// TODO: Do something with the results
-				let _ = Self::update_achievement_for(&sender, AccountAchievement::Promiscuous, 1);
+				Self::update_achievement_for(&sender, AccountAchievement::Promiscuous, 1);
 			}
 
 			// Emit an event.
@@ -732,65 +900,662 @@ pub mod pallet {
 
 			Ok(())
 		}
-	}
-}
 
-impl<T: Config> Pallet<T> {
-	fn encode_and_update_nonce() -> Vec<u8> {
-		Nonce::<T>::mutate(|nonce| {
-			*nonce = nonce.wrapping_add(1);
-			*nonce
-		})
-		.encode()
-	}
+		/// Claim the reward of a completed achievement, each achievement pays out only once.
+		#[pallet::weight(T::WeightInfo::claim_achievement())]
+		#[pallet::call_index(13)]
+		pub fn claim_achievement(
+			origin: OriginFor<T>,
+			achievement: AccountAchievement,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
 
-	fn generate_random_hash(phrase: &[u8], sender: T::AccountId) -> T::Hash {
-		let (seed, _) = T::Randomness::random(phrase);
-		let decoded_seed =
-			<[u8; 32]>::decode(&mut TrailingZeroInput::new(seed.as_ref())).unwrap_or_default();
-		(decoded_seed, &sender, Self::encode_and_update_nonce()).using_encoded(T::Hashing::hash)
-	}
+			ensure!(
+				Self::account_achievements(&sender, achievement) ==
+					Some(AchievementState::Completed),
+				Error::<T>::AchievementNotCompleted
+			);
+			ensure!(
+				!ClaimedAchievements::<T>::contains_key(&sender, achievement),
+				Error::<T>::AchievementAlreadyClaimed
+			);
 
-	/// pay fee
-	fn pay_fee(who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
-		let _ = T::Currency::withdraw(
-			who,
-			amount,
-			WithdrawReasons::FEE,
-			ExistenceRequirement::KeepAlive,
-		)?;
+			let reward = T::AchievementRewards::convert(achievement)
+				.ok_or(Error::<T>::AchievementNoReward)?;
+
+			match reward {
+				AchievementReward::Currency(amount) => {
+					T::Currency::transfer(
+						&Self::account_id(),
+						&sender,
+						amount,
+						ExistenceRequirement::KeepAlive,
+					)?;
+				},
+				AchievementReward::ConfigUpgrade(index) => {
+					match Self::upgrade_config(&sender, index, None) {
+						Ok(parameters) => {
+							AccountConfig::<T>::insert(&sender, parameters);
+
+							Self::deposit_event(Event::AccountConfigChanged(
+								sender.clone(),
+								parameters,
+							));
+						},
+						// the parameter is already at its maximum, the claim is recorded anyway
+						Err(error) if error == Error::<T>::ConfigUpdateInvalid.into() => {},
+						Err(error) => return Err(error),
+					}
+				},
+				AchievementReward::SpecialEgg(rarity) => {
+					ensure!(
+						Self::ensure_not_max_mogwais(sender.clone()),
+						Error::<T>::MaxMogwaisInAccount
+					);
+
+					let mut new_mogwai = Self::new_egg(&sender);
+					new_mogwai.rarity = rarity;
+					let mogwai_id = new_mogwai.id;
+
+					Self::mint(&sender, mogwai_id, new_mogwai)?;
+
+					Self::deposit_event(Event::MogwaiCreated(sender.clone(), mogwai_id));
+				},
+			}
 
-		Ok(())
-	}
+			let block_number = <frame_system::Pallet<T>>::block_number();
+			ClaimedAchievements::<T>::insert(&sender, achievement, block_number);
 
-	/// pay founder
-	fn pay_founder(
-		who: T::AccountId,
-		organizer: T::AccountId,
-		amount: BalanceOf<T>,
-	) -> DispatchResult {
-		T::Currency::transfer(&who, &organizer, amount, ExistenceRequirement::KeepAlive)?;
+			// Emit an event.
+			Self::deposit_event(Event::AchievementClaimed(sender, achievement));
 
-		Ok(())
-	}
+			Ok(())
+		}
 
-	/// tiping mogwai
-	fn tip_mogwai(
-		who: &T::AccountId,
-		amount: BalanceOf<T>,
-		mogwai_id: MogwaiIdOf<T>,
-		mogwai: &mut MogwaiOf<T>,
-	) -> DispatchResult {
-		Self::pay_fee(who, amount)?;
+		/// Set who can mint new mogwais, and at which price.
+		#[pallet::weight(T::WeightInfo::set_mint_policy())]
+		#[pallet::call_index(14)]
+		pub fn set_mint_policy(
+			origin: OriginFor<T>,
+			mint_policy: MintPolicy<BalanceOf<T>>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
 
-		mogwai.intrinsic = mogwai.intrinsic.saturating_add(amount);
-		Mogwais::<T>::insert(mogwai_id, mogwai);
+			CurrentMintPolicy::<T>::put(mint_policy.clone());
 
-		Ok(())
-	}
+			// Emit an event.
+			Self::deposit_event(Event::MintPolicySet(mint_policy));
 
-	///
-	pub(crate) fn config_value(who: T::AccountId, index: u8) -> u32 {
+			Ok(())
+		}
+
+		/// Offer to lend a mogwai to `terms.borrower`, replacing any previous offer.
+		#[pallet::weight(T::WeightInfo::offer_lending())]
+		#[pallet::call_index(15)]
+		pub fn offer_lending(
+			origin: OriginFor<T>,
+			mogwai_id: MogwaiIdOf<T>,
+			terms: LendingTermsOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let mogwai: MogwaiOf<T> =
+				Self::mogwai(mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
+			ensure!(mogwai.owner == sender, Error::<T>::MogwaiNotOwned);
+			ensure!(!Lendings::<T>::contains_key(mogwai_id), Error::<T>::MogwaiLent);
+			ensure!(!MogwaiPrices::<T>::contains_key(mogwai_id), Error::<T>::MogwaiIsOnSale);
+			ensure!(terms.borrower != sender, Error::<T>::MogwaiAlreadyOwned);
+			ensure!(
+				!terms.duration.is_zero() && terms.duration <= T::MaxLendingDuration::get(),
+				Error::<T>::LendingDurationInvalid
+			);
+
+			LendingOffers::<T>::insert(mogwai_id, terms.clone());
+
+			// Emit an event.
+			Self::deposit_event(Event::LendingOffered(sender, mogwai_id, terms));
+
+			Ok(())
+		}
+
+		/// Withdraw a lending offer that hasn't been accepted yet.
+		#[pallet::weight(T::WeightInfo::cancel_lending_offer())]
+		#[pallet::call_index(16)]
+		pub fn cancel_lending_offer(
+			origin: OriginFor<T>,
+			mogwai_id: MogwaiIdOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let mogwai: MogwaiOf<T> =
+				Self::mogwai(mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
+			ensure!(mogwai.owner == sender, Error::<T>::MogwaiNotOwned);
+			ensure!(
+				LendingOffers::<T>::take(mogwai_id).is_some(),
+				Error::<T>::LendingOfferNotFound
+			);
+
+			// Emit an event.
+			Self::deposit_event(Event::LendingOfferCanceled(sender, mogwai_id));
+
+			Ok(())
+		}
+
+		/// Accept a lending offer, paying its fee to the lender.
+		#[pallet::weight(T::WeightInfo::borrow_mogwai())]
+		#[pallet::call_index(17)]
+		pub fn borrow_mogwai(origin: OriginFor<T>, mogwai_id: MogwaiIdOf<T>) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let mogwai: MogwaiOf<T> =
+				Self::mogwai(mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
+			let terms =
+				LendingOffers::<T>::take(mogwai_id).ok_or(Error::<T>::LendingOfferNotFound)?;
+			ensure!(terms.borrower == sender, Error::<T>::NotTheBorrower);
+			ensure!(!MogwaiPrices::<T>::contains_key(mogwai_id), Error::<T>::MogwaiIsOnSale);
+
+			T::Currency::transfer(
+				&sender,
+				&mogwai.owner,
+				terms.fee,
+				ExistenceRequirement::KeepAlive,
+			)?;
+
+			let expires_at =
+				<frame_system::Pallet<T>>::block_number().saturating_add(terms.duration);
+			LendingExpiries::<T>::try_mutate(expires_at, |mogwai_ids| {
+				mogwai_ids.try_push(mogwai_id)
+			})
+			.map_err(|_| Error::<T>::TooManyLendingExpiries)?;
+
+			Lendings::<T>::insert(
+				mogwai_id,
+				LendingAgreement {
+					lender: mogwai.owner.clone(),
+					borrower: sender.clone(),
+					fee: terms.fee,
+					expires_at,
+					permissions: terms.permissions,
+				},
+			);
+
+			// Emit an event.
+			Self::deposit_event(Event::MogwaiLent(mogwai.owner, sender, mogwai_id, expires_at));
+
+			Ok(())
+		}
+
+		/// Return a borrowed mogwai to its lender before the lending expires.
+		#[pallet::weight(T::WeightInfo::return_mogwai())]
+		#[pallet::call_index(18)]
+		pub fn return_mogwai(origin: OriginFor<T>, mogwai_id: MogwaiIdOf<T>) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let lending = Lendings::<T>::get(mogwai_id).ok_or(Error::<T>::MogwaiNotLent)?;
+			ensure!(lending.borrower == sender, Error::<T>::NotTheBorrower);
+
+			Lendings::<T>::remove(mogwai_id);
+
+			// Emit an event.
+			Self::deposit_event(Event::MogwaiReturned(lending.lender, sender, mogwai_id));
+
+			Ok(())
+		}
+
+		/// Hatch several mogwai eggs, eggs which can't be hatched are skipped.
+		#[pallet::weight(T::WeightInfo::hatch_mogwais(mogwai_ids.len() as u32))]
+		#[pallet::call_index(19)]
+		pub fn hatch_mogwais(
+			origin: OriginFor<T>,
+			mogwai_ids: BatchOf<T, MogwaiIdOf<T>>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::execute_batch(&sender, BatchAction::Hatch, mogwai_ids, |id| *id, Self::do_hatch)
+		}
+
+		/// Set the prices of several mogwais, mogwais whose price can't be set are skipped.
+		#[pallet::weight(T::WeightInfo::set_prices(prices.len() as u32))]
+		#[pallet::call_index(20)]
+		pub fn set_prices(
+			origin: OriginFor<T>,
+			prices: BatchOf<T, (MogwaiIdOf<T>, BalanceOf<T>)>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::execute_batch(
+				&sender,
+				BatchAction::SetPrice,
+				prices,
+				|(mogwai_id, _)| *mogwai_id,
+				|sender, (mogwai_id, price)| Self::do_set_price(sender, mogwai_id, price),
+			)
+		}
+
+		/// Clear the prices of several mogwais, mogwais which aren't for sale are skipped.
+		#[pallet::weight(T::WeightInfo::remove_prices(mogwai_ids.len() as u32))]
+		#[pallet::call_index(21)]
+		pub fn remove_prices(
+			origin: OriginFor<T>,
+			mogwai_ids: BatchOf<T, MogwaiIdOf<T>>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::execute_batch(
+				&sender,
+				BatchAction::RemovePrice,
+				mogwai_ids,
+				|id| *id,
+				Self::do_remove_price,
+			)
+		}
+
+		/// Sacrifice several mogwais, mogwais which can't be sacrificed are skipped.
+		#[pallet::weight(T::WeightInfo::sacrifice_mogwais(mogwai_ids.len() as u32))]
+		#[pallet::call_index(22)]
+		pub fn sacrifice_mogwais(
+			origin: OriginFor<T>,
+			mogwai_ids: BatchOf<T, MogwaiIdOf<T>>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			Self::execute_batch(
+				&sender,
+				BatchAction::Sacrifice,
+				mogwai_ids,
+				|id| *id,
+				Self::do_sacrifice,
+			)
+		}
+
+		/// Offer currency and mogwais of your own for a mogwai, listed or not. The amount is
+		/// reserved until the offer is accepted, withdrawn, rejected or expires.
+		#[pallet::weight(T::WeightInfo::make_offer())]
+		#[pallet::call_index(23)]
+		pub fn make_offer(
+			origin: OriginFor<T>,
+			mogwai_id: MogwaiIdOf<T>,
+			terms: TradeTermsOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let mogwai: MogwaiOf<T> =
+				Self::mogwai(mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
+			ensure!(mogwai.owner != sender, Error::<T>::MogwaiAlreadyOwned);
+			Self::ensure_trade_terms(&sender, &terms)?;
+
+			let expires_at =
+				<frame_system::Pallet<T>>::block_number().saturating_add(T::OfferDuration::get());
+			let offer = TradeOffer {
+				buyer: sender.clone(),
+				terms: terms.clone(),
+				counter: None,
+				expires_at,
+			};
+
+			TradeOffers::<T>::try_mutate(mogwai_id, |offers| {
+				ensure!(
+					!offers.iter().any(|offer| offer.buyer == sender),
+					Error::<T>::OfferAlreadyMade
+				);
+				offers.try_push(offer).map_err(|_| Error::<T>::TooManyOffers)
+			})?;
+			TradeOfferExpiries::<T>::try_mutate(expires_at, |expiries| {
+				expiries.try_push((mogwai_id, sender.clone()))
+			})
+			.map_err(|_| Error::<T>::TooManyOfferExpiries)?;
+
+			T::Currency::reserve(&sender, terms.amount)?;
+
+			// Emit an event.
+			Self::deposit_event(Event::OfferMade(sender, mogwai_id, terms));
+
+			Ok(())
+		}
+
+		/// Withdraw an offer and unreserve its amount.
+		#[pallet::weight(T::WeightInfo::withdraw_offer())]
+		#[pallet::call_index(24)]
+		pub fn withdraw_offer(origin: OriginFor<T>, mogwai_id: MogwaiIdOf<T>) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let offer = Self::take_offer(mogwai_id, &sender)?;
+			T::Currency::unreserve(&sender, offer.terms.amount);
+
+			// Emit an event.
+			Self::deposit_event(Event::OfferWithdrawn(sender, mogwai_id));
+
+			Ok(())
+		}
+
+		/// Reject the offer of `buyer` for one of your mogwais.
+		#[pallet::weight(T::WeightInfo::reject_offer())]
+		#[pallet::call_index(25)]
+		pub fn reject_offer(
+			origin: OriginFor<T>,
+			mogwai_id: MogwaiIdOf<T>,
+			buyer: T::AccountId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let mogwai: MogwaiOf<T> =
+				Self::mogwai(mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
+			ensure!(mogwai.owner == sender, Error::<T>::MogwaiNotOwned);
+
+			let offer = Self::take_offer(mogwai_id, &buyer)?;
+			T::Currency::unreserve(&buyer, offer.terms.amount);
+
+			// Emit an event.
+			Self::deposit_event(Event::OfferRejected(sender, buyer, mogwai_id));
+
+			Ok(())
+		}
+
+		/// Answer the offer of `buyer` with the terms you would accept, the buyer can then
+		/// accept them with `accept_counter_offer` until the offer expires.
+		#[pallet::weight(T::WeightInfo::counter_offer())]
+		#[pallet::call_index(26)]
+		pub fn counter_offer(
+			origin: OriginFor<T>,
+			mogwai_id: MogwaiIdOf<T>,
+			buyer: T::AccountId,
+			terms: TradeTermsOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let mogwai: MogwaiOf<T> =
+				Self::mogwai(mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
+			Self::ensure_permitted(&sender, mogwai_id, &mogwai, MogwaiAction::Sell)?;
+			Self::ensure_trade_terms(&buyer, &terms)?;
+
+			TradeOffers::<T>::try_mutate(mogwai_id, |offers| {
+				let offer = offers
+					.iter_mut()
+					.find(|offer| offer.buyer == buyer)
+					.ok_or(Error::<T>::OfferNotFound)?;
+				offer.counter = Some(terms.clone());
+				Ok::<_, DispatchError>(())
+			})?;
+
+			// Emit an event.
+			Self::deposit_event(Event::OfferCountered(sender, buyer, mogwai_id, terms));
+
+			Ok(())
+		}
+
+		/// Accept the offer of `buyer` for one of your mogwais.
+		#[pallet::weight(T::WeightInfo::accept_offer())]
+		#[pallet::call_index(27)]
+		pub fn accept_offer(
+			origin: OriginFor<T>,
+			mogwai_id: MogwaiIdOf<T>,
+			buyer: T::AccountId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let offer = Self::take_offer(mogwai_id, &buyer)?;
+			Self::settle_offer(&sender, mogwai_id, &buyer, offer.terms)
+		}
+
+		/// Accept the terms the owner of a mogwai countered your offer with.
+		#[pallet::weight(T::WeightInfo::accept_counter_offer())]
+		#[pallet::call_index(28)]
+		pub fn accept_counter_offer(
+			origin: OriginFor<T>,
+			mogwai_id: MogwaiIdOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let mogwai: MogwaiOf<T> =
+				Self::mogwai(mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
+			let offer = Self::take_offer(mogwai_id, &sender)?;
+			let counter = offer.counter.ok_or(Error::<T>::CounterOfferNotFound)?;
+
+			if counter.amount > offer.terms.amount {
+				T::Currency::reserve(&sender, counter.amount.saturating_sub(offer.terms.amount))?;
+			} else {
+				T::Currency::unreserve(&sender, offer.terms.amount.saturating_sub(counter.amount));
+			}
+
+			Self::settle_offer(&mogwai.owner, mogwai_id, &sender, counter)
+		}
+	}
+}
+
+/// Deposits the fees paid to the pallet into its own account, so they can fund rewards.
+pub struct DepositToPallet<T>(PhantomData<T>);
+
+impl<T: Config> OnUnbalanced<NegativeImbalanceOf<T>> for DepositToPallet<T> {
+	fn on_nonzero_unbalanced(amount: NegativeImbalanceOf<T>) {
+		T::Currency::resolve_creating(&Pallet::<T>::account_id(), amount);
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Run `execute` for each item of a batch in its own storage layer, so that failing items
+	/// are rolled back without affecting the others, and report all results in one event.
+	fn execute_batch<Item>(
+		sender: &T::AccountId,
+		action: BatchAction,
+		items: BatchOf<T, Item>,
+		mogwai_id: impl Fn(&Item) -> MogwaiIdOf<T>,
+		execute: impl Fn(&T::AccountId, Item) -> DispatchResult,
+	) -> DispatchResult {
+		ensure!(!items.is_empty(), Error::<T>::BatchEmpty);
+
+		let results = items
+			.into_iter()
+			.map(|item| (mogwai_id(&item), with_storage_layer(|| execute(sender, item))))
+			.collect::<Vec<_>>();
+
+		Self::deposit_event(Event::BatchExecuted(
+			sender.clone(),
+			action,
+			BoundedVec::truncate_from(results),
+		));
+
+		Ok(())
+	}
+
+	fn do_set_price(
+		sender: &T::AccountId,
+		mogwai_id: MogwaiIdOf<T>,
+		new_price: BalanceOf<T>,
+	) -> DispatchResult {
+		let mogwai: MogwaiOf<T> = Self::mogwai(mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
+		Self::ensure_permitted(sender, mogwai_id, &mogwai, MogwaiAction::Sell)?;
+
+		MogwaiPrices::<T>::insert(mogwai_id, new_price);
+		Self::deposit_event(Event::ForSale(sender.clone(), mogwai_id, new_price));
+
+		Ok(())
+	}
+
+	fn do_remove_price(sender: &T::AccountId, mogwai_id: MogwaiIdOf<T>) -> DispatchResult {
+		let mogwai: MogwaiOf<T> = Self::mogwai(mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
+		Self::ensure_permitted(sender, mogwai_id, &mogwai, MogwaiAction::Sell)?;
+		ensure!(MogwaiPrices::<T>::contains_key(mogwai_id), Error::<T>::MogwaiNotForSale);
+
+		MogwaiPrices::<T>::remove(mogwai_id);
+		Self::deposit_event(Event::RemovedFromSale(sender.clone(), mogwai_id));
+
+		Ok(())
+	}
+
+	fn do_hatch(sender: &T::AccountId, mogwai_id: MogwaiIdOf<T>) -> DispatchResult {
+		let mut mogwai: MogwaiOf<T> =
+			Self::mogwai(mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
+		Self::ensure_permitted(sender, mogwai_id, &mogwai, MogwaiAction::Hatch)?;
+
+		let block_number = <frame_system::Pallet<T>>::block_number();
+
+		ensure!(
+			block_number - mogwai.genesis >= GameEventType::time_till(GameEventType::Hatch).into(),
+			Error::<T>::MogwaiNoHatch
+		);
+
+		let block_hash = <frame_system::Pallet<T>>::block_hash(block_number);
+
+		let (dna, mut rarity) = Self::segment_and_bake(mogwai.clone(), block_hash);
+
+		if matches!(Self::world_event_kind(), Some(WorldEventKind::RareHatch)) {
+			rarity = rarity.upgraded();
+		}
+
+		mogwai.phase = PhaseType::Hatched;
+		mogwai.rarity = rarity;
+		mogwai.dna = dna;
+
+		Self::update_nft(mogwai_id, &mogwai)?;
+		Mogwais::<T>::insert(mogwai_id, mogwai);
+		Self::invalidate_offers(mogwai_id);
+
+		Self::update_achievement_for(sender, AccountAchievement::EggHatcher, 1);
+
+		// Emit an event.
+		Self::deposit_event(Event::MogwaiHatched(sender.clone(), mogwai_id));
+
+		Ok(())
+	}
+
+	fn do_sacrifice(sender: &T::AccountId, mogwai_id: MogwaiIdOf<T>) -> DispatchResult {
+		// TODO this needs to be check, reworked and corrected, add dynasty feature !!!
+		let mogwai: MogwaiOf<T> = Self::mogwai(mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
+		Self::ensure_permitted(sender, mogwai_id, &mogwai, MogwaiAction::Sacrifice)?;
+		ensure!(!MogwaiPrices::<T>::contains_key(mogwai_id), Error::<T>::MogwaiIsOnSale);
+		ensure!(mogwai.phase != PhaseType::Bred, Error::<T>::MogwaiNoHatch);
+
+		let intrinsic_to_deposit = {
+			let computed_intrinsic =
+				mogwai.intrinsic / Pricing::intrinsic_return(mogwai.phase).saturated_into();
+
+			let max_intrinsic = BalanceOf::<T>::max_value() - T::Currency::free_balance(sender);
+
+			sp_std::cmp::min(computed_intrinsic, max_intrinsic)
+		};
+
+		Self::remove(sender.clone(), mogwai_id)?;
+
+		// TODO check this function on return value
+		let _ = T::Currency::deposit_into_existing(sender, intrinsic_to_deposit)?;
+
+		Self::update_achievement_for(sender, AccountAchievement::Sacrificer, 1);
+
+		// Emit an event.
+		Self::deposit_event(Event::MogwaiSacrificed(sender.clone(), mogwai_id));
+
+		Ok(())
+	}
+
+	/// The account holding the fees routed to this pallet and paying out rewards.
+	pub fn account_id() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	fn encode_and_update_nonce() -> Vec<u8> {
+		Nonce::<T>::mutate(|nonce| {
+			*nonce = nonce.wrapping_add(1);
+			*nonce
+		})
+		.encode()
+	}
+
+	fn generate_random_hash(phrase: &[u8], sender: T::AccountId) -> T::Hash {
+		let (seed, _) = T::Randomness::random(phrase);
+		let decoded_seed =
+			<[u8; 32]>::decode(&mut TrailingZeroInput::new(seed.as_ref())).unwrap_or_default();
+		(decoded_seed, &sender, Self::encode_and_update_nonce()).using_encoded(T::Hashing::hash)
+	}
+
+	/// Build a fresh egg owned by `owner`, without adding it to storage.
+	fn new_egg(owner: &T::AccountId) -> MogwaiOf<T> {
+		let random_hash_1 = Self::generate_random_hash(b"create_mogwai", owner.clone());
+		let random_hash_2 = Self::generate_random_hash(b"extend_mogwai", owner.clone());
+
+		let (rarity, next_gen, max_rarity) = Generation::next_gen(
+			MogwaiGeneration::First,
+			RarityType::Common,
+			MogwaiGeneration::First,
+			RarityType::Common,
+			random_hash_1.as_ref(),
+		);
+
+		let block_number = <frame_system::Pallet<T>>::block_number();
+		let breed_type: BreedType = Self::calculate_breedtype(block_number);
+
+		let dx = Dna::segment_from_hash(random_hash_1.as_ref());
+		let dy = Dna::segment_from_hash(random_hash_2.as_ref());
+
+		let final_dna = Breeding::pairing(breed_type, &dx, &dy);
+
+		MogwaiStruct {
+			id: random_hash_1,
+			dna: final_dna,
+			genesis: block_number,
+			intrinsic: Zero::zero(),
+			generation: next_gen,
+			rarity: RarityType::from(((max_rarity as u8) << 4) + rarity as u8),
+			phase: PhaseType::Bred,
+			owner: owner.clone(),
+		}
+	}
+
+	/// Compute the configuration of `who` after updating the value at `index`.
+	fn upgrade_config(
+		who: &T::AccountId,
+		index: u8,
+		value_opt: Option<u8>,
+	) -> Result<[u8; GameConfig::PARAM_COUNT], DispatchError> {
+		ensure!(usize::from(index) < GameConfig::PARAM_COUNT, Error::<T>::ConfigIndexOutOfRange);
+
+		let mut game_config = GameConfig::new();
+
+		if let Some(config) = AccountConfig::<T>::get(who) {
+			game_config.parameters = config;
+		}
+
+		let update_value = GameConfig::rule(index)
+			.and_then(|rule| rule.next_value(game_config.parameters[usize::from(index)]))
+			.ok_or(Error::<T>::ConfigUpdateInvalid)?;
+		// don't allow bad requests
+		ensure!(
+			value_opt.map_or(true, |value| value == update_value),
+			Error::<T>::ConfigUpdateInvalid
+		);
+
+		game_config.parameters[usize::from(index)] = update_value;
+
+		Ok(game_config.parameters)
+	}
+
+	/// pay fee, the withdrawn amount is handed over to the fee destination
+	fn pay_fee(who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+		let imbalance = T::Currency::withdraw(
+			who,
+			amount,
+			WithdrawReasons::FEE,
+			ExistenceRequirement::KeepAlive,
+		)?;
+
+		T::FeeDestination::on_unbalanced(imbalance);
+
+		Ok(())
+	}
+
+	/// tiping mogwai
+	fn tip_mogwai(
+		who: &T::AccountId,
+		amount: BalanceOf<T>,
+		mogwai_id: MogwaiIdOf<T>,
+		mogwai: &mut MogwaiOf<T>,
+	) -> DispatchResult {
+		Self::pay_fee(who, amount)?;
+
+		mogwai.intrinsic = mogwai.intrinsic.saturating_add(amount);
+		Mogwais::<T>::insert(mogwai_id, mogwai);
+		Self::invalidate_offers(mogwai_id);
+
+		Ok(())
+	}
+
+	///
+	pub(crate) fn config_value(who: T::AccountId, index: u8) -> u32 {
 		GameConfig::config_value(
 			index,
 			AccountConfig::<T>::get(&who)
@@ -801,7 +1566,8 @@ impl<T: Config> Pallet<T> {
 
 	///
 	fn ensure_not_max_mogwais(who: T::AccountId) -> bool {
-		Self::owned_mogwais_count(&who) < Self::config_value(who, 1) as u64
+		Self::owned_mogwais_count(&who) <
+			Self::config_value(who, GameConfigType::MaxMogwaisInAccount as u8) as u64
 	}
 
 	/// Add mogwai to storage
@@ -812,6 +1578,7 @@ impl<T: Config> Pallet<T> {
 	) -> DispatchResult {
 		ensure!(!Mogwais::<T>::contains_key(mogwai_id), Error::<T>::MogwaiAlreadyExists);
 
+		Self::mint_nft(to, mogwai_id, &new_mogwai)?;
 		Mogwais::<T>::insert(mogwai_id, new_mogwai);
 		Owners::<T>::try_mutate(to, |id_set| id_set.try_insert(mogwai_id))
 			.map_err(|_| Error::<T>::MaxMogwaisInAccount)?;
@@ -832,6 +1599,14 @@ impl<T: Config> Pallet<T> {
 
 		Mogwais::<T>::remove(mogwai_id);
 
+		if let Some(item) = MogwaiNftItems::<T>::take(mogwai_id) {
+			T::NftHandler::burn(item)?;
+		}
+
+		Lendings::<T>::remove(mogwai_id);
+		LendingOffers::<T>::remove(mogwai_id);
+		Self::invalidate_offers(mogwai_id);
+
 		Owners::<T>::mutate(&from, |id_set| {
 			id_set.remove(&mogwai_id);
 		});
@@ -872,6 +1647,15 @@ impl<T: Config> Pallet<T> {
 		Owners::<T>::try_mutate(&to, |id_set| id_set.try_insert(mogwai_id))
 			.map_err(|_| Error::<T>::MaxMogwaisInAccount)?;
 
+		if let Some(item) = MogwaiNftItems::<T>::get(mogwai_id) {
+			T::NftHandler::transfer(item, &to)?;
+		}
+
+		// A new owner isn't bound to the lendings and offers of the previous one.
+		Lendings::<T>::remove(mogwai_id);
+		LendingOffers::<T>::remove(mogwai_id);
+		Self::invalidate_offers(mogwai_id);
+
 		Mogwais::<T>::try_mutate(mogwai_id, |maybe_mogwai| {
 			if let Some(mogwai) = maybe_mogwai {
 				mogwai.owner = to;
@@ -884,50 +1668,333 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
-	/// Calculate breed type
+	/// Mint the NFT mirroring `mogwai` to `owner`, if mirroring is enabled.
+	pub(crate) fn mint_nft(
+		owner: &T::AccountId,
+		mogwai_id: MogwaiIdOf<T>,
+		mogwai: &MogwaiOf<T>,
+	) -> DispatchResult {
+		if !T::NftHandler::ENABLED {
+			return Ok(())
+		}
+
+		let item = NextNftItemId::<T>::get();
+		T::NftHandler::mint(owner, item, mogwai)?;
+
+		MogwaiNftItems::<T>::insert(mogwai_id, item);
+		NextNftItemId::<T>::put(item.checked_add(1).ok_or(ArithmeticError::Overflow)?);
+
+		Ok(())
+	}
+
+	/// Mirror the next mogwais of an ongoing [`NftImport`], skipping the ones already mirrored.
+	fn import_nfts() -> Weight {
+		let mut weight = T::DbWeight::get().reads(1);
+		let mogwais = match NftImport::<T>::get() {
+			Some(NftImportProgress::Started) => Mogwais::<T>::iter(),
+			Some(NftImportProgress::After(mogwai_id)) =>
+				Mogwais::<T>::iter_from(Mogwais::<T>::hashed_key_for(mogwai_id)),
+			None => return weight,
+		};
+
+		let mut last_mogwai_id = None;
+		for (mogwai_id, mogwai) in mogwais.take(T::MaxNftImportsPerBlock::get() as usize) {
+			weight.saturating_accrue(T::DbWeight::get().reads(2));
+			last_mogwai_id = Some(mogwai_id);
+
+			if MogwaiNftItems::<T>::contains_key(mogwai_id) {
+				continue
+			}
+
+			if let Err(e) = with_storage_layer(|| Self::mint_nft(&mogwai.owner, mogwai_id, &mogwai))
+			{
+				log::error!(
+					target: migration::LOG_TARGET,
+					"failed to import mogwai {:?} as nft: {:?}",
+					mogwai_id,
+					e
+				);
+			}
+			// Each mint also touches the collection, item, account and three attributes.
+			weight.saturating_accrue(T::DbWeight::get().reads_writes(5, 8));
+		}
+
+		match last_mogwai_id {
+			Some(mogwai_id) => NftImport::<T>::put(NftImportProgress::After(mogwai_id)),
+			None => {
+				NftImport::<T>::kill();
+				log::info!(target: migration::LOG_TARGET, "imported all mogwais as nfts");
+			},
+		}
+		weight.saturating_accrue(T::DbWeight::get().writes(1));
+
+		weight
+	}
+
+	/// Sync the attributes of the NFT mirroring `mogwai`, if there is one.
+	fn update_nft(mogwai_id: MogwaiIdOf<T>, mogwai: &MogwaiOf<T>) -> DispatchResult {
+		match MogwaiNftItems::<T>::get(mogwai_id) {
+			Some(item) => T::NftHandler::update(item, mogwai),
+			None => Ok(()),
+		}
+	}
+
+	/// Ensure `who` may perform `action` with `mogwai`. While a mogwai is lent only its borrower
+	/// may use it, and only for the permitted actions.
+	pub fn ensure_permitted(
+		who: &T::AccountId,
+		mogwai_id: MogwaiIdOf<T>,
+		mogwai: &MogwaiOf<T>,
+		action: MogwaiAction,
+	) -> DispatchResult {
+		match Lendings::<T>::get(mogwai_id) {
+			Some(lending) if lending.borrower == *who => {
+				ensure!(lending.permissions.allows(action), Error::<T>::LendingActionNotPermitted);
+			},
+			Some(_) if mogwai.owner == *who => return Err(Error::<T>::MogwaiLent.into()),
+			_ => ensure!(mogwai.owner == *who, Error::<T>::MogwaiNotOwned),
+		}
+
+		Ok(())
+	}
+
+	/// Check that `buyer` can give `terms`: they are not empty and the mogwais are distinct
+	/// and at the buyer's disposal.
+	fn ensure_trade_terms(buyer: &T::AccountId, terms: &TradeTermsOf<T>) -> DispatchResult {
+		ensure!(!terms.amount.is_zero() || !terms.mogwais.is_empty(), Error::<T>::OfferEmpty);
+
+		for (index, mogwai_id) in terms.mogwais.iter().enumerate() {
+			ensure!(!terms.mogwais[..index].contains(mogwai_id), Error::<T>::MogwaiSame);
+			let mogwai: MogwaiOf<T> =
+				Self::mogwai(mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
+			Self::ensure_permitted(buyer, *mogwai_id, &mogwai, MogwaiAction::Sell)?;
+		}
+
+		Ok(())
+	}
+
+	/// Remove the offer of `buyer` for `mogwai_id`, leaving its amount reserved.
+	fn take_offer(
+		mogwai_id: MogwaiIdOf<T>,
+		buyer: &T::AccountId,
+	) -> Result<TradeOfferOf<T>, DispatchError> {
+		TradeOffers::<T>::try_mutate_exists(mogwai_id, |maybe_offers| {
+			let offers = maybe_offers.as_mut().ok_or(Error::<T>::OfferNotFound)?;
+			let index = offers
+				.iter()
+				.position(|offer| offer.buyer == *buyer)
+				.ok_or(Error::<T>::OfferNotFound)?;
+			let offer = offers.remove(index);
+			if offers.is_empty() {
+				*maybe_offers = None;
+			}
+			Ok(offer)
+		})
+	}
+
+	/// Trade `mogwai_id` from `seller` to `buyer`, whose offer with `terms` has been taken and
+	/// whose `terms.amount` is reserved.
+	fn settle_offer(
+		seller: &T::AccountId,
+		mogwai_id: MogwaiIdOf<T>,
+		buyer: &T::AccountId,
+		terms: TradeTermsOf<T>,
+	) -> DispatchResult {
+		let mogwai: MogwaiOf<T> = Self::mogwai(mogwai_id).ok_or(Error::<T>::MogwaiDoesntExists)?;
+		Self::ensure_permitted(seller, mogwai_id, &mogwai, MogwaiAction::Sell)?;
+		Self::ensure_trade_terms(buyer, &terms)?;
+
+		let swapped = terms.mogwais.len() as u64;
+		ensure!(
+			Self::owned_mogwais_count(buyer).saturating_add(1).saturating_sub(swapped) <=
+				Self::config_value(buyer.clone(), GameConfigType::MaxMogwaisInAccount as u8)
+					as u64,
+			Error::<T>::MaxMogwaisInAccount
+		);
+		ensure!(
+			Self::owned_mogwais_count(seller).saturating_add(swapped).saturating_sub(1) <=
+				Self::config_value(seller.clone(), GameConfigType::MaxMogwaisInAccount as u8)
+					as u64,
+			Error::<T>::MaxMogwaisInAccount
+		);
+
+		let unpaid =
+			T::Currency::repatriate_reserved(buyer, seller, terms.amount, BalanceStatus::Free)?;
+		ensure!(unpaid.is_zero(), Error::<T>::MogwaiNotAffordable);
+
+		Self::transfer_unchecked(seller.clone(), buyer.clone(), mogwai_id)?;
+		MogwaiPrices::<T>::remove(mogwai_id);
+		for swapped_id in terms.mogwais.iter() {
+			Self::transfer_unchecked(buyer.clone(), seller.clone(), *swapped_id)?;
+			MogwaiPrices::<T>::remove(swapped_id);
+		}
+
+		Self::update_achievement_for(buyer, AccountAchievement::Buyer, 1);
+		Self::update_achievement_for(seller, AccountAchievement::Seller, 1);
+
+		// Emit an event.
+		Self::deposit_event(Event::OfferAccepted(seller.clone(), buyer.clone(), mogwai_id, terms));
+
+		Ok(())
+	}
+
+	/// Drop all offers for a mogwai whose value changed, unreserving their amounts.
+	fn invalidate_offers(mogwai_id: MogwaiIdOf<T>) {
+		for offer in TradeOffers::<T>::take(mogwai_id) {
+			T::Currency::unreserve(&offer.buyer, offer.terms.amount);
+			Self::deposit_event(Event::OfferInvalidated(offer.buyer, mogwai_id));
+		}
+	}
+
+	/// Unreserve the trade offers expiring at `now`.
+	fn expire_trade_offers(now: BlockNumberFor<T>) -> Weight {
+		let expiries = TradeOfferExpiries::<T>::take(now);
+		let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+		for (mogwai_id, buyer) in expiries {
+			weight.saturating_accrue(T::DbWeight::get().reads(1));
+
+			// Offers that were settled or dropped since are skipped.
+			let expired = TradeOffers::<T>::get(mogwai_id)
+				.iter()
+				.any(|offer| offer.buyer == buyer && offer.expires_at == now);
+			if expired {
+				if let Ok(offer) = Self::take_offer(mogwai_id, &buyer) {
+					T::Currency::unreserve(&buyer, offer.terms.amount);
+					Self::deposit_event(Event::OfferExpired(buyer, mogwai_id));
+				}
+				weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+			}
+		}
+
+		weight
+	}
+
+	/// Return the mogwais whose lending expires at `now` to their lenders.
+	fn return_expired_lendings(now: BlockNumberFor<T>) -> Weight {
+		let mogwai_ids = LendingExpiries::<T>::take(now);
+		let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+		for mogwai_id in mogwai_ids {
+			weight.saturating_accrue(T::DbWeight::get().reads(1));
+
+			// Lendings that were ended early or replaced since are skipped.
+			match Lendings::<T>::get(mogwai_id) {
+				Some(lending) if lending.expires_at == now => {
+					Lendings::<T>::remove(mogwai_id);
+					Self::deposit_event(Event::MogwaiReturned(
+						lending.lender,
+						lending.borrower,
+						mogwai_id,
+					));
+					weight.saturating_accrue(T::DbWeight::get().writes(2));
+				},
+				_ => {},
+			}
+		}
+
+		weight
+	}
+
+	/// Calculate breed type, an active breeding moon overrides the regular moon cycle.
 	fn calculate_breedtype(block_number: BlockNumberFor<T>) -> BreedType {
-		let mod_value: u32 = 80;
-		let modulo80 = (block_number % mod_value.into()).saturated_into::<u32>();
+		if let Some(WorldEventKind::BreedingMoon(breed_type)) = Self::world_event_kind() {
+			return breed_type
+		}
 
-		match modulo80 {
-			0..=19 => BreedType::DomDom,
-			20..=39 => BreedType::DomRez,
-			40..=59 => BreedType::RezDom,
-			_ => BreedType::RezRez,
+		BreedType::from_moon_cycle((block_number % 80_u32.into()).saturated_into())
+	}
+
+	/// The pairing price of two rarities, halved during a breeding moon.
+	fn pairing_price(rarity_1: RarityType, rarity_2: RarityType) -> BalanceOf<T> {
+		let price = Pricing::pairing(rarity_1, rarity_2);
+
+		match Self::world_event_kind() {
+			Some(WorldEventKind::BreedingMoon(_)) => (price / 2).saturated_into(),
+			_ => price.saturated_into(),
+		}
+	}
+
+	/// The kind of the currently active world event.
+	fn world_event_kind() -> Option<WorldEventKind> {
+		ActiveWorldEvent::<T>::get().map(|event| event.kind)
+	}
+
+	/// End, start and announce world events as their blocks are reached.
+	fn progress_world_events(now: BlockNumberFor<T>) -> Weight {
+		let mut weight = T::DbWeight::get().reads(2);
+
+		if let Some(active) = ActiveWorldEvent::<T>::get() {
+			if now >= active.end {
+				ActiveWorldEvent::<T>::kill();
+				Self::deposit_event(Event::WorldEventEnded(active.kind));
+				weight.saturating_accrue(T::DbWeight::get().writes(2));
+			}
+		}
+
+		match UpcomingWorldEvent::<T>::get() {
+			Some(upcoming) if now >= upcoming.start => {
+				UpcomingWorldEvent::<T>::kill();
+				ActiveWorldEvent::<T>::put(upcoming);
+				Self::deposit_event(Event::WorldEventStarted(upcoming.kind));
+				weight.saturating_accrue(T::DbWeight::get().writes(3));
+			},
+			None if !ActiveWorldEvent::<T>::exists() => {
+				let event = Self::random_world_event(now);
+				UpcomingWorldEvent::<T>::put(event);
+				Self::deposit_event(Event::WorldEventScheduled(event));
+				weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 2));
+			},
+			_ => {},
+		}
+
+		weight
+	}
+
+	/// Derive the kind and start of the next world event from the randomness source.
+	fn random_world_event(now: BlockNumberFor<T>) -> WorldEvent<BlockNumberFor<T>> {
+		let (random, _) = T::Randomness::random(&(b"world_event", now).encode());
+		let (kind_seed, delay_seed) =
+			<(u8, u32)>::decode(&mut TrailingZeroInput::new(random.as_ref())).unwrap_or_default();
+
+		let max_delay = T::WorldEventMaxDelay::get().saturated_into::<u32>();
+		let delay = delay_seed % max_delay.saturating_add(1);
+		let start = now.saturating_add(T::WorldEventNotice::get()).saturating_add(delay.into());
+
+		WorldEvent {
+			kind: WorldEventKind::from_seed(kind_seed),
+			start,
+			end: start.saturating_add(T::WorldEventDuration::get()),
 		}
 	}
 
 	/// do the segmentation and baking
-	fn segment_and_bake(mogwai: MogwaiOf<T>, hash: T::Hash) -> ([[u8; 32]; 2], RarityType) {
-		let block_hash = unsafe {
-			let mut block_hash: MaybeUninit<[u8; 32]> = MaybeUninit::uninit();
-			let block_hash_ptr = block_hash.as_mut_ptr() as *mut u8;
-			copy_nonoverlapping(hash.as_ref()[0..32].as_ptr(), block_hash_ptr, 32);
-			block_hash.assume_init()
-		};
+	fn segment_and_bake(mogwai: MogwaiOf<T>, hash: T::Hash) -> (Dna, RarityType) {
+		let block_hash = Dna::segment_from_hash(hash.as_ref());
 
 		// segment and and bake the hatched mogwai
 		(Breeding::segmenting(mogwai.dna, block_hash), Breeding::bake(mogwai.rarity, block_hash))
 	}
 
-	#[inline]
+	/// Progress `achievement` for `account`, emits an event once it gets completed.
 	fn update_achievement_for(
 		account: &T::AccountId,
 		achievement: AccountAchievement,
 		update_amount: u16,
-	) -> bool {
-		AccountAchievements::<T>::mutate(account, achievement, |maybe_value| match maybe_value {
-			None => {
-				*maybe_value =
-					Some(AchievementState::new(achievement.target_for()).update(update_amount));
-				false
-			},
-			Some(AchievementState::Completed) => false,
-			Some(value) => {
-				let updated_value = value.update(update_amount);
-				*maybe_value = Some(updated_value);
-				updated_value == AchievementState::Completed
-			},
-		})
+	) {
+		let completed = AccountAchievements::<T>::mutate(account, achievement, |maybe_value| {
+			let value = match maybe_value {
+				None => AchievementState::new(achievement.target_for()),
+				Some(AchievementState::Completed) => return false,
+				Some(value) => *value,
+			};
+			let updated_value = value.update(update_amount);
+			*maybe_value = Some(updated_value);
+			updated_value == AchievementState::Completed
+		});
+
+		if completed {
+			Self::deposit_event(Event::AchievementCompleted(account.clone(), achievement));
+		}
 	}
 }
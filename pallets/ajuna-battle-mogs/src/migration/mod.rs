@@ -16,8 +16,10 @@
 
 use super::*;
 
+pub mod nft_import;
+pub mod v2;
+
 // The current storage version.
-pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
-#[allow(dead_code)]
-const LOG_TARGET: &str = "runtime::ajuna-battle-mogs";
+pub(crate) const LOG_TARGET: &str = "runtime::ajuna-battle-mogs";
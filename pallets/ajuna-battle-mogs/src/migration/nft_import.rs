@@ -0,0 +1,42 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_support::traits::OnRuntimeUpgrade;
+
+/// Starts mirroring the mogwais existing before mirroring was enabled, see [`NftImport`].
+///
+/// The import doesn't depend on the storage version, so it is added to the migrations of the
+/// runtime upgrade enabling the `NftHandler`.
+pub struct ImportMogwaiNfts<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for ImportMogwaiNfts<T> {
+	fn on_runtime_upgrade() -> Weight {
+		if !T::NftHandler::ENABLED {
+			log::warn!(target: LOG_TARGET, "nft mirroring is disabled, skipping the import");
+			return Weight::zero()
+		}
+
+		if NftImport::<T>::exists() {
+			return T::DbWeight::get().reads(1)
+		}
+
+		NftImport::<T>::put(NftImportProgress::Started);
+		log::info!(target: LOG_TARGET, "importing mogwais as nfts in the next blocks");
+
+		T::DbWeight::get().reads_writes(1, 1)
+	}
+}
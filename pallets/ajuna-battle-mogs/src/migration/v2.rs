@@ -0,0 +1,39 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use frame_support::traits::OnRuntimeUpgrade;
+
+/// The organizer account, replaced by the `MintOrigin` and `AdminOrigin` of the pallet.
+#[frame_support::storage_alias]
+type Organizer<T: Config> = StorageValue<Pallet<T>, <T as frame_system::Config>::AccountId>;
+
+/// Removes the organizer, minting keeps the previous open behaviour for free.
+pub struct MigrateToV2<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+	fn on_runtime_upgrade() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() != 1 {
+			return T::DbWeight::get().reads(1)
+		}
+
+		Organizer::<T>::kill();
+		CurrentMintPolicy::<T>::put(MintPolicy::default());
+		StorageVersion::new(2).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(1, 3)
+	}
+}
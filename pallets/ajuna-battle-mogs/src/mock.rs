@@ -14,16 +14,22 @@
 // You should have received a copy of the GNU Affero General Public License
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
-use crate::{self as pallet_battle_mogs};
+use crate::{
+	self as pallet_battle_mogs, AccountAchievement, AchievementReward, MogwaiGeneration,
+	MogwaiNftHandler, MogwaiOf, NftItemId, PhaseType, RarityType,
+};
 use frame_support::{
-	parameter_types,
+	ord_parameter_types, parameter_types,
 	traits::{ConstU16, ConstU64, OnFinalize, OnInitialize},
+	PalletId,
 };
+use frame_system::{EnsureRoot, EnsureSignedBy};
 use sp_core::H256;
 use sp_runtime::{
-	traits::{BlakeTwo256, IdentityLookup},
-	BuildStorage,
+	traits::{BlakeTwo256, Convert, IdentityLookup},
+	BuildStorage, DispatchResult,
 };
+use std::collections::BTreeMap;
 
 pub type MockBlock = frame_system::mocking::MockBlock<Test>;
 pub type MockAccountId = u32;
@@ -99,10 +105,122 @@ impl pallet_balances::Config for Test {
 
 impl pallet_insecure_randomness_collective_flip::Config for Test {}
 
+pub const ACHIEVEMENT_REWARD: MockBalance = 1_000;
+
+pub struct MockAchievementRewards;
+
+impl Convert<AccountAchievement, Option<AchievementReward<MockBalance>>>
+	for MockAchievementRewards
+{
+	fn convert(achievement: AccountAchievement) -> Option<AchievementReward<MockBalance>> {
+		match achievement {
+			AccountAchievement::EggHatcher => Some(AchievementReward::SpecialEgg(RarityType::Rare)),
+			AccountAchievement::Morpheus => Some(AchievementReward::ConfigUpgrade(1)),
+			AccountAchievement::LegendBreeder => None,
+			_ => Some(AchievementReward::Currency(ACHIEVEMENT_REWARD)),
+		}
+	}
+}
+
+/// Owner and attributes of a mirrored mogwai.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MockNft {
+	pub owner: MockAccountId,
+	pub rarity: RarityType,
+	pub generation: MogwaiGeneration,
+	pub phase: PhaseType,
+}
+
+parameter_types! {
+	pub static MockNfts: BTreeMap<NftItemId, MockNft> = BTreeMap::new();
+}
+
+pub struct MockNftHandler;
+
+impl MogwaiNftHandler<MockAccountId, MogwaiOf<Test>> for MockNftHandler {
+	const ENABLED: bool = true;
+
+	fn mint(owner: &MockAccountId, item: NftItemId, mogwai: &MogwaiOf<Test>) -> DispatchResult {
+		let mut nfts = MockNfts::get();
+		assert!(!nfts.contains_key(&item), "item minted twice");
+		nfts.insert(
+			item,
+			MockNft {
+				owner: *owner,
+				rarity: mogwai.rarity,
+				generation: mogwai.generation,
+				phase: mogwai.phase,
+			},
+		);
+		MockNfts::set(nfts);
+		Ok(())
+	}
+
+	fn update(item: NftItemId, mogwai: &MogwaiOf<Test>) -> DispatchResult {
+		let mut nfts = MockNfts::get();
+		let nft = nfts.get_mut(&item).expect("item should exist");
+		nft.rarity = mogwai.rarity;
+		nft.generation = mogwai.generation;
+		nft.phase = mogwai.phase;
+		MockNfts::set(nfts);
+		Ok(())
+	}
+
+	fn burn(item: NftItemId) -> DispatchResult {
+		let mut nfts = MockNfts::get();
+		nfts.remove(&item).expect("item should exist");
+		MockNfts::set(nfts);
+		Ok(())
+	}
+
+	fn transfer(item: NftItemId, to: &MockAccountId) -> DispatchResult {
+		let mut nfts = MockNfts::get();
+		nfts.get_mut(&item).expect("item should exist").owner = *to;
+		MockNfts::set(nfts);
+		Ok(())
+	}
+}
+
+parameter_types! {
+	pub const BattleMogsPalletId: PalletId = PalletId(*b"aj/mogwi");
+	pub const WorldEventNotice: u64 = 200;
+	pub const WorldEventMaxDelay: u64 = 10;
+	pub const WorldEventDuration: u64 = 20;
+	pub const MaxLendingDuration: u64 = 1_000;
+	pub const MaxLendingExpiriesPerBlock: u32 = 2;
+	pub const MaxBatchSize: u32 = 4;
+	pub const OfferDuration: u64 = 100;
+	pub const MaxOffersPerMogwai: u32 = 2;
+	pub const MaxOfferedMogwais: u32 = 2;
+	pub const MaxOfferExpiriesPerBlock: u32 = 3;
+	pub const MaxNftImportsPerBlock: u32 = 1;
+}
+
+ord_parameter_types! {
+	pub const Organizer: MockAccountId = ALICE;
+}
+
 impl pallet_battle_mogs::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type Randomness = Randomness;
+	type MintOrigin = EnsureSignedBy<Organizer, MockAccountId>;
+	type AdminOrigin = EnsureRoot<MockAccountId>;
+	type FeeDestination = pallet_battle_mogs::DepositToPallet<Self>;
+	type PalletId = BattleMogsPalletId;
+	type WorldEventNotice = WorldEventNotice;
+	type WorldEventMaxDelay = WorldEventMaxDelay;
+	type WorldEventDuration = WorldEventDuration;
+	type MaxLendingDuration = MaxLendingDuration;
+	type MaxLendingExpiriesPerBlock = MaxLendingExpiriesPerBlock;
+	type MaxBatchSize = MaxBatchSize;
+	type OfferDuration = OfferDuration;
+	type MaxOffersPerMogwai = MaxOffersPerMogwai;
+	type MaxOfferedMogwais = MaxOfferedMogwais;
+	type MaxOfferExpiriesPerBlock = MaxOfferExpiriesPerBlock;
+	type MaxNftImportsPerBlock = MaxNftImportsPerBlock;
+	type NftHandler = MockNftHandler;
+	type AchievementRewards = MockAchievementRewards;
 	type WeightInfo = ();
 }
 
@@ -124,11 +242,10 @@ impl ExtBuilder {
 			.assimilate_storage(&mut t)
 			.unwrap();
 
+		MockNfts::set(BTreeMap::new());
+
 		let mut ext = sp_io::TestExternalities::new(t);
 		ext.execute_with(|| System::set_block_number(1));
-		ext.execute_with(|| {
-			let _ = BattleMogs::set_organizer(RuntimeOrigin::root(), ALICE);
-		});
 
 		ext
 	}
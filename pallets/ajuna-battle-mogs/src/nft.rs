@@ -0,0 +1,142 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Mirroring of mogwais as items of an external NFT collection.
+
+use crate::*;
+use frame_support::traits::tokens::nonfungibles_v2::{Inspect, Mutate, Transfer};
+use sp_std::marker::PhantomData;
+
+/// The id of the item mirroring a mogwai, allocated sequentially by the pallet.
+pub type NftItemId = u32;
+
+/// Attribute key holding the [`RarityType`] of a mirrored mogwai.
+pub const ATTRIBUTE_RARITY: &[u8] = b"rarity";
+/// Attribute key holding the [`MogwaiGeneration`] of a mirrored mogwai.
+pub const ATTRIBUTE_GENERATION: &[u8] = b"generation";
+/// Attribute key holding the [`PhaseType`] of a mirrored mogwai.
+pub const ATTRIBUTE_PHASE: &[u8] = b"phase";
+
+/// Keeps an NFT representation of each mogwai in sync with the pallet.
+pub trait MogwaiNftHandler<AccountId, Mogwai> {
+	/// Whether mogwais are mirrored at all, the pallet skips all NFT bookkeeping otherwise.
+	const ENABLED: bool;
+
+	/// Mint `item` representing `mogwai` to `owner`.
+	fn mint(owner: &AccountId, item: NftItemId, mogwai: &Mogwai) -> DispatchResult;
+
+	/// Refresh the attributes of `item` after `mogwai` changed.
+	fn update(item: NftItemId, mogwai: &Mogwai) -> DispatchResult;
+
+	/// Burn `item` once its mogwai has been removed.
+	fn burn(item: NftItemId) -> DispatchResult;
+
+	/// Move `item` to the new owner of its mogwai.
+	fn transfer(item: NftItemId, to: &AccountId) -> DispatchResult;
+}
+
+impl<AccountId, Mogwai> MogwaiNftHandler<AccountId, Mogwai> for () {
+	const ENABLED: bool = false;
+
+	fn mint(_: &AccountId, _: NftItemId, _: &Mogwai) -> DispatchResult {
+		Ok(())
+	}
+
+	fn update(_: NftItemId, _: &Mogwai) -> DispatchResult {
+		Ok(())
+	}
+
+	fn burn(_: NftItemId) -> DispatchResult {
+		Ok(())
+	}
+
+	fn transfer(_: NftItemId, _: &AccountId) -> DispatchResult {
+		Ok(())
+	}
+}
+
+/// Mirrors mogwais as items of the dedicated `Collection` in `Nfts`, usually `pallet_nfts`.
+///
+/// The collection has to exist beforehand and be owned by an account able to cover the item
+/// deposits, e.g. the pallet's account.
+pub struct NftsAdapter<Nfts, Collection, ItemConfig>(PhantomData<(Nfts, Collection, ItemConfig)>);
+
+impl<Hash, BlockNumber, Balance, AccountId, Nfts, Collection, ItemConfig>
+	MogwaiNftHandler<
+		AccountId,
+		MogwaiStruct<
+			Hash,
+			BlockNumber,
+			Balance,
+			MogwaiGeneration,
+			RarityType,
+			PhaseType,
+			AccountId,
+		>,
+	> for NftsAdapter<Nfts, Collection, ItemConfig>
+where
+	Nfts: Mutate<AccountId, ItemConfig> + Transfer<AccountId>,
+	<Nfts as Inspect<AccountId>>::ItemId: From<NftItemId>,
+	Collection: Get<<Nfts as Inspect<AccountId>>::CollectionId>,
+	ItemConfig: Default,
+{
+	const ENABLED: bool = true;
+
+	fn mint(
+		owner: &AccountId,
+		item: NftItemId,
+		mogwai: &MogwaiStruct<
+			Hash,
+			BlockNumber,
+			Balance,
+			MogwaiGeneration,
+			RarityType,
+			PhaseType,
+			AccountId,
+		>,
+	) -> DispatchResult {
+		Nfts::mint_into(&Collection::get(), &item.into(), owner, &ItemConfig::default(), true)?;
+		Self::update(item, mogwai)
+	}
+
+	fn update(
+		item: NftItemId,
+		mogwai: &MogwaiStruct<
+			Hash,
+			BlockNumber,
+			Balance,
+			MogwaiGeneration,
+			RarityType,
+			PhaseType,
+			AccountId,
+		>,
+	) -> DispatchResult {
+		let collection = Collection::get();
+		let item = item.into();
+
+		Nfts::set_attribute(&collection, &item, ATTRIBUTE_RARITY, &[mogwai.rarity as u8])?;
+		Nfts::set_attribute(&collection, &item, ATTRIBUTE_GENERATION, &[mogwai.generation as u8])?;
+		Nfts::set_attribute(&collection, &item, ATTRIBUTE_PHASE, &[mogwai.phase as u8])
+	}
+
+	fn burn(item: NftItemId) -> DispatchResult {
+		Nfts::burn(&Collection::get(), &item.into(), None)
+	}
+
+	fn transfer(item: NftItemId, to: &AccountId) -> DispatchResult {
+		<Nfts as Transfer<AccountId>>::transfer(&Collection::get(), &item.into(), to)
+	}
+}
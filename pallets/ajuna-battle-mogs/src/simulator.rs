@@ -0,0 +1,408 @@
+// Ajuna Node
+// Copyright (C) 2022 BlogaTech AG
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU Affero General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU Affero General Public License for more details.
+
+// You should have received a copy of the GNU Affero General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Deterministic breeding simulator, used to balance the breeding algorithm and pricing.
+//!
+//! Populations of mogwais are bred for many rounds using the same [`Breeding`], [`Generation`]
+//! and [`Pricing`] functions as the pallet, with all randomness derived from a seed so that
+//! reports can be diffed between versions of the algorithm.
+
+use crate::{
+	Balance, BreedType, Breeding, Dna, GameEventType, Generation, MogwaiGeneration, PhaseType,
+	Pricing, RarityType,
+};
+use parity_scale_codec::Encode;
+use serde::Serialize;
+use sp_io::hashing::blake2_256;
+use std::fmt::Write;
+
+const RARITY_COUNT: usize = 6;
+const GENERATION_COUNT: usize = 16;
+
+/// Parameters of a simulation.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SimulationConfig {
+	/// Seed all randomness of the simulation is derived from.
+	pub seed: u64,
+	/// Number of independent populations simulated, their statistics are summed up.
+	pub trials: u32,
+	/// Number of eggs each population starts with.
+	pub initial_population: u32,
+	/// Population size above which the oldest mogwais get sacrificed.
+	pub max_population: u32,
+	/// Number of rounds each population is bred for.
+	pub rounds: u32,
+	/// Number of pairings per round.
+	pub breeds_per_round: u32,
+}
+
+impl Default for SimulationConfig {
+	fn default() -> Self {
+		Self {
+			seed: 0,
+			trials: 10,
+			initial_population: 24,
+			max_population: 1_000,
+			rounds: 1_000,
+			breeds_per_round: 10,
+		}
+	}
+}
+
+/// Statistics of a round, summed over all trials.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct RoundStats {
+	pub round: u32,
+	pub population: u64,
+	/// Number of mogwais per [`RarityType`], from `Common` to `Mythical`.
+	pub rarity: [u64; RARITY_COUNT],
+	/// Number of mogwais per [`MogwaiGeneration`], from `First` to `Sixteenth`.
+	pub generation: [u64; GENERATION_COUNT],
+	/// Intrinsic value held by the living mogwais.
+	pub total_intrinsic: Balance,
+	/// Pairing prices paid so far.
+	pub spent: Balance,
+	/// Currency returned by sacrificed mogwais so far.
+	pub returned: Balance,
+}
+
+impl RoundStats {
+	/// Average intrinsic value of a living mogwai, its growth is the intrinsic inflation.
+	pub fn average_intrinsic(&self) -> Balance {
+		self.total_intrinsic.checked_div(self.population.into()).unwrap_or_default()
+	}
+}
+
+/// The outcome of a simulation.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SimulationReport {
+	pub config: SimulationConfig,
+	pub rounds: Vec<RoundStats>,
+	/// Number of trials in which a `Mythical` mogwai appeared.
+	pub mythical_reached: u32,
+	/// Average pairing prices paid until the first `Mythical` mogwai appeared.
+	pub expected_mythical_cost: Option<Balance>,
+	/// Average round in which the first `Mythical` mogwai appeared.
+	pub expected_mythical_round: Option<u32>,
+}
+
+impl SimulationReport {
+	/// One line per round, with rarity and generation histograms in separate columns.
+	pub fn to_csv(&self) -> String {
+		let mut csv = String::from("round,population");
+		for rarity in ["common", "uncommon", "rare", "epic", "legendary", "mythical"] {
+			let _ = write!(csv, ",{}", rarity);
+		}
+		for generation in 1..=GENERATION_COUNT {
+			let _ = write!(csv, ",gen_{}", generation);
+		}
+		csv.push_str(",total_intrinsic,average_intrinsic,spent,returned\n");
+
+		for stats in &self.rounds {
+			let _ = write!(csv, "{},{}", stats.round, stats.population);
+			for count in stats.rarity.iter().chain(stats.generation.iter()) {
+				let _ = write!(csv, ",{}", count);
+			}
+			let _ = writeln!(
+				csv,
+				",{},{},{},{}",
+				stats.total_intrinsic,
+				stats.average_intrinsic(),
+				stats.spent,
+				stats.returned
+			);
+		}
+
+		csv
+	}
+
+	pub fn to_json(&self) -> String {
+		serde_json::to_string_pretty(self).expect("report only contains serializable types; qed")
+	}
+}
+
+#[derive(Clone)]
+struct SimMogwai {
+	dna: Dna,
+	generation: MogwaiGeneration,
+	rarity: RarityType,
+	phase: PhaseType,
+	intrinsic: Balance,
+}
+
+/// Hash based random source, every value only depends on the seed and how many were drawn.
+struct SeededRandom {
+	seed: u64,
+	trial: u32,
+	nonce: u64,
+}
+
+impl SeededRandom {
+	fn hash(&mut self) -> [u8; 32] {
+		self.nonce += 1;
+		blake2_256(&(b"mogwai_simulator", self.seed, self.trial, self.nonce).encode())
+	}
+
+	fn below(&mut self, bound: usize) -> usize {
+		let hash = self.hash();
+		let value = u64::from_le_bytes(hash[0..8].try_into().expect("hash has 32 bytes; qed"));
+		(value % bound as u64) as usize
+	}
+}
+
+struct Population {
+	random: SeededRandom,
+	mogwais: Vec<SimMogwai>,
+	block_number: u32,
+	spent: Balance,
+	returned: Balance,
+	first_mythical: Option<(u32, Balance)>,
+}
+
+impl Population {
+	fn new(config: &SimulationConfig, trial: u32) -> Self {
+		let mut population = Self {
+			random: SeededRandom { seed: config.seed, trial, nonce: 0 },
+			mogwais: Vec::new(),
+			block_number: 0,
+			spent: 0,
+			returned: 0,
+			first_mythical: None,
+		};
+
+		for _ in 0..config.initial_population {
+			let egg = population.new_egg();
+			population.mogwais.push(egg);
+		}
+
+		population
+	}
+
+	/// Mirrors the egg creation of the pallet.
+	fn new_egg(&mut self) -> SimMogwai {
+		let random_hash_1 = self.random.hash();
+		let random_hash_2 = self.random.hash();
+
+		let (rarity, next_gen, max_rarity) = Generation::next_gen(
+			MogwaiGeneration::First,
+			RarityType::Common,
+			MogwaiGeneration::First,
+			RarityType::Common,
+			&random_hash_1,
+		);
+		let breed_type = BreedType::from_moon_cycle(self.block_number);
+
+		SimMogwai {
+			dna: Breeding::pairing(breed_type, &random_hash_1, &random_hash_2),
+			generation: next_gen,
+			rarity: RarityType::from(((max_rarity as u8) << 4) + rarity as u8),
+			phase: PhaseType::Bred,
+			intrinsic: 0,
+		}
+	}
+
+	fn run_round(&mut self, round: u32, config: &SimulationConfig) {
+		// Eggs are ready to hatch after each round.
+		self.block_number += GameEventType::time_till(GameEventType::Hatch) as u32;
+
+		for index in 0..self.mogwais.len() {
+			if self.mogwais[index].phase == PhaseType::Bred {
+				self.hatch(index);
+			}
+		}
+		self.note_mythical(round);
+
+		for _ in 0..config.breeds_per_round {
+			self.block_number += 1;
+			self.breed();
+		}
+		self.note_mythical(round);
+
+		while self.mogwais.len() > config.max_population as usize {
+			self.sacrifice_oldest();
+		}
+	}
+
+	/// Mirrors `hatch_mogwai`, with a random block hash.
+	fn hatch(&mut self, index: usize) {
+		let block_hash = self.random.hash();
+		let mogwai = &mut self.mogwais[index];
+
+		mogwai.dna = Breeding::segmenting(mogwai.dna, block_hash);
+		mogwai.rarity = Breeding::bake(mogwai.rarity, block_hash);
+		mogwai.phase = PhaseType::Hatched;
+	}
+
+	/// Mirrors `breed_mogwai` for two random hatched mogwais.
+	fn breed(&mut self) {
+		let hatched = self
+			.mogwais
+			.iter()
+			.enumerate()
+			.filter(|(_, mogwai)| mogwai.phase != PhaseType::Bred)
+			.map(|(index, _)| index)
+			.collect::<Vec<_>>();
+		if hatched.len() < 2 {
+			return
+		}
+
+		let index_1 = hatched[self.random.below(hatched.len())];
+		let index_2 = hatched[self.random.below(hatched.len())];
+		if index_1 == index_2 {
+			return
+		}
+
+		let (mogwai_1, mogwai_2) = (&self.mogwais[index_1], &self.mogwais[index_2]);
+		let mogwai_id = self.random.hash();
+
+		let (rarity, next_gen, max_rarity) = Generation::next_gen(
+			mogwai_1.generation,
+			mogwai_1.rarity,
+			mogwai_2.generation,
+			mogwai_2.rarity,
+			&mogwai_id,
+		);
+		let breed_type = BreedType::from_moon_cycle(self.block_number);
+		let pairing_price = Pricing::pairing(mogwai_1.rarity, mogwai_2.rarity);
+
+		let child = SimMogwai {
+			dna: Breeding::pairing(breed_type, mogwai_1.dna.stats(), mogwai_2.dna.stats()),
+			generation: next_gen,
+			rarity: RarityType::from(((max_rarity as u8) << 4) + rarity as u8),
+			phase: PhaseType::Bred,
+			intrinsic: 0,
+		};
+
+		self.spent = self.spent.saturating_add(pairing_price);
+		self.mogwais[index_2].intrinsic =
+			self.mogwais[index_2].intrinsic.saturating_add(pairing_price);
+		self.mogwais.push(child);
+	}
+
+	/// Mirrors `sacrifice`, the oldest mogwai returns part of its intrinsic value.
+	fn sacrifice_oldest(&mut self) {
+		let mogwai = self.mogwais.remove(0);
+		let returned = mogwai
+			.intrinsic
+			.checked_div(Pricing::intrinsic_return(mogwai.phase))
+			.unwrap_or_default();
+
+		self.returned = self.returned.saturating_add(returned);
+	}
+
+	fn note_mythical(&mut self, round: u32) {
+		if self.first_mythical.is_none() &&
+			self.mogwais.iter().any(|mogwai| mogwai.rarity == RarityType::Mythical)
+		{
+			self.first_mythical = Some((round, self.spent));
+		}
+	}
+
+	fn add_stats(&self, stats: &mut RoundStats) {
+		stats.population += self.mogwais.len() as u64;
+		for mogwai in &self.mogwais {
+			stats.rarity[mogwai.rarity as usize] += 1;
+			stats.generation[mogwai.generation as usize - 1] += 1;
+			stats.total_intrinsic = stats.total_intrinsic.saturating_add(mogwai.intrinsic);
+		}
+		stats.spent = stats.spent.saturating_add(self.spent);
+		stats.returned = stats.returned.saturating_add(self.returned);
+	}
+}
+
+/// Run the simulation described by `config`.
+pub fn simulate(config: &SimulationConfig) -> SimulationReport {
+	let mut rounds = (1..=config.rounds)
+		.map(|round| RoundStats { round, ..Default::default() })
+		.collect::<Vec<_>>();
+	let mut mythicals = Vec::new();
+
+	for trial in 0..config.trials {
+		let mut population = Population::new(config, trial);
+
+		for stats in rounds.iter_mut() {
+			population.run_round(stats.round, config);
+			population.add_stats(stats);
+		}
+
+		mythicals.extend(population.first_mythical);
+	}
+
+	let mythical_reached = mythicals.len() as u32;
+	let (expected_mythical_round, expected_mythical_cost) = if mythicals.is_empty() {
+		(None, None)
+	} else {
+		let rounds_sum = mythicals.iter().map(|(round, _)| *round as u64).sum::<u64>();
+		let cost_sum =
+			mythicals.iter().fold(0, |sum: Balance, (_, cost)| sum.saturating_add(*cost));
+		(
+			Some((rounds_sum / mythical_reached as u64) as u32),
+			Some(cost_sum / mythical_reached as Balance),
+		)
+	};
+
+	SimulationReport {
+		config: config.clone(),
+		rounds,
+		mythical_reached,
+		expected_mythical_cost,
+		expected_mythical_round,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn small_config(seed: u64) -> SimulationConfig {
+		SimulationConfig {
+			seed,
+			trials: 2,
+			initial_population: 6,
+			max_population: 20,
+			rounds: 30,
+			breeds_per_round: 4,
+		}
+	}
+
+	#[test]
+	fn simulation_is_deterministic() {
+		assert_eq!(simulate(&small_config(42)), simulate(&small_config(42)));
+		assert_ne!(simulate(&small_config(42)).rounds, simulate(&small_config(43)).rounds);
+	}
+
+	#[test]
+	fn histograms_cover_the_population() {
+		let report = simulate(&small_config(7));
+
+		assert_eq!(report.rounds.len(), 30);
+		for stats in &report.rounds {
+			assert_eq!(stats.rarity.iter().sum::<u64>(), stats.population);
+			assert_eq!(stats.generation.iter().sum::<u64>(), stats.population);
+			assert!(stats.population <= 2 * 20);
+		}
+	}
+
+	#[test]
+	fn csv_has_a_line_per_round() {
+		let report = simulate(&small_config(7));
+		let csv = report.to_csv();
+
+		assert_eq!(csv.lines().count(), 31);
+		assert!(csv.starts_with("round,population,common,"));
+		assert!(report.to_json().contains("\"expected_mythical_cost\""));
+	}
+}
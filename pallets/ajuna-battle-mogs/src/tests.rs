@@ -15,10 +15,12 @@
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
 use crate::{
-	mock, mock::*, AccountAchievement, AchievementState, Error, Event, GameEventType, MogwaiPrices,
-	Mogwais, PhaseType, RarityType,
+	mock, mock::*, AccountAchievement, AccountAchievements, AchievementState, Error, Event,
+	GameConfig, GameEventType, MintPolicy, MogwaiPrices, Mogwais, PhaseType, Pricing, RarityType,
+	DMOGS,
 };
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::Currency};
+use sp_runtime::DispatchError;
 
 #[cfg(test)]
 mod update_config {
@@ -44,6 +46,42 @@ mod update_config {
 		});
 	}
 
+	#[test]
+	fn config_update_fee_goes_to_pallet_account() {
+		ExtBuilder.build().execute_with(|| {
+			let pallet_balance = Balances::free_balance(BattleMogs::account_id());
+
+			assert_ok!(BattleMogs::update_config(RuntimeOrigin::signed(BOB), 1, Some(1)));
+
+			assert_eq!(
+				Balances::free_balance(BattleMogs::account_id()),
+				pallet_balance + 5 * DMOGS as MockBalance
+			);
+		});
+	}
+
+	#[test]
+	fn config_update_price_follows_rule() {
+		ExtBuilder.build().execute_with(|| {
+			let rule = GameConfig::rule(1).expect("mogwai limit should be configurable");
+
+			for value in 1..=rule.max {
+				let balance = Balances::free_balance(BOB);
+				assert_ok!(BattleMogs::update_config(RuntimeOrigin::signed(BOB), 1, Some(value)));
+				assert_eq!(
+					Balances::free_balance(BOB),
+					balance - rule.update_price(value) as MockBalance
+				);
+			}
+
+			assert_eq!(rule.update_price(3), 20 * DMOGS);
+			assert_noop!(
+				BattleMogs::update_config(RuntimeOrigin::signed(BOB), 1, None),
+				Error::<Test>::ConfigUpdateInvalid
+			);
+		});
+	}
+
 	#[test]
 	fn config_update_fails_validation() {
 		ExtBuilder.build().execute_with(|| {
@@ -188,6 +226,85 @@ mod create_mogwai {
 		});
 	}
 
+	#[test]
+	fn create_mogwai_charges_open_mint_price() {
+		ExtBuilder.build().execute_with(|| {
+			let price = 1_000;
+			assert_ok!(BattleMogs::set_mint_policy(
+				RuntimeOrigin::root(),
+				MintPolicy::Open { price }
+			));
+
+			let balance = Balances::free_balance(BOB);
+			let pallet_balance = Balances::free_balance(BattleMogs::account_id());
+
+			assert_ok!(BattleMogs::create_mogwai(RuntimeOrigin::signed(BOB)));
+
+			assert_eq!(Balances::free_balance(BOB), balance - price);
+			assert_eq!(Balances::free_balance(BattleMogs::account_id()), pallet_balance + price);
+		});
+	}
+
+	#[test]
+	fn create_mogwai_restricted_to_mint_origin() {
+		ExtBuilder.build().execute_with(|| {
+			assert_ok!(BattleMogs::set_mint_policy(RuntimeOrigin::root(), MintPolicy::Restricted));
+			System::assert_last_event(mock::RuntimeEvent::BattleMogs(crate::Event::MintPolicySet(
+				MintPolicy::Restricted,
+			)));
+
+			assert_noop!(
+				BattleMogs::create_mogwai(RuntimeOrigin::signed(BOB)),
+				DispatchError::BadOrigin
+			);
+
+			let balance = Balances::free_balance(ALICE);
+			assert_ok!(BattleMogs::create_mogwai(RuntimeOrigin::signed(ALICE)));
+			assert_eq!(Balances::free_balance(ALICE), balance);
+			assert_eq!(BattleMogs::owned_mogwais_count(ALICE), 1);
+		});
+	}
+
+	#[test]
+	fn set_mint_policy_only_admin() {
+		ExtBuilder.build().execute_with(|| {
+			assert_noop!(
+				BattleMogs::set_mint_policy(RuntimeOrigin::signed(ALICE), MintPolicy::Restricted),
+				DispatchError::BadOrigin
+			);
+		});
+	}
+
+	#[test]
+	fn create_mogwai_beyond_former_limit() {
+		ExtBuilder.build().execute_with(|| {
+			let account = ALICE;
+			let max_value = GameConfig::rule(1).unwrap().max;
+
+			for value in 1..=max_value {
+				assert_ok!(BattleMogs::update_config(
+					RuntimeOrigin::signed(account),
+					1,
+					Some(value)
+				));
+			}
+
+			let mogwai_limit = BattleMogs::config_value(account, 1);
+			assert_eq!(mogwai_limit, GameConfig::max_mogwais());
+			assert!(mogwai_limit > 24);
+
+			for _ in 0..mogwai_limit {
+				assert_ok!(BattleMogs::create_mogwai(RuntimeOrigin::signed(account)));
+			}
+			assert_eq!(BattleMogs::owners(account).len() as u32, mogwai_limit);
+
+			assert_noop!(
+				BattleMogs::create_mogwai(RuntimeOrigin::signed(account)),
+				Error::<Test>::MaxMogwaisInAccount
+			);
+		});
+	}
+
 	#[test]
 	fn create_mogwai_cannot_go_over_limit() {
 		ExtBuilder.build().execute_with(|| {
@@ -229,7 +346,7 @@ mod remove_mogwai {
 			let account = ALICE;
 			let mogwai_id = create_mogwai(account);
 
-			assert_ok!(BattleMogs::remove_mogwai(RuntimeOrigin::signed(account), mogwai_id));
+			assert_ok!(BattleMogs::remove_mogwai(RuntimeOrigin::root(), mogwai_id));
 
 			assert_eq!(BattleMogs::mogwai(mogwai_id), None);
 
@@ -244,15 +361,14 @@ mod remove_mogwai {
 	}
 
 	#[test]
-	fn remove_mogwai_only_owner_can_remove() {
+	fn remove_mogwai_only_admin_can_remove() {
 		ExtBuilder.build().execute_with(|| {
 			let account = ALICE;
-			let other = CHARLIE;
 			let mogwai_id = create_mogwai(account);
 
 			assert_noop!(
-				BattleMogs::remove_mogwai(RuntimeOrigin::signed(other), mogwai_id),
-				Error::<Test>::FounderAction
+				BattleMogs::remove_mogwai(RuntimeOrigin::signed(account), mogwai_id),
+				DispatchError::BadOrigin
 			);
 		});
 	}
@@ -269,7 +385,7 @@ mod transfer {
 			let target = BOB;
 			let mogwai_id = create_mogwai(founder);
 
-			assert_ok!(BattleMogs::transfer(RuntimeOrigin::signed(founder), target, mogwai_id));
+			assert_ok!(BattleMogs::transfer(RuntimeOrigin::root(), target, mogwai_id));
 
 			assert_eq!(BattleMogs::mogwai(mogwai_id).unwrap().owner, target);
 
@@ -283,7 +399,7 @@ mod transfer {
 	}
 
 	#[test]
-	fn transfer_only_founder_can_transfer() {
+	fn transfer_only_admin_can_transfer() {
 		ExtBuilder.build().execute_with(|| {
 			let target = ALICE;
 			let sender = CHARLIE;
@@ -291,7 +407,20 @@ mod transfer {
 
 			assert_noop!(
 				BattleMogs::transfer(RuntimeOrigin::signed(sender), target, mogwai_id),
-				Error::<Test>::FounderAction
+				DispatchError::BadOrigin
+			);
+		});
+	}
+
+	#[test]
+	fn transfer_to_current_owner_fails() {
+		ExtBuilder.build().execute_with(|| {
+			let owner = CHARLIE;
+			let mogwai_id = create_mogwai(owner);
+
+			assert_noop!(
+				BattleMogs::transfer(RuntimeOrigin::root(), owner, mogwai_id),
+				Error::<Test>::MogwaiAlreadyOwned
 			);
 		});
 	}
@@ -310,7 +439,7 @@ mod transfer {
 			let mogwai_id = create_mogwai(founder);
 
 			assert_noop!(
-				BattleMogs::transfer(RuntimeOrigin::signed(founder), target, mogwai_id),
+				BattleMogs::transfer(RuntimeOrigin::root(), target, mogwai_id),
 				Error::<Test>::MaxMogwaisInAccount
 			);
 		});
@@ -325,7 +454,7 @@ mod transfer {
 
 			put_mogwai_on_sale(sender, mogwai_id, 1000);
 
-			assert_ok!(BattleMogs::transfer(RuntimeOrigin::signed(sender), target, mogwai_id));
+			assert_ok!(BattleMogs::transfer(RuntimeOrigin::root(), target, mogwai_id));
 			assert!(!MogwaiPrices::<Test>::contains_key(mogwai_id));
 		});
 	}
@@ -1109,3 +1238,1105 @@ mod breed_mogwai {
 		});
 	}
 }
+
+#[cfg(test)]
+mod claim_achievement {
+	use super::*;
+
+	fn complete_achievement(account: MockAccountId, achievement: AccountAchievement) {
+		AccountAchievements::<Test>::insert(account, achievement, AchievementState::Completed);
+	}
+
+	#[test]
+	fn claim_achievement_currency_successfully() {
+		ExtBuilder.build().execute_with(|| {
+			let account = BOB;
+			complete_achievement(account, AccountAchievement::Buyer);
+
+			let pot = BattleMogs::account_id();
+			Balances::make_free_balance_be(&pot, 10 * ACHIEVEMENT_REWARD);
+
+			let balance_before = Balances::free_balance(account);
+
+			assert_ok!(BattleMogs::claim_achievement(
+				RuntimeOrigin::signed(account),
+				AccountAchievement::Buyer
+			));
+
+			assert_eq!(Balances::free_balance(account), balance_before + ACHIEVEMENT_REWARD);
+			assert_eq!(Balances::free_balance(pot), 9 * ACHIEVEMENT_REWARD);
+			assert_eq!(
+				BattleMogs::claimed_achievements(account, AccountAchievement::Buyer),
+				Some(System::block_number())
+			);
+
+			System::assert_last_event(mock::RuntimeEvent::BattleMogs(
+				crate::Event::AchievementClaimed(account, AccountAchievement::Buyer),
+			));
+		});
+	}
+
+	#[test]
+	fn claim_achievement_config_upgrade_successfully() {
+		ExtBuilder.build().execute_with(|| {
+			let account = BOB;
+			let mogwai_limit = BattleMogs::config_value(account, 1);
+			complete_achievement(account, AccountAchievement::Morpheus);
+
+			assert_ok!(BattleMogs::claim_achievement(
+				RuntimeOrigin::signed(account),
+				AccountAchievement::Morpheus
+			));
+
+			assert_eq!(BattleMogs::account_config(account), Some([0, 1, 0, 0, 0, 0, 0, 0, 0, 0]));
+			assert!(BattleMogs::config_value(account, 1) > mogwai_limit);
+		});
+	}
+
+	#[test]
+	fn claim_achievement_config_upgrade_at_max_successfully() {
+		ExtBuilder.build().execute_with(|| {
+			let account = BOB;
+			let max_value = GameConfig::rule(1).unwrap().max;
+			for value in 1..=max_value {
+				assert_ok!(BattleMogs::update_config(
+					RuntimeOrigin::signed(account),
+					1,
+					Some(value)
+				));
+			}
+			let config = BattleMogs::account_config(account);
+			assert_eq!(config.map(|config| config[1]), Some(max_value));
+			complete_achievement(account, AccountAchievement::Morpheus);
+
+			assert_ok!(BattleMogs::claim_achievement(
+				RuntimeOrigin::signed(account),
+				AccountAchievement::Morpheus
+			));
+
+			assert_eq!(BattleMogs::account_config(account), config);
+			assert_eq!(
+				BattleMogs::claimed_achievements(account, AccountAchievement::Morpheus),
+				Some(System::block_number())
+			);
+			System::assert_last_event(mock::RuntimeEvent::BattleMogs(
+				crate::Event::AchievementClaimed(account, AccountAchievement::Morpheus),
+			));
+		});
+	}
+
+	#[test]
+	fn claim_achievement_special_egg_successfully() {
+		ExtBuilder.build().execute_with(|| {
+			let account = BOB;
+			complete_achievement(account, AccountAchievement::EggHatcher);
+
+			assert_ok!(BattleMogs::claim_achievement(
+				RuntimeOrigin::signed(account),
+				AccountAchievement::EggHatcher
+			));
+
+			let mogwai_id =
+				BattleMogs::owners(account).into_iter().next().expect("Should get mogwai id");
+			let mogwai = BattleMogs::mogwai(mogwai_id).expect("Should have found mogwai");
+			assert_eq!(mogwai.rarity, RarityType::Rare);
+			assert_eq!(mogwai.phase, PhaseType::Bred);
+			assert_eq!(BattleMogs::owned_mogwais_count(account), 1);
+		});
+	}
+
+	#[test]
+	fn claim_achievement_pays_out_only_once() {
+		ExtBuilder.build().execute_with(|| {
+			let account = BOB;
+			complete_achievement(account, AccountAchievement::Seller);
+			Balances::make_free_balance_be(&BattleMogs::account_id(), 10 * ACHIEVEMENT_REWARD);
+
+			assert_ok!(BattleMogs::claim_achievement(
+				RuntimeOrigin::signed(account),
+				AccountAchievement::Seller
+			));
+
+			assert_noop!(
+				BattleMogs::claim_achievement(
+					RuntimeOrigin::signed(account),
+					AccountAchievement::Seller
+				),
+				Error::<Test>::AchievementAlreadyClaimed
+			);
+		});
+	}
+
+	#[test]
+	fn claim_achievement_fails_for_achievement_in_progress() {
+		ExtBuilder.build().execute_with(|| {
+			let account = BOB;
+
+			assert_noop!(
+				BattleMogs::claim_achievement(
+					RuntimeOrigin::signed(account),
+					AccountAchievement::Buyer
+				),
+				Error::<Test>::AchievementNotCompleted
+			);
+
+			AccountAchievements::<Test>::insert(
+				account,
+				AccountAchievement::Buyer,
+				AchievementState::new(AccountAchievement::Buyer.target_for()),
+			);
+
+			assert_noop!(
+				BattleMogs::claim_achievement(
+					RuntimeOrigin::signed(account),
+					AccountAchievement::Buyer
+				),
+				Error::<Test>::AchievementNotCompleted
+			);
+		});
+	}
+
+	#[test]
+	fn claim_achievement_fails_without_reward() {
+		ExtBuilder.build().execute_with(|| {
+			let account = BOB;
+			complete_achievement(account, AccountAchievement::LegendBreeder);
+
+			assert_noop!(
+				BattleMogs::claim_achievement(
+					RuntimeOrigin::signed(account),
+					AccountAchievement::LegendBreeder
+				),
+				Error::<Test>::AchievementNoReward
+			);
+		});
+	}
+
+	#[test]
+	fn completing_an_achievement_emits_event() {
+		ExtBuilder.build().execute_with(|| {
+			let account = BOB;
+			let target = AccountAchievement::Buyer.target_for();
+
+			AccountAchievements::<Test>::insert(
+				account,
+				AccountAchievement::Buyer,
+				AchievementState::InProgress { current: target - 1, target },
+			);
+
+			let mogwai_id = create_mogwai(ALICE);
+			put_mogwai_on_sale(ALICE, mogwai_id, 1);
+
+			assert_ok!(BattleMogs::buy_mogwai(RuntimeOrigin::signed(account), mogwai_id, 1));
+
+			assert_eq!(
+				BattleMogs::account_achievements(account, AccountAchievement::Buyer),
+				Some(AchievementState::Completed)
+			);
+			System::assert_has_event(mock::RuntimeEvent::BattleMogs(
+				crate::Event::AchievementCompleted(account, AccountAchievement::Buyer),
+			));
+		});
+	}
+}
+
+#[cfg(test)]
+mod nft_mirroring {
+	use super::*;
+	use crate::{
+		migration::nft_import::ImportMogwaiNfts, MogwaiNftItems, NextNftItemId, NftImport,
+		NftImportProgress,
+	};
+	use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+	fn nft_of(mogwai_id: MockMogwaiId) -> MockNft {
+		let item = BattleMogs::mogwai_nft_item(mogwai_id).expect("mogwai should be mirrored");
+		MockNfts::get().get(&item).cloned().expect("nft should exist")
+	}
+
+	#[test]
+	fn created_mogwai_is_minted_as_nft() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id = create_mogwai(BOB);
+			let mogwai = BattleMogs::mogwai(mogwai_id).unwrap();
+
+			assert_eq!(BattleMogs::mogwai_nft_item(mogwai_id), Some(0));
+			assert_eq!(NextNftItemId::<Test>::get(), 1);
+			assert_eq!(
+				nft_of(mogwai_id),
+				MockNft {
+					owner: BOB,
+					rarity: mogwai.rarity,
+					generation: mogwai.generation,
+					phase: PhaseType::Bred,
+				}
+			);
+		});
+	}
+
+	#[test]
+	fn hatching_updates_nft_attributes() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id = create_mogwai(BOB);
+
+			run_to_block(
+				System::block_number() + GameEventType::time_till(GameEventType::Hatch) as u64,
+			);
+			assert_ok!(BattleMogs::hatch_mogwai(RuntimeOrigin::signed(BOB), mogwai_id));
+
+			let mogwai = BattleMogs::mogwai(mogwai_id).unwrap();
+			let nft = nft_of(mogwai_id);
+			assert_eq!(nft.phase, PhaseType::Hatched);
+			assert_eq!(nft.rarity, mogwai.rarity);
+		});
+	}
+
+	#[test]
+	fn transfer_moves_nft() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id = create_mogwai(BOB);
+
+			assert_ok!(BattleMogs::transfer(RuntimeOrigin::root(), CHARLIE, mogwai_id));
+
+			assert_eq!(nft_of(mogwai_id).owner, CHARLIE);
+		});
+	}
+
+	#[test]
+	fn removing_mogwai_burns_nft() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id = create_mogwai(BOB);
+			let item = BattleMogs::mogwai_nft_item(mogwai_id).unwrap();
+
+			assert_ok!(BattleMogs::remove_mogwai(RuntimeOrigin::root(), mogwai_id));
+
+			assert_eq!(BattleMogs::mogwai_nft_item(mogwai_id), None);
+			assert!(!MockNfts::get().contains_key(&item));
+		});
+	}
+
+	#[test]
+	fn import_migration_mirrors_existing_mogwais() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id_1 = create_mogwai(BOB);
+			let mogwai_id_2 = create_mogwai(CHARLIE);
+			let mogwai_id_3 = create_mogwai(CHARLIE);
+
+			// Simulate mogwais created before mirroring was enabled, one of them mirrored since.
+			let _ = MogwaiNftItems::<Test>::clear(u32::MAX, None);
+			NextNftItemId::<Test>::kill();
+			MockNfts::set(Default::default());
+			assert_ok!(BattleMogs::mint_nft(
+				&BOB,
+				mogwai_id_1,
+				&BattleMogs::mogwai(mogwai_id_1).unwrap()
+			));
+			StorageVersion::new(2).put::<BattleMogs>();
+
+			ImportMogwaiNfts::<Test>::on_runtime_upgrade();
+
+			// The import doesn't depend on the storage version.
+			assert_eq!(BattleMogs::on_chain_storage_version(), StorageVersion::new(2));
+			assert_eq!(NftImport::<Test>::get(), Some(NftImportProgress::Started));
+
+			// A single mogwai is looked at in each block.
+			let now = System::block_number();
+			run_to_block(now + 1);
+			assert!(NftImport::<Test>::get().is_some());
+			run_to_block(now + 3);
+			assert!(BattleMogs::mogwai_nft_item(mogwai_id_2).is_some());
+			assert!(BattleMogs::mogwai_nft_item(mogwai_id_3).is_some());
+
+			run_to_block(now + 4);
+			assert_eq!(NftImport::<Test>::get(), None);
+			assert_eq!(NextNftItemId::<Test>::get(), 3);
+			assert_eq!(BattleMogs::mogwai_nft_item(mogwai_id_1), Some(0));
+			assert_eq!(nft_of(mogwai_id_1).owner, BOB);
+			assert_eq!(nft_of(mogwai_id_2).owner, CHARLIE);
+			assert_eq!(nft_of(mogwai_id_3).owner, CHARLIE);
+		});
+	}
+}
+
+#[cfg(test)]
+mod world_events {
+	use super::*;
+	use crate::{ActiveWorldEvent, BreedType, WorldEvent, WorldEventKind};
+
+	fn activate(kind: WorldEventKind) {
+		let now = System::block_number();
+		ActiveWorldEvent::<Test>::put(WorldEvent { kind, start: now, end: now + 100 });
+	}
+
+	fn hatched_mogwai(owner: MockAccountId) -> MockMogwaiId {
+		let mogwai_id = create_mogwai(owner);
+		run_to_block(
+			System::block_number() + GameEventType::time_till(GameEventType::Hatch) as u64,
+		);
+		assert_ok!(BattleMogs::hatch_mogwai(RuntimeOrigin::signed(owner), mogwai_id));
+		mogwai_id
+	}
+
+	#[test]
+	fn world_event_is_announced_ahead() {
+		ExtBuilder.build().execute_with(|| {
+			run_to_block(2);
+
+			let event = BattleMogs::upcoming_world_event().expect("event should be scheduled");
+			assert!(event.start >= 2 + WorldEventNotice::get());
+			assert!(event.start <= 2 + WorldEventNotice::get() + WorldEventMaxDelay::get());
+			assert_eq!(event.end, event.start + WorldEventDuration::get());
+			assert_eq!(BattleMogs::active_world_event(), None);
+
+			System::assert_has_event(mock::RuntimeEvent::BattleMogs(
+				crate::Event::WorldEventScheduled(event),
+			));
+		});
+	}
+
+	#[test]
+	fn world_event_starts_and_ends() {
+		ExtBuilder.build().execute_with(|| {
+			run_to_block(2);
+			let event = BattleMogs::upcoming_world_event().unwrap();
+
+			run_to_block(event.start);
+			assert_eq!(BattleMogs::active_world_event(), Some(event));
+			assert_eq!(BattleMogs::upcoming_world_event(), None);
+			System::assert_has_event(mock::RuntimeEvent::BattleMogs(
+				crate::Event::WorldEventStarted(event.kind),
+			));
+
+			run_to_block(event.end);
+			assert_eq!(BattleMogs::active_world_event(), None);
+			System::assert_has_event(mock::RuntimeEvent::BattleMogs(
+				crate::Event::WorldEventEnded(event.kind),
+			));
+
+			let next = BattleMogs::upcoming_world_event().expect("next event should be scheduled");
+			assert!(next.start >= event.end + WorldEventNotice::get());
+		});
+	}
+
+	#[test]
+	fn breeding_moon_overrides_breed_type() {
+		ExtBuilder.build().execute_with(|| {
+			let block_number = 25;
+			assert_eq!(BattleMogs::calculate_breedtype(block_number), BreedType::DomRez);
+
+			activate(WorldEventKind::BreedingMoon(BreedType::RezRez));
+
+			assert_eq!(BattleMogs::calculate_breedtype(block_number), BreedType::RezRez);
+		});
+	}
+
+	#[test]
+	fn breeding_moon_halves_pairing_price() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id = hatched_mogwai(BOB);
+			let rarity = BattleMogs::mogwai(mogwai_id).unwrap().rarity;
+
+			activate(WorldEventKind::BreedingMoon(BreedType::DomDom));
+			assert_ok!(BattleMogs::morph_mogwai(RuntimeOrigin::signed(BOB), mogwai_id));
+
+			assert_eq!(
+				BattleMogs::mogwai(mogwai_id).unwrap().intrinsic,
+				(Pricing::pairing(rarity, rarity) / 2) as MockBalance
+			);
+		});
+	}
+
+	#[test]
+	fn rare_hatch_upgrades_rarity() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id = create_mogwai(BOB);
+			run_to_block(
+				System::block_number() + GameEventType::time_till(GameEventType::Hatch) as u64,
+			);
+
+			let mogwai = BattleMogs::mogwai(mogwai_id).unwrap();
+			let block_hash = System::block_hash(System::block_number());
+			let (_, baked_rarity) = BattleMogs::segment_and_bake(mogwai, block_hash);
+
+			activate(WorldEventKind::RareHatch);
+			assert_ok!(BattleMogs::hatch_mogwai(RuntimeOrigin::signed(BOB), mogwai_id));
+
+			assert_eq!(BattleMogs::mogwai(mogwai_id).unwrap().rarity, baked_rarity.upgraded());
+		});
+	}
+}
+
+#[cfg(test)]
+mod lending {
+	use super::*;
+	use crate::{LendingPermissions, LendingTerms, LendingTermsOf};
+
+	const FEE: MockBalance = 500;
+	const DURATION: u64 = 50;
+
+	fn terms(borrower: MockAccountId, breed: bool) -> LendingTermsOf<Test> {
+		LendingTerms {
+			borrower,
+			duration: DURATION,
+			fee: FEE,
+			permissions: LendingPermissions { breed, battle: true },
+		}
+	}
+
+	fn hatched_mogwai(owner: MockAccountId) -> MockMogwaiId {
+		let mogwai_id = create_mogwai(owner);
+		crate::Mogwais::<Test>::mutate(mogwai_id, |maybe_mogwai| {
+			if let Some(ref mut mogwai) = maybe_mogwai {
+				mogwai.phase = PhaseType::Hatched;
+			}
+		});
+		mogwai_id
+	}
+
+	fn lend(lender: MockAccountId, borrower: MockAccountId, breed: bool) -> MockMogwaiId {
+		let mogwai_id = hatched_mogwai(lender);
+		assert_ok!(BattleMogs::offer_lending(
+			RuntimeOrigin::signed(lender),
+			mogwai_id,
+			terms(borrower, breed)
+		));
+		assert_ok!(BattleMogs::borrow_mogwai(RuntimeOrigin::signed(borrower), mogwai_id));
+		mogwai_id
+	}
+
+	#[test]
+	fn borrow_mogwai_pays_fee_to_lender() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id = hatched_mogwai(ALICE);
+			assert_ok!(BattleMogs::offer_lending(
+				RuntimeOrigin::signed(ALICE),
+				mogwai_id,
+				terms(BOB, true)
+			));
+
+			let lender_balance = Balances::free_balance(ALICE);
+			let borrower_balance = Balances::free_balance(BOB);
+
+			assert_ok!(BattleMogs::borrow_mogwai(RuntimeOrigin::signed(BOB), mogwai_id));
+
+			assert_eq!(Balances::free_balance(ALICE), lender_balance + FEE);
+			assert_eq!(Balances::free_balance(BOB), borrower_balance - FEE);
+			assert_eq!(BattleMogs::lending_offers(mogwai_id), None);
+
+			let lending = BattleMogs::lendings(mogwai_id).expect("mogwai should be lent");
+			assert_eq!(lending.lender, ALICE);
+			assert_eq!(lending.borrower, BOB);
+			assert_eq!(lending.expires_at, System::block_number() + DURATION);
+
+			// Ownership stays with the lender.
+			assert_eq!(BattleMogs::mogwai(mogwai_id).unwrap().owner, ALICE);
+
+			System::assert_last_event(mock::RuntimeEvent::BattleMogs(crate::Event::MogwaiLent(
+				ALICE,
+				BOB,
+				mogwai_id,
+				lending.expires_at,
+			)));
+		});
+	}
+
+	#[test]
+	fn borrow_mogwai_only_by_borrower() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id = hatched_mogwai(ALICE);
+			assert_ok!(BattleMogs::offer_lending(
+				RuntimeOrigin::signed(ALICE),
+				mogwai_id,
+				terms(BOB, true)
+			));
+
+			assert_noop!(
+				BattleMogs::borrow_mogwai(RuntimeOrigin::signed(CHARLIE), mogwai_id),
+				Error::<Test>::NotTheBorrower
+			);
+		});
+	}
+
+	#[test]
+	fn offer_lending_fails_with_invalid_duration() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id = hatched_mogwai(ALICE);
+			let mut terms = terms(BOB, true);
+
+			terms.duration = 0;
+			assert_noop!(
+				BattleMogs::offer_lending(RuntimeOrigin::signed(ALICE), mogwai_id, terms.clone()),
+				Error::<Test>::LendingDurationInvalid
+			);
+
+			terms.duration = MaxLendingDuration::get() + 1;
+			assert_noop!(
+				BattleMogs::offer_lending(RuntimeOrigin::signed(ALICE), mogwai_id, terms),
+				Error::<Test>::LendingDurationInvalid
+			);
+		});
+	}
+
+	#[test]
+	fn cancel_lending_offer_successfully() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id = hatched_mogwai(ALICE);
+			assert_ok!(BattleMogs::offer_lending(
+				RuntimeOrigin::signed(ALICE),
+				mogwai_id,
+				terms(BOB, true)
+			));
+
+			assert_ok!(BattleMogs::cancel_lending_offer(RuntimeOrigin::signed(ALICE), mogwai_id));
+
+			assert_noop!(
+				BattleMogs::borrow_mogwai(RuntimeOrigin::signed(BOB), mogwai_id),
+				Error::<Test>::LendingOfferNotFound
+			);
+		});
+	}
+
+	#[test]
+	fn lender_cannot_sacrifice_or_sell_lent_mogwai() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id = lend(ALICE, BOB, true);
+			let other_id = hatched_mogwai(ALICE);
+
+			assert_noop!(
+				BattleMogs::sacrifice(RuntimeOrigin::signed(ALICE), mogwai_id),
+				Error::<Test>::MogwaiLent
+			);
+			assert_noop!(
+				BattleMogs::sacrifice_into(RuntimeOrigin::signed(ALICE), other_id, mogwai_id),
+				Error::<Test>::MogwaiLent
+			);
+			assert_noop!(
+				BattleMogs::set_price(RuntimeOrigin::signed(ALICE), mogwai_id, 1_000),
+				Error::<Test>::MogwaiLent
+			);
+			assert_noop!(
+				BattleMogs::breed_mogwai(RuntimeOrigin::signed(ALICE), mogwai_id, other_id),
+				Error::<Test>::MogwaiLent
+			);
+		});
+	}
+
+	#[test]
+	fn borrower_limited_to_permitted_actions() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id = lend(ALICE, BOB, false);
+			let other_id = hatched_mogwai(BOB);
+
+			assert_noop!(
+				BattleMogs::breed_mogwai(RuntimeOrigin::signed(BOB), mogwai_id, other_id),
+				Error::<Test>::LendingActionNotPermitted
+			);
+			assert_noop!(
+				BattleMogs::sacrifice(RuntimeOrigin::signed(BOB), mogwai_id),
+				Error::<Test>::LendingActionNotPermitted
+			);
+			assert_noop!(
+				BattleMogs::set_price(RuntimeOrigin::signed(BOB), mogwai_id, 1_000),
+				Error::<Test>::LendingActionNotPermitted
+			);
+			assert_noop!(
+				BattleMogs::sacrifice(RuntimeOrigin::signed(CHARLIE), mogwai_id),
+				Error::<Test>::MogwaiNotOwned
+			);
+		});
+	}
+
+	#[test]
+	fn borrower_can_breed_with_permission() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id = lend(ALICE, BOB, true);
+			let other_id = hatched_mogwai(BOB);
+
+			assert_ok!(BattleMogs::breed_mogwai(RuntimeOrigin::signed(BOB), mogwai_id, other_id));
+
+			assert_eq!(BattleMogs::owned_mogwais_count(BOB), 2);
+			assert_eq!(BattleMogs::owned_mogwais_count(ALICE), 1);
+		});
+	}
+
+	#[test]
+	fn lent_mogwai_returns_on_expiry() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id = lend(ALICE, BOB, true);
+			let expires_at = BattleMogs::lendings(mogwai_id).unwrap().expires_at;
+
+			run_to_block(expires_at - 1);
+			assert!(BattleMogs::lendings(mogwai_id).is_some());
+
+			run_to_block(expires_at);
+			assert_eq!(BattleMogs::lendings(mogwai_id), None);
+			System::assert_has_event(mock::RuntimeEvent::BattleMogs(crate::Event::MogwaiReturned(
+				ALICE, BOB, mogwai_id,
+			)));
+
+			assert_ok!(BattleMogs::sacrifice(RuntimeOrigin::signed(ALICE), mogwai_id));
+		});
+	}
+
+	#[test]
+	fn return_mogwai_early() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id = lend(ALICE, BOB, true);
+
+			assert_noop!(
+				BattleMogs::return_mogwai(RuntimeOrigin::signed(ALICE), mogwai_id),
+				Error::<Test>::NotTheBorrower
+			);
+			assert_ok!(BattleMogs::return_mogwai(RuntimeOrigin::signed(BOB), mogwai_id));
+
+			assert_eq!(BattleMogs::lendings(mogwai_id), None);
+			assert_ok!(BattleMogs::set_price(RuntimeOrigin::signed(ALICE), mogwai_id, 1_000));
+		});
+	}
+
+	#[test]
+	fn admin_transfer_ends_lending() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id = lend(ALICE, BOB, true);
+
+			assert_ok!(BattleMogs::transfer(RuntimeOrigin::root(), CHARLIE, mogwai_id));
+
+			assert_eq!(BattleMogs::lendings(mogwai_id), None);
+			assert_ok!(BattleMogs::sacrifice(RuntimeOrigin::signed(CHARLIE), mogwai_id));
+		});
+	}
+}
+
+mod batch {
+	use super::*;
+	use crate::BatchAction;
+	use frame_support::BoundedVec;
+
+	fn batch<Item: Clone>(items: &[Item]) -> BoundedVec<Item, MaxBatchSize> {
+		BoundedVec::truncate_from(items.to_vec())
+	}
+
+	fn hatched_mogwais(owner: MockAccountId, count: usize) -> Vec<MockMogwaiId> {
+		let mogwai_ids = (0..count).map(|_| create_mogwai(owner)).collect::<Vec<_>>();
+		run_to_block(
+			System::block_number() + GameEventType::time_till(GameEventType::Hatch) as u64,
+		);
+		assert_ok!(BattleMogs::hatch_mogwais(RuntimeOrigin::signed(owner), batch(&mogwai_ids)));
+		mogwai_ids
+	}
+
+	#[test]
+	fn hatch_mogwais_reports_each_result() {
+		ExtBuilder.build().execute_with(|| {
+			let ready = create_mogwai(ALICE);
+			run_to_block(
+				System::block_number() + GameEventType::time_till(GameEventType::Hatch) as u64,
+			);
+			let not_ready = create_mogwai(ALICE);
+			let not_owned = create_mogwai(BOB);
+
+			assert_ok!(BattleMogs::hatch_mogwais(
+				RuntimeOrigin::signed(ALICE),
+				batch(&[ready, not_ready, not_owned])
+			));
+
+			assert_eq!(BattleMogs::mogwai(ready).unwrap().phase, PhaseType::Hatched);
+			assert_eq!(BattleMogs::mogwai(not_ready).unwrap().phase, PhaseType::Bred);
+			assert_eq!(BattleMogs::mogwai(not_owned).unwrap().phase, PhaseType::Bred);
+			assert_eq!(
+				last_event(),
+				mock::RuntimeEvent::BattleMogs(Event::BatchExecuted(
+					ALICE,
+					BatchAction::Hatch,
+					batch(&[
+						(ready, Ok(())),
+						(not_ready, Err(Error::<Test>::MogwaiNoHatch.into())),
+						(not_owned, Err(Error::<Test>::MogwaiNotOwned.into())),
+					]),
+				))
+			);
+		});
+	}
+
+	#[test]
+	fn set_and_remove_prices() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_1 = create_mogwai(ALICE);
+			let mogwai_2 = create_mogwai(ALICE);
+			let unknown = MockMogwaiId::repeat_byte(7);
+
+			assert_ok!(BattleMogs::set_prices(
+				RuntimeOrigin::signed(ALICE),
+				batch(&[(mogwai_1, 100), (unknown, 200), (mogwai_2, 300)])
+			));
+			assert_eq!(BattleMogs::mogwai_prices(mogwai_1), Some(100));
+			assert_eq!(BattleMogs::mogwai_prices(mogwai_2), Some(300));
+			System::assert_has_event(mock::RuntimeEvent::BattleMogs(Event::ForSale(
+				ALICE, mogwai_2, 300,
+			)));
+
+			assert_ok!(BattleMogs::remove_price(RuntimeOrigin::signed(ALICE), mogwai_2));
+			assert_ok!(BattleMogs::remove_prices(
+				RuntimeOrigin::signed(ALICE),
+				batch(&[mogwai_1, mogwai_2])
+			));
+			assert_eq!(BattleMogs::mogwai_prices(mogwai_1), None);
+			assert_eq!(
+				last_event(),
+				mock::RuntimeEvent::BattleMogs(Event::BatchExecuted(
+					ALICE,
+					BatchAction::RemovePrice,
+					batch(&[
+						(mogwai_1, Ok(())),
+						(mogwai_2, Err(Error::<Test>::MogwaiNotForSale.into())),
+					]),
+				))
+			);
+		});
+	}
+
+	#[test]
+	fn sacrifice_mogwais_skips_failures() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_ids = hatched_mogwais(CHARLIE, 3);
+			let egg = create_mogwai(CHARLIE);
+			put_mogwai_on_sale(CHARLIE, mogwai_ids[1], 1_000);
+
+			assert_ok!(BattleMogs::sacrifice_mogwais(
+				RuntimeOrigin::signed(CHARLIE),
+				batch(&[mogwai_ids[0], mogwai_ids[1], egg, mogwai_ids[2]])
+			));
+
+			assert_eq!(BattleMogs::mogwai(mogwai_ids[0]), None);
+			assert!(BattleMogs::mogwai(mogwai_ids[1]).is_some());
+			assert!(BattleMogs::mogwai(egg).is_some());
+			assert_eq!(BattleMogs::mogwai(mogwai_ids[2]), None);
+			assert_eq!(BattleMogs::owned_mogwais_count(CHARLIE), 2);
+			assert_eq!(
+				BattleMogs::account_achievements(CHARLIE, AccountAchievement::Sacrificer),
+				Some(AchievementState::InProgress {
+					current: 2,
+					target: AccountAchievement::Sacrificer.target_for()
+				})
+			);
+		});
+	}
+
+	#[test]
+	fn empty_batch_is_rejected() {
+		ExtBuilder.build().execute_with(|| {
+			assert_noop!(
+				BattleMogs::hatch_mogwais(RuntimeOrigin::signed(ALICE), batch(&[])),
+				Error::<Test>::BatchEmpty
+			);
+			assert_noop!(
+				BattleMogs::sacrifice_mogwais(RuntimeOrigin::signed(ALICE), batch(&[])),
+				Error::<Test>::BatchEmpty
+			);
+		});
+	}
+}
+
+mod trade_offers {
+	use super::*;
+	use crate::{TradeOffers, TradeTerms};
+	use frame_support::{traits::ReservableCurrency, BoundedVec};
+
+	fn terms(
+		amount: MockBalance,
+		mogwais: &[MockMogwaiId],
+	) -> TradeTerms<MockBalance, BoundedVec<MockMogwaiId, MaxOfferedMogwais>> {
+		TradeTerms { amount, mogwais: BoundedVec::truncate_from(mogwais.to_vec()) }
+	}
+
+	fn hatched_mogwai(owner: MockAccountId) -> MockMogwaiId {
+		let mogwai_id = create_mogwai(owner);
+		Mogwais::<Test>::mutate(mogwai_id, |maybe_mogwai| {
+			if let Some(ref mut mogwai) = maybe_mogwai {
+				mogwai.phase = PhaseType::Hatched;
+			}
+		});
+		mogwai_id
+	}
+
+	#[test]
+	fn make_offer_reserves_amount() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id = create_mogwai(ALICE);
+
+			assert_ok!(BattleMogs::make_offer(
+				RuntimeOrigin::signed(BOB),
+				mogwai_id,
+				terms(1_000, &[])
+			));
+
+			assert_eq!(Balances::reserved_balance(BOB), 1_000);
+			let offers = BattleMogs::trade_offers(mogwai_id);
+			assert_eq!(offers.len(), 1);
+			assert_eq!(offers[0].buyer, BOB);
+			assert_eq!(offers[0].expires_at, System::block_number() + OfferDuration::get());
+			System::assert_last_event(mock::RuntimeEvent::BattleMogs(Event::OfferMade(
+				BOB,
+				mogwai_id,
+				terms(1_000, &[]),
+			)));
+		});
+	}
+
+	#[test]
+	fn make_offer_rejects_invalid_offers() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id = create_mogwai(ALICE);
+			let alices_other = create_mogwai(ALICE);
+			let bobs_mogwai = create_mogwai(BOB);
+
+			assert_noop!(
+				BattleMogs::make_offer(RuntimeOrigin::signed(ALICE), mogwai_id, terms(1, &[])),
+				Error::<Test>::MogwaiAlreadyOwned
+			);
+			assert_noop!(
+				BattleMogs::make_offer(RuntimeOrigin::signed(BOB), mogwai_id, terms(0, &[])),
+				Error::<Test>::OfferEmpty
+			);
+			assert_noop!(
+				BattleMogs::make_offer(
+					RuntimeOrigin::signed(BOB),
+					mogwai_id,
+					terms(0, &[alices_other])
+				),
+				Error::<Test>::MogwaiNotOwned
+			);
+			assert_noop!(
+				BattleMogs::make_offer(
+					RuntimeOrigin::signed(BOB),
+					mogwai_id,
+					terms(0, &[bobs_mogwai, bobs_mogwai])
+				),
+				Error::<Test>::MogwaiSame
+			);
+
+			assert_ok!(BattleMogs::make_offer(
+				RuntimeOrigin::signed(BOB),
+				mogwai_id,
+				terms(1, &[])
+			));
+			assert_noop!(
+				BattleMogs::make_offer(RuntimeOrigin::signed(BOB), mogwai_id, terms(2, &[])),
+				Error::<Test>::OfferAlreadyMade
+			);
+			assert_ok!(BattleMogs::make_offer(
+				RuntimeOrigin::signed(CHARLIE),
+				mogwai_id,
+				terms(1, &[])
+			));
+			assert_noop!(
+				BattleMogs::make_offer(RuntimeOrigin::signed(4), mogwai_id, terms(1, &[])),
+				Error::<Test>::TooManyOffers
+			);
+		});
+	}
+
+	#[test]
+	fn accept_offer_trades_mogwais() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id = create_mogwai(ALICE);
+			let bobs_mogwai = create_mogwai(BOB);
+			put_mogwai_on_sale(ALICE, mogwai_id, 5_000);
+
+			assert_ok!(BattleMogs::make_offer(
+				RuntimeOrigin::signed(BOB),
+				mogwai_id,
+				terms(500, &[bobs_mogwai])
+			));
+			assert_ok!(BattleMogs::make_offer(
+				RuntimeOrigin::signed(CHARLIE),
+				mogwai_id,
+				terms(700, &[])
+			));
+
+			let alice_balance = Balances::free_balance(ALICE);
+			assert_noop!(
+				BattleMogs::accept_offer(RuntimeOrigin::signed(CHARLIE), mogwai_id, BOB),
+				Error::<Test>::MogwaiNotOwned
+			);
+			assert_ok!(BattleMogs::accept_offer(RuntimeOrigin::signed(ALICE), mogwai_id, BOB));
+
+			assert_eq!(BattleMogs::mogwai(mogwai_id).unwrap().owner, BOB);
+			assert_eq!(BattleMogs::mogwai(bobs_mogwai).unwrap().owner, ALICE);
+			assert_eq!(BattleMogs::mogwai_prices(mogwai_id), None);
+			assert_eq!(Balances::free_balance(ALICE), alice_balance + 500);
+			assert_eq!(Balances::reserved_balance(BOB), 0);
+
+			// The competing offer is dropped along with the trade.
+			assert!(BattleMogs::trade_offers(mogwai_id).is_empty());
+			assert_eq!(Balances::reserved_balance(CHARLIE), 0);
+			System::assert_has_event(mock::RuntimeEvent::BattleMogs(Event::OfferInvalidated(
+				CHARLIE, mogwai_id,
+			)));
+			System::assert_last_event(mock::RuntimeEvent::BattleMogs(Event::OfferAccepted(
+				ALICE,
+				BOB,
+				mogwai_id,
+				terms(500, &[bobs_mogwai]),
+			)));
+		});
+	}
+
+	#[test]
+	fn accept_offer_fails_once_swapped_mogwai_is_gone() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id = create_mogwai(ALICE);
+			let bobs_mogwai = hatched_mogwai(BOB);
+
+			assert_ok!(BattleMogs::make_offer(
+				RuntimeOrigin::signed(BOB),
+				mogwai_id,
+				terms(0, &[bobs_mogwai])
+			));
+			assert_ok!(BattleMogs::sacrifice(RuntimeOrigin::signed(BOB), bobs_mogwai));
+
+			assert_noop!(
+				BattleMogs::accept_offer(RuntimeOrigin::signed(ALICE), mogwai_id, BOB),
+				Error::<Test>::MogwaiDoesntExists
+			);
+			assert_ok!(BattleMogs::reject_offer(RuntimeOrigin::signed(ALICE), mogwai_id, BOB));
+		});
+	}
+
+	#[test]
+	fn withdraw_and_reject_unreserve() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id = create_mogwai(ALICE);
+			assert_ok!(BattleMogs::make_offer(
+				RuntimeOrigin::signed(BOB),
+				mogwai_id,
+				terms(1_000, &[])
+			));
+			assert_ok!(BattleMogs::make_offer(
+				RuntimeOrigin::signed(CHARLIE),
+				mogwai_id,
+				terms(2_000, &[])
+			));
+
+			assert_ok!(BattleMogs::withdraw_offer(RuntimeOrigin::signed(BOB), mogwai_id));
+			assert_eq!(Balances::reserved_balance(BOB), 0);
+			assert_noop!(
+				BattleMogs::withdraw_offer(RuntimeOrigin::signed(BOB), mogwai_id),
+				Error::<Test>::OfferNotFound
+			);
+
+			assert_noop!(
+				BattleMogs::reject_offer(RuntimeOrigin::signed(BOB), mogwai_id, CHARLIE),
+				Error::<Test>::MogwaiNotOwned
+			);
+			assert_ok!(BattleMogs::reject_offer(RuntimeOrigin::signed(ALICE), mogwai_id, CHARLIE));
+			assert_eq!(Balances::reserved_balance(CHARLIE), 0);
+			assert!(!TradeOffers::<Test>::contains_key(mogwai_id));
+		});
+	}
+
+	#[test]
+	fn counter_offer_can_be_accepted_by_buyer() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id = create_mogwai(ALICE);
+			let bobs_mogwai = create_mogwai(BOB);
+			assert_ok!(BattleMogs::make_offer(
+				RuntimeOrigin::signed(BOB),
+				mogwai_id,
+				terms(1_000, &[bobs_mogwai])
+			));
+
+			assert_noop!(
+				BattleMogs::accept_counter_offer(RuntimeOrigin::signed(BOB), mogwai_id),
+				Error::<Test>::CounterOfferNotFound
+			);
+			assert_noop!(
+				BattleMogs::counter_offer(
+					RuntimeOrigin::signed(ALICE),
+					mogwai_id,
+					CHARLIE,
+					terms(3_000, &[])
+				),
+				Error::<Test>::OfferNotFound
+			);
+			assert_ok!(BattleMogs::counter_offer(
+				RuntimeOrigin::signed(ALICE),
+				mogwai_id,
+				BOB,
+				terms(3_000, &[])
+			));
+
+			let alice_balance = Balances::free_balance(ALICE);
+			let bob_balance = Balances::free_balance(BOB);
+			assert_ok!(BattleMogs::accept_counter_offer(RuntimeOrigin::signed(BOB), mogwai_id));
+
+			assert_eq!(BattleMogs::mogwai(mogwai_id).unwrap().owner, BOB);
+			assert_eq!(BattleMogs::mogwai(bobs_mogwai).unwrap().owner, BOB);
+			assert_eq!(Balances::free_balance(ALICE), alice_balance + 3_000);
+			assert_eq!(Balances::free_balance(BOB), bob_balance - 2_000);
+			assert_eq!(Balances::reserved_balance(BOB), 0);
+		});
+	}
+
+	#[test]
+	fn offers_expire_and_unreserve() {
+		ExtBuilder.build().execute_with(|| {
+			let mogwai_id = create_mogwai(ALICE);
+			assert_ok!(BattleMogs::make_offer(
+				RuntimeOrigin::signed(BOB),
+				mogwai_id,
+				terms(1_000, &[])
+			));
+			let expires_at = BattleMogs::trade_offers(mogwai_id)[0].expires_at;
+
+			run_to_block(expires_at - 1);
+			assert_eq!(Balances::reserved_balance(BOB), 1_000);
+
+			run_to_block(expires_at);
+			assert!(BattleMogs::trade_offers(mogwai_id).is_empty());
+			assert_eq!(Balances::reserved_balance(BOB), 0);
+			System::assert_has_event(mock::RuntimeEvent::BattleMogs(Event::OfferExpired(
+				BOB, mogwai_id,
+			)));
+		});
+	}
+
+	#[test]
+	fn offers_are_invalidated_by_sacrifice_transfer_and_breeding() {
+		ExtBuilder.build().execute_with(|| {
+			let sacrificed = hatched_mogwai(ALICE);
+			let transferred = hatched_mogwai(ALICE);
+			let bred_into = hatched_mogwai(ALICE);
+			let other_parent = hatched_mogwai(ALICE);
+
+			for mogwai_id in [sacrificed, transferred, bred_into] {
+				assert_ok!(BattleMogs::make_offer(
+					RuntimeOrigin::signed(BOB),
+					mogwai_id,
+					terms(1_000, &[])
+				));
+			}
+			assert_eq!(Balances::reserved_balance(BOB), 3_000);
+
+			assert_ok!(BattleMogs::sacrifice(RuntimeOrigin::signed(ALICE), sacrificed));
+			assert_eq!(Balances::reserved_balance(BOB), 2_000);
+
+			assert_ok!(BattleMogs::transfer(RuntimeOrigin::root(), CHARLIE, transferred));
+			assert_eq!(Balances::reserved_balance(BOB), 1_000);
+
+			assert_ok!(BattleMogs::breed_mogwai(
+				RuntimeOrigin::signed(ALICE),
+				other_parent,
+				bred_into
+			));
+			assert_eq!(Balances::reserved_balance(BOB), 0);
+
+			for mogwai_id in [sacrificed, transferred, bred_into] {
+				assert!(BattleMogs::trade_offers(mogwai_id).is_empty());
+				System::assert_has_event(mock::RuntimeEvent::BattleMogs(Event::OfferInvalidated(
+					BOB, mogwai_id,
+				)));
+			}
+		});
+	}
+}
@@ -14,9 +14,11 @@
 // You should have received a copy of the GNU Affero General Public License
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
+use crate::Dna;
 use frame_support::pallet_prelude::*;
 use parity_scale_codec::MaxEncodedLen;
 use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
 
 #[derive(Encode, Decode, Debug, Default, Clone, PartialEq, TypeInfo, MaxEncodedLen)]
 pub struct MogwaiStruct<
@@ -29,7 +31,7 @@ pub struct MogwaiStruct<
 	AccountId,
 > {
 	pub id: Hash,
-	pub dna: [[u8; 32]; 2],
+	pub dna: Dna,
 	//	pub state: u32,
 	//  pub level: u32,
 	pub genesis: BlockNumber,
@@ -106,7 +108,7 @@ impl From<u16> for MogwaiGeneration {
 	}
 }
 
-#[derive(Encode, Decode, Debug, Copy, Clone, PartialEq, Eq, TypeInfo)]
+#[derive(Encode, Decode, Debug, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub enum BreedType {
 	DomDom = 0,
 	DomRez = 1,
@@ -114,6 +116,18 @@ pub enum BreedType {
 	RezRez = 3,
 }
 
+impl BreedType {
+	/// The breed type of the regular moon cycle, which repeats every 80 blocks.
+	pub fn from_moon_cycle(block_number: u32) -> Self {
+		match block_number % 80 {
+			0..=19 => BreedType::DomDom,
+			20..=39 => BreedType::DomRez,
+			40..=59 => BreedType::RezDom,
+			_ => BreedType::RezRez,
+		}
+	}
+}
+
 #[derive(Encode, Decode, Debug, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub enum RarityType {
 	Common = 0,
@@ -130,6 +144,16 @@ impl Default for RarityType {
 	}
 }
 
+impl RarityType {
+	/// The next higher rarity, `Mythical` stays as it is.
+	pub fn upgraded(self) -> Self {
+		match self {
+			Self::Mythical => Self::Mythical,
+			rarity => Self::from(rarity as u8 + 1),
+		}
+	}
+}
+
 impl From<u8> for RarityType {
 	fn from(num: u8) -> Self {
 		RarityType::from(num as u16)
@@ -218,6 +242,34 @@ impl AccountAchievement {
 	}
 }
 
+/// What an account receives for claiming a completed [`AccountAchievement`].
+#[derive(Encode, Decode, Debug, Copy, Clone, PartialEq, TypeInfo, MaxEncodedLen)]
+pub enum AchievementReward<Balance> {
+	/// An amount of currency paid out of the pallet's account.
+	Currency(Balance),
+	/// A free upgrade of the account configuration at the given index.
+	ConfigUpgrade(u8),
+	/// A new egg with the given rarity.
+	SpecialEgg(RarityType),
+}
+
+/// Who is allowed to mint new mogwais.
+#[derive(
+	Encode, Decode, Debug, Clone, PartialEq, TypeInfo, MaxEncodedLen, Serialize, Deserialize,
+)]
+pub enum MintPolicy<Balance> {
+	/// Any account can mint by paying the price.
+	Open { price: Balance },
+	/// Only the mint origin can mint, free of charge.
+	Restricted,
+}
+
+impl<Balance: Default> Default for MintPolicy<Balance> {
+	fn default() -> Self {
+		Self::Open { price: Default::default() }
+	}
+}
+
 pub type Balance = u128;
 pub const MILLIMOGS: Balance = 1_000_000_000;
 pub const DMOGS: Balance = 1_000 * MILLIMOGS;
@@ -236,22 +288,6 @@ impl Default for FeeType {
 
 pub struct Pricing;
 impl Pricing {
-	pub fn config_update_price(index: u8, value: u8) -> Balance {
-		match index {
-			1 => Self::config_max_mogwais(value),
-			_ => 0,
-		}
-	}
-
-	fn config_max_mogwais(value: u8) -> Balance {
-		match value {
-			1 => 5 * DMOGS,
-			2 => 10 * DMOGS,
-			3 => 20 * DMOGS,
-			_ => 0,
-		}
-	}
-
 	pub fn fee_price(fee: FeeType) -> Balance {
 		match fee {
 			FeeType::Default => MILLIMOGS,
@@ -310,43 +346,122 @@ pub struct GameConfig {
 impl GameConfig {
 	pub const PARAM_COUNT: usize = 10;
 
+	/// The rules of each configuration parameter, parameters without a rule can't be updated.
+	pub const RULES: [Option<ConfigRule>; GameConfig::PARAM_COUNT] = [
+		// Activated
+		None,
+		// MaxMogwaisInAccount
+		Some(ConfigRule {
+			min: 0,
+			max: 7,
+			step: 1,
+			price: PriceCurve::Exponential(5 * DMOGS),
+			effect: ConfigEffect::MaxMogwais { base: 6, per_level: 6 },
+		}),
+		// MaxStashSize
+		None,
+		// AccountNaming
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+		None,
+	];
+
 	pub fn new() -> Self {
 		GameConfig { parameters: [0; GameConfig::PARAM_COUNT] }
 	}
 
+	/// The rule of the parameter at `index`, if it can be configured.
+	pub fn rule(index: u8) -> Option<ConfigRule> {
+		Self::RULES.get(usize::from(index)).copied().flatten()
+	}
+
+	/// The effective value of the parameter at `index` when set to `value`.
 	pub fn config_value(index: u8, value: u8) -> u32 {
-		let result: u32;
-		match index {
-			// MaxMogwaisInAccount
-			1 => match value {
-				0 => result = 6,
-				1 => result = 12,
-				2 => result = 18,
-				3 => result = 24,
-				_ => result = 0,
-			},
-			_ => result = 0,
+		Self::rule(index).map(|rule| rule.effect_value(value)).unwrap_or_default()
+	}
+
+	/// The highest mogwai limit an account can reach, bounding the mogwais it can own.
+	pub const fn max_mogwais() -> u32 {
+		match Self::RULES[GameConfigType::MaxMogwaisInAccount as usize] {
+			Some(rule) => rule.effect_value(rule.max),
+			None => 0,
 		}
-		result
-	}
-
-	pub fn verify_update(index: u8, value: u8, update_value_opt: Option<u8>) -> u8 {
-		let mut result: u8;
-		match index {
-			// MaxMogwaisInAccount
-			1 => match value {
-				0 => result = 1,
-				1 => result = 2,
-				2 => result = 3,
-				_ => result = 0,
-			},
-			_ => result = 0,
+	}
+}
+
+/// How the price of a configuration update grows with the level reached.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PriceCurve {
+	/// Every level costs the same.
+	Flat(Balance),
+	/// Level `n` costs `n` times the base price.
+	Linear(Balance),
+	/// The first level costs the base price, every further level twice the previous one.
+	Exponential(Balance),
+}
+
+impl PriceCurve {
+	pub const fn price(&self, level: u8) -> Balance {
+		match *self {
+			_ if level == 0 => 0,
+			PriceCurve::Flat(base) => base,
+			PriceCurve::Linear(base) => base.saturating_mul(level as Balance),
+			PriceCurve::Exponential(base) =>
+				base.saturating_mul((2 as Balance).saturating_pow(level as u32 - 1)),
 		}
-		// don't allow bad requests
-		if update_value_opt.is_some() && result != update_value_opt.unwrap() {
-			result = 0;
+	}
+}
+
+/// What a configuration parameter changes for its account.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ConfigEffect {
+	/// The account can own `base` mogwais, plus `per_level` for each level.
+	MaxMogwais { base: u32, per_level: u32 },
+}
+
+impl ConfigEffect {
+	pub const fn value(&self, level: u8) -> u32 {
+		match *self {
+			ConfigEffect::MaxMogwais { base, per_level } =>
+				base.saturating_add(per_level.saturating_mul(level as u32)),
 		}
-		result
+	}
+}
+
+/// A configuration parameter starts at `min` and is raised `step` at a time up to `max`, each
+/// update costing the price of the level it reaches.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ConfigRule {
+	pub min: u8,
+	pub max: u8,
+	pub step: u8,
+	pub price: PriceCurve,
+	pub effect: ConfigEffect,
+}
+
+impl ConfigRule {
+	/// The number of updates needed to get from `min` to `value`.
+	pub const fn level(&self, value: u8) -> u8 {
+		value.saturating_sub(self.min) / self.step
+	}
+
+	/// The value following `value`, if it doesn't exceed `max`.
+	pub fn next_value(&self, value: u8) -> Option<u8> {
+		value.max(self.min).checked_add(self.step).filter(|next| *next <= self.max)
+	}
+
+	/// The price of updating the parameter to `value`.
+	pub const fn update_price(&self, value: u8) -> Balance {
+		self.price.price(self.level(value))
+	}
+
+	/// The effective value of the parameter when set to `value`.
+	pub const fn effect_value(&self, value: u8) -> u32 {
+		self.effect.value(self.level(value))
 	}
 }
 
@@ -377,3 +492,117 @@ impl GameEventType {
 		}
 	}
 }
+
+/// The effect of a world event while it is active.
+#[derive(Encode, Decode, Debug, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum WorldEventKind {
+	/// Breeding and morphing use the given moon phase and pay half the pairing price.
+	BreedingMoon(BreedType),
+	/// Hatched eggs get their rarity upgraded by one level.
+	RareHatch,
+}
+
+impl WorldEventKind {
+	/// Pick a world event kind from a random `seed`.
+	pub fn from_seed(seed: u8) -> Self {
+		match seed % 5 {
+			0 => Self::RareHatch,
+			1 => Self::BreedingMoon(BreedType::DomDom),
+			2 => Self::BreedingMoon(BreedType::DomRez),
+			3 => Self::BreedingMoon(BreedType::RezDom),
+			_ => Self::BreedingMoon(BreedType::RezRez),
+		}
+	}
+}
+
+/// A world event, announced before it starts and active for the blocks in `start..end`.
+#[derive(Encode, Decode, Debug, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct WorldEvent<BlockNumber> {
+	pub kind: WorldEventKind,
+	pub start: BlockNumber,
+	pub end: BlockNumber,
+}
+
+/// Actions performed with a mogwai that are subject to ownership checks.
+#[derive(Encode, Decode, Debug, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum MogwaiAction {
+	Hatch,
+	Morph,
+	Breed,
+	Battle,
+	Sacrifice,
+	Sell,
+}
+
+/// The actions a borrower may perform with a lent mogwai.
+#[derive(Encode, Decode, Debug, Default, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct LendingPermissions {
+	pub breed: bool,
+	pub battle: bool,
+}
+
+impl LendingPermissions {
+	pub fn allows(&self, action: MogwaiAction) -> bool {
+		match action {
+			MogwaiAction::Breed => self.breed,
+			MogwaiAction::Battle => self.battle,
+			_ => false,
+		}
+	}
+}
+
+/// The terms under which the owner of a mogwai offers to lend it.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct LendingTerms<AccountId, Balance, BlockNumber> {
+	pub borrower: AccountId,
+	/// Number of blocks the mogwai is lent for.
+	pub duration: BlockNumber,
+	/// Paid by the borrower to the lender once the lending starts.
+	pub fee: Balance,
+	pub permissions: LendingPermissions,
+}
+
+/// An active lending, the mogwai returns to its lender at `expires_at`.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct LendingAgreement<AccountId, Balance, BlockNumber> {
+	pub lender: AccountId,
+	pub borrower: AccountId,
+	pub fee: Balance,
+	pub expires_at: BlockNumber,
+	pub permissions: LendingPermissions,
+}
+
+/// The call a batch of mogwais has been submitted to.
+#[derive(Encode, Decode, Debug, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum BatchAction {
+	Hatch,
+	SetPrice,
+	RemovePrice,
+	Sacrifice,
+}
+
+/// What a buyer gives in exchange for a mogwai: reserved currency and mogwais of their own.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct TradeTerms<Balance, MogwaiIds> {
+	pub amount: Balance,
+	pub mogwais: MogwaiIds,
+}
+
+/// An offer for a mogwai, `terms.amount` stays reserved from the buyer until it is settled.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct TradeOffer<AccountId, Balance, BlockNumber, MogwaiIds> {
+	pub buyer: AccountId,
+	pub terms: TradeTerms<Balance, MogwaiIds>,
+	/// The terms the owner of the mogwai would accept instead.
+	pub counter: Option<TradeTerms<Balance, MogwaiIds>>,
+	pub expires_at: BlockNumber,
+}
+
+/// How far the mogwais created before NFT mirroring was enabled have been imported.
+#[derive(Encode, Decode, Debug, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum NftImportProgress<MogwaiId> {
+	/// No mogwai has been imported yet.
+	Started,
+	/// The mogwais stored up to this one have been imported.
+	After(MogwaiId),
+}
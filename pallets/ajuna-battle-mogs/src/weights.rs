@@ -44,7 +44,7 @@ use sp_std::marker::PhantomData;
 
 /// Weight functions needed for pallet_battle_mogs.
 pub trait WeightInfo {
-	fn set_organizer() -> Weight;
+	fn set_mint_policy() -> Weight;
 	fn update_config() -> Weight;
 	fn set_price() -> Weight;
 	fn remove_price() -> Weight;
@@ -57,18 +57,33 @@ pub trait WeightInfo {
 	fn buy_mogwai() -> Weight;
 	fn morph_mogwai() -> Weight;
 	fn breed_mogwai() -> Weight;
+	fn claim_achievement() -> Weight;
+	fn offer_lending() -> Weight;
+	fn cancel_lending_offer() -> Weight;
+	fn borrow_mogwai() -> Weight;
+	fn return_mogwai() -> Weight;
+	fn hatch_mogwais(n: u32) -> Weight;
+	fn set_prices(n: u32) -> Weight;
+	fn remove_prices(n: u32) -> Weight;
+	fn sacrifice_mogwais(n: u32) -> Weight;
+	fn make_offer() -> Weight;
+	fn withdraw_offer() -> Weight;
+	fn reject_offer() -> Weight;
+	fn counter_offer() -> Weight;
+	fn accept_offer() -> Weight;
+	fn accept_counter_offer() -> Weight;
 }
 
 /// Weights for pallet_battle_mogs using the Substrate node and recommended hardware.
 pub struct AjunaWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
-	// Storage: BattleMogs Organizer (r:0 w:1)
-	fn set_organizer() -> Weight {
+	// Storage: BattleMogs CurrentMintPolicy (r:0 w:1)
+	fn set_mint_policy() -> Weight {
 		Weight::from_parts(35_212_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	// Storage: BattleMogs AccountConfig (r:1 w:1)
-	// Storage: BattleMogs Organizer (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
 	fn update_config() -> Weight {
 		Weight::from_parts(79_832_000, 0)
 			.saturating_add(T::DbWeight::get().reads(2_u64))
@@ -88,6 +103,7 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	// Storage: BattleMogs CurrentMintPolicy (r:1 w:0)
 	// Storage: BattleMogs OwnedMogwaisCount (r:1 w:1)
 	// Storage: BattleMogs AccountConfig (r:1 w:0)
 	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
@@ -97,10 +113,9 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 	// Storage: BattleMogs AllMogwaisCount (r:1 w:1)
 	fn create_mogwai() -> Weight {
 		Weight::from_parts(116_283_000, 0)
-			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
-	// Storage: BattleMogs Organizer (r:1 w:0)
 	// Storage: BattleMogs Mogwais (r:1 w:1)
 	// Storage: BattleMogs Owners (r:1 w:1)
 	// Storage: BattleMogs MogwaiPrices (r:1 w:0)
@@ -111,7 +126,6 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
-	// Storage: BattleMogs Organizer (r:1 w:0)
 	// Storage: BattleMogs OwnedMogwaisCount (r:2 w:2)
 	// Storage: BattleMogs AccountConfig (r:1 w:0)
 	// Storage: BattleMogs Owners (r:2 w:2)
@@ -183,17 +197,173 @@ impl<T: frame_system::Config> WeightInfo for AjunaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(9_u64))
 			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
+	// Storage: BattleMogs AccountAchievements (r:1 w:0)
+	// Storage: BattleMogs ClaimedAchievements (r:1 w:1)
+	// Storage: BattleMogs OwnedMogwaisCount (r:1 w:1)
+	// Storage: BattleMogs AccountConfig (r:1 w:0)
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	// Storage: BattleMogs Nonce (r:1 w:1)
+	// Storage: BattleMogs Mogwais (r:1 w:1)
+	// Storage: BattleMogs Owners (r:1 w:1)
+	// Storage: BattleMogs AllMogwaisCount (r:1 w:1)
+	fn claim_achievement() -> Weight {
+		Weight::from_parts(128_417_000, 0)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	// Storage: BattleMogs Mogwais (r:1 w:0)
+	// Storage: BattleMogs Lendings (r:1 w:0)
+	// Storage: BattleMogs MogwaiPrices (r:1 w:0)
+	// Storage: BattleMogs LendingOffers (r:0 w:1)
+	fn offer_lending() -> Weight {
+		Weight::from_parts(48_310_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: BattleMogs Mogwais (r:1 w:0)
+	// Storage: BattleMogs LendingOffers (r:1 w:1)
+	fn cancel_lending_offer() -> Weight {
+		Weight::from_parts(38_124_000, 0)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: BattleMogs Mogwais (r:1 w:0)
+	// Storage: BattleMogs LendingOffers (r:1 w:1)
+	// Storage: BattleMogs MogwaiPrices (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: BattleMogs LendingExpiries (r:1 w:1)
+	// Storage: BattleMogs Lendings (r:0 w:1)
+	fn borrow_mogwai() -> Weight {
+		Weight::from_parts(84_977_000, 0)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	// Storage: BattleMogs Lendings (r:1 w:1)
+	fn return_mogwai() -> Weight {
+		Weight::from_parts(36_402_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: System BlockHash (r:1 w:0)
+	// Storage: BattleMogs Mogwais (r:1 w:1)
+	// Storage: BattleMogs AccountAchievements (r:1 w:1)
+	/// The range of component `n` is `[1, 24]`.
+	fn hatch_mogwais(n: u32) -> Weight {
+		Weight::from_parts(8_512_000, 0)
+			.saturating_add(Weight::from_parts(66_143_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	// Storage: BattleMogs Mogwais (r:1 w:0)
+	// Storage: BattleMogs Lendings (r:1 w:0)
+	// Storage: BattleMogs MogwaiPrices (r:0 w:1)
+	/// The range of component `n` is `[1, 24]`.
+	fn set_prices(n: u32) -> Weight {
+		Weight::from_parts(6_934_000, 0)
+			.saturating_add(Weight::from_parts(49_871_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	// Storage: BattleMogs Mogwais (r:1 w:0)
+	// Storage: BattleMogs Lendings (r:1 w:0)
+	// Storage: BattleMogs MogwaiPrices (r:1 w:1)
+	/// The range of component `n` is `[1, 24]`.
+	fn remove_prices(n: u32) -> Weight {
+		Weight::from_parts(7_102_000, 0)
+			.saturating_add(Weight::from_parts(57_466_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	// Storage: BattleMogs Mogwais (r:1 w:1)
+	// Storage: BattleMogs MogwaiPrices (r:1 w:0)
+	// Storage: BattleMogs Owners (r:1 w:1)
+	// Storage: BattleMogs AllMogwaisCount (r:1 w:1)
+	// Storage: BattleMogs OwnedMogwaisCount (r:1 w:1)
+	// Storage: BattleMogs AccountAchievements (r:1 w:1)
+	/// The range of component `n` is `[1, 24]`.
+	fn sacrifice_mogwais(n: u32) -> Weight {
+		Weight::from_parts(9_310_000, 0)
+			.saturating_add(Weight::from_parts(121_804_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+	}
+	// Storage: BattleMogs Mogwais (r:4 w:0)
+	// Storage: BattleMogs Lendings (r:3 w:0)
+	// Storage: BattleMogs TradeOffers (r:1 w:1)
+	// Storage: BattleMogs TradeOfferExpiries (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn make_offer() -> Weight {
+		Weight::from_parts(74_382_000, 0)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	// Storage: BattleMogs TradeOffers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn withdraw_offer() -> Weight {
+		Weight::from_parts(41_207_000, 0)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: BattleMogs Mogwais (r:1 w:0)
+	// Storage: BattleMogs TradeOffers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn reject_offer() -> Weight {
+		Weight::from_parts(45_930_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	// Storage: BattleMogs Mogwais (r:4 w:0)
+	// Storage: BattleMogs Lendings (r:4 w:0)
+	// Storage: BattleMogs TradeOffers (r:1 w:1)
+	fn counter_offer() -> Weight {
+		Weight::from_parts(58_114_000, 0)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	// Storage: BattleMogs TradeOffers (r:5 w:5)
+	// Storage: BattleMogs Mogwais (r:5 w:4)
+	// Storage: BattleMogs Lendings (r:5 w:4)
+	// Storage: BattleMogs AccountConfig (r:2 w:0)
+	// Storage: BattleMogs OwnedMogwaisCount (r:2 w:2)
+	// Storage: BattleMogs Owners (r:2 w:2)
+	// Storage: BattleMogs MogwaiNftItems (r:4 w:0)
+	// Storage: BattleMogs LendingOffers (r:0 w:4)
+	// Storage: BattleMogs MogwaiPrices (r:0 w:4)
+	// Storage: BattleMogs AccountAchievements (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	fn accept_offer() -> Weight {
+		Weight::from_parts(412_856_000, 0)
+			.saturating_add(T::DbWeight::get().reads(32_u64))
+			.saturating_add(T::DbWeight::get().writes(28_u64))
+	}
+	// Storage: BattleMogs TradeOffers (r:5 w:5)
+	// Storage: BattleMogs Mogwais (r:6 w:4)
+	// Storage: BattleMogs Lendings (r:5 w:4)
+	// Storage: BattleMogs AccountConfig (r:2 w:0)
+	// Storage: BattleMogs OwnedMogwaisCount (r:2 w:2)
+	// Storage: BattleMogs Owners (r:2 w:2)
+	// Storage: BattleMogs MogwaiNftItems (r:4 w:0)
+	// Storage: BattleMogs LendingOffers (r:0 w:4)
+	// Storage: BattleMogs MogwaiPrices (r:0 w:4)
+	// Storage: BattleMogs AccountAchievements (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	fn accept_counter_offer() -> Weight {
+		Weight::from_parts(421_390_000, 0)
+			.saturating_add(T::DbWeight::get().reads(33_u64))
+			.saturating_add(T::DbWeight::get().writes(28_u64))
+	}
 }
 
 // For backwards compatibility and tests
 impl WeightInfo for () {
-	// Storage: BattleMogs Organizer (r:0 w:1)
-	fn set_organizer() -> Weight {
+	// Storage: BattleMogs CurrentMintPolicy (r:0 w:1)
+	fn set_mint_policy() -> Weight {
 		Weight::from_parts(35_212_000, 0)
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	// Storage: BattleMogs AccountConfig (r:1 w:1)
-	// Storage: BattleMogs Organizer (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
 	fn update_config() -> Weight {
 		Weight::from_parts(79_832_000, 0)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
@@ -213,6 +383,7 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	// Storage: BattleMogs CurrentMintPolicy (r:1 w:0)
 	// Storage: BattleMogs OwnedMogwaisCount (r:1 w:1)
 	// Storage: BattleMogs AccountConfig (r:1 w:0)
 	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
@@ -222,10 +393,9 @@ impl WeightInfo for () {
 	// Storage: BattleMogs AllMogwaisCount (r:1 w:1)
 	fn create_mogwai() -> Weight {
 		Weight::from_parts(116_283_000, 0)
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
-	// Storage: BattleMogs Organizer (r:1 w:0)
 	// Storage: BattleMogs Mogwais (r:1 w:1)
 	// Storage: BattleMogs Owners (r:1 w:1)
 	// Storage: BattleMogs MogwaiPrices (r:1 w:0)
@@ -236,7 +406,6 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
-	// Storage: BattleMogs Organizer (r:1 w:0)
 	// Storage: BattleMogs OwnedMogwaisCount (r:2 w:2)
 	// Storage: BattleMogs AccountConfig (r:1 w:0)
 	// Storage: BattleMogs Owners (r:2 w:2)
@@ -308,4 +477,160 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(9_u64))
 			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
+	// Storage: BattleMogs AccountAchievements (r:1 w:0)
+	// Storage: BattleMogs ClaimedAchievements (r:1 w:1)
+	// Storage: BattleMogs OwnedMogwaisCount (r:1 w:1)
+	// Storage: BattleMogs AccountConfig (r:1 w:0)
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	// Storage: BattleMogs Nonce (r:1 w:1)
+	// Storage: BattleMogs Mogwais (r:1 w:1)
+	// Storage: BattleMogs Owners (r:1 w:1)
+	// Storage: BattleMogs AllMogwaisCount (r:1 w:1)
+	fn claim_achievement() -> Weight {
+		Weight::from_parts(128_417_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	// Storage: BattleMogs Mogwais (r:1 w:0)
+	// Storage: BattleMogs Lendings (r:1 w:0)
+	// Storage: BattleMogs MogwaiPrices (r:1 w:0)
+	// Storage: BattleMogs LendingOffers (r:0 w:1)
+	fn offer_lending() -> Weight {
+		Weight::from_parts(48_310_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: BattleMogs Mogwais (r:1 w:0)
+	// Storage: BattleMogs LendingOffers (r:1 w:1)
+	fn cancel_lending_offer() -> Weight {
+		Weight::from_parts(38_124_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: BattleMogs Mogwais (r:1 w:0)
+	// Storage: BattleMogs LendingOffers (r:1 w:1)
+	// Storage: BattleMogs MogwaiPrices (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: BattleMogs LendingExpiries (r:1 w:1)
+	// Storage: BattleMogs Lendings (r:0 w:1)
+	fn borrow_mogwai() -> Weight {
+		Weight::from_parts(84_977_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	// Storage: BattleMogs Lendings (r:1 w:1)
+	fn return_mogwai() -> Weight {
+		Weight::from_parts(36_402_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: System BlockHash (r:1 w:0)
+	// Storage: BattleMogs Mogwais (r:1 w:1)
+	// Storage: BattleMogs AccountAchievements (r:1 w:1)
+	/// The range of component `n` is `[1, 24]`.
+	fn hatch_mogwais(n: u32) -> Weight {
+		Weight::from_parts(8_512_000, 0)
+			.saturating_add(Weight::from_parts(66_143_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	// Storage: BattleMogs Mogwais (r:1 w:0)
+	// Storage: BattleMogs Lendings (r:1 w:0)
+	// Storage: BattleMogs MogwaiPrices (r:0 w:1)
+	/// The range of component `n` is `[1, 24]`.
+	fn set_prices(n: u32) -> Weight {
+		Weight::from_parts(6_934_000, 0)
+			.saturating_add(Weight::from_parts(49_871_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	// Storage: BattleMogs Mogwais (r:1 w:0)
+	// Storage: BattleMogs Lendings (r:1 w:0)
+	// Storage: BattleMogs MogwaiPrices (r:1 w:1)
+	/// The range of component `n` is `[1, 24]`.
+	fn remove_prices(n: u32) -> Weight {
+		Weight::from_parts(7_102_000, 0)
+			.saturating_add(Weight::from_parts(57_466_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	// Storage: BattleMogs Mogwais (r:1 w:1)
+	// Storage: BattleMogs MogwaiPrices (r:1 w:0)
+	// Storage: BattleMogs Owners (r:1 w:1)
+	// Storage: BattleMogs AllMogwaisCount (r:1 w:1)
+	// Storage: BattleMogs OwnedMogwaisCount (r:1 w:1)
+	// Storage: BattleMogs AccountAchievements (r:1 w:1)
+	/// The range of component `n` is `[1, 24]`.
+	fn sacrifice_mogwais(n: u32) -> Weight {
+		Weight::from_parts(9_310_000, 0)
+			.saturating_add(Weight::from_parts(121_804_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads((6_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(n.into())))
+	}
+	// Storage: BattleMogs Mogwais (r:4 w:0)
+	// Storage: BattleMogs Lendings (r:3 w:0)
+	// Storage: BattleMogs TradeOffers (r:1 w:1)
+	// Storage: BattleMogs TradeOfferExpiries (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn make_offer() -> Weight {
+		Weight::from_parts(74_382_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	// Storage: BattleMogs TradeOffers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn withdraw_offer() -> Weight {
+		Weight::from_parts(41_207_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: BattleMogs Mogwais (r:1 w:0)
+	// Storage: BattleMogs TradeOffers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn reject_offer() -> Weight {
+		Weight::from_parts(45_930_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	// Storage: BattleMogs Mogwais (r:4 w:0)
+	// Storage: BattleMogs Lendings (r:4 w:0)
+	// Storage: BattleMogs TradeOffers (r:1 w:1)
+	fn counter_offer() -> Weight {
+		Weight::from_parts(58_114_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	// Storage: BattleMogs TradeOffers (r:5 w:5)
+	// Storage: BattleMogs Mogwais (r:5 w:4)
+	// Storage: BattleMogs Lendings (r:5 w:4)
+	// Storage: BattleMogs AccountConfig (r:2 w:0)
+	// Storage: BattleMogs OwnedMogwaisCount (r:2 w:2)
+	// Storage: BattleMogs Owners (r:2 w:2)
+	// Storage: BattleMogs MogwaiNftItems (r:4 w:0)
+	// Storage: BattleMogs LendingOffers (r:0 w:4)
+	// Storage: BattleMogs MogwaiPrices (r:0 w:4)
+	// Storage: BattleMogs AccountAchievements (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	fn accept_offer() -> Weight {
+		Weight::from_parts(412_856_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(32_u64))
+			.saturating_add(RocksDbWeight::get().writes(28_u64))
+	}
+	// Storage: BattleMogs TradeOffers (r:5 w:5)
+	// Storage: BattleMogs Mogwais (r:6 w:4)
+	// Storage: BattleMogs Lendings (r:5 w:4)
+	// Storage: BattleMogs AccountConfig (r:2 w:0)
+	// Storage: BattleMogs OwnedMogwaisCount (r:2 w:2)
+	// Storage: BattleMogs Owners (r:2 w:2)
+	// Storage: BattleMogs MogwaiNftItems (r:4 w:0)
+	// Storage: BattleMogs LendingOffers (r:0 w:4)
+	// Storage: BattleMogs MogwaiPrices (r:0 w:4)
+	// Storage: BattleMogs AccountAchievements (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	fn accept_counter_offer() -> Weight {
+		Weight::from_parts(421_390_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(33_u64))
+			.saturating_add(RocksDbWeight::get().writes(28_u64))
+	}
 }
@@ -7,19 +7,66 @@ use crate::Pallet as GameModule;
 use frame_benchmarking::v2::*;
 use frame_support::{
 	assert_ok,
-	traits::{OnFinalize, OnInitialize},
+	pallet_prelude::DispatchError,
+	storage::{with_transaction, TransactionOutcome},
+	traits::{Get, OnFinalize, OnInitialize},
+	weights::Weight,
+	BoundedVec,
 };
 use frame_system::RawOrigin;
+use sp_std::vec::Vec;
 
 fn create_setup<T: Config>() -> T::AccountId {
 	let caller: T::AccountId = whitelisted_caller();
 	let admin: T::AccountId = account("admin", 0, 0);
 	assert_ok!(GameModule::<T>::setup_game(RawOrigin::Root.into()));
-	assert_ok!(GameModule::<T>::add_to_admins(RawOrigin::Root.into(), admin.clone()));
+	lock_properties::<T>();
+	assert_ok!(GameModule::<T>::add_to_admins(
+		RawOrigin::Root.into(),
+		Role::Registrar,
+		admin.clone()
+	));
 	assert_ok!(GameModule::<T>::register_user(RawOrigin::Signed(admin).into(), caller.clone()));
 	caller
 }
 
+fn property<T: Config>(id: u32, round: RoundNumber) -> PropertyInfoData<T> {
+	PropertyInfoData {
+		id,
+		data: "nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(),
+		region: "Bristol".as_bytes().to_vec().try_into().unwrap(),
+		features: "3 bedrooms, garden".as_bytes().to_vec().try_into().unwrap(),
+		round,
+		price: T::BenchmarkHelper::lock_price(round, 220000).try_into().unwrap(),
+	}
+}
+
+/// The property in the import schema.
+fn property_v1<T: Config>(id: u32, round: RoundNumber) -> VersionedProperty<T> {
+	let PropertyInfoData { id, data, region, features, round, price } = property::<T>(id, round);
+	VersionedProperty::V1(PropertyInfoDataV1 { id, data, region, features, round, price })
+}
+
+/// Replaces the properties with ones locked to a round that is far enough in the future.
+fn lock_properties<T: Config>() {
+	let round = T::Timelock::latest_round()
+		.saturating_add(T::RevealDelay::get())
+		.saturating_add(1);
+	let properties = [147229391, 146480642, 147031382, 147031383]
+		.into_iter()
+		.map(|id| property::<T>(id, round))
+		.collect::<Vec<_>>();
+	GameProperties::<T>::put(BoundedVec::truncate_from(properties));
+	DrawnProperties::<T>::kill();
+}
+
+/// Publishes the round of the game's property and checks the result.
+fn reveal<T: Config>(game_id: u32) {
+	T::BenchmarkHelper::reveal_round(GameModule::<T>::game_info(game_id).unwrap().property.round);
+	GameModule::<T>::on_initialize(frame_system::Pallet::<T>::block_number());
+	lock_properties::<T>();
+}
+
 fn practise_round<T: Config>(caller: T::AccountId, game_id: u32) {
 	assert_ok!(GameModule::<T>::play_game(
 		RawOrigin::Signed(caller.clone()).into(),
@@ -30,13 +77,7 @@ fn practise_round<T: Config>(caller: T::AccountId, game_id: u32) {
 		20,
 		game_id
 	));
-	assert_ok!(GameModule::<T>::check_result(
-		RawOrigin::Root.into(),
-		20,
-		game_id,
-		20,
-		"test".as_bytes().to_vec().try_into().unwrap(),
-	));
+	reveal::<T>(game_id);
 }
 
 #[benchmarks]
@@ -54,7 +95,11 @@ mod benchmarks {
 		let caller: T::AccountId = account("caller", 0, 0);
 		assert_ok!(GameModule::<T>::setup_game(RawOrigin::Root.into()));
 		let admin: T::AccountId = account("admin", 0, 0);
-		assert_ok!(GameModule::<T>::add_to_admins(RawOrigin::Root.into(), admin.clone()));
+		assert_ok!(GameModule::<T>::add_to_admins(
+			RawOrigin::Root.into(),
+			Role::Registrar,
+			admin.clone()
+		));
 		#[extrinsic_call]
 		register_user(RawOrigin::Signed(admin), caller.clone());
 
@@ -93,18 +138,12 @@ mod benchmarks {
 		#[extrinsic_call]
 		submit_answer(RawOrigin::Signed(caller.clone()), 220000, 1);
 
-		assert_ok!(GameModule::<T>::check_result(
-			RawOrigin::Root.into(),
-			220000,
-			1,
-			220000,
-			"test".as_bytes().to_vec().try_into().unwrap(),
-		));
+		reveal::<T>(1);
 		assert_eq!(GameModule::<T>::users::<AccountIdOf<T>>(caller).unwrap().nfts.xorange, 1);
 	}
 
 	#[benchmark]
-	fn check_result() {
+	fn reveal_result() {
 		let caller = create_setup::<T>();
 		current_block::<T>(30u32.into());
 		practise_round::<T>(caller.clone(), 0);
@@ -117,14 +156,11 @@ mod benchmarks {
 			220000,
 			1
 		));
-		#[extrinsic_call]
-		check_result(
-			RawOrigin::Root,
-			220000,
-			1,
-			220000,
-			"test".as_bytes().to_vec().try_into().unwrap(),
-		);
+		T::BenchmarkHelper::reveal_round(GameModule::<T>::game_info(1).unwrap().property.round);
+		#[block]
+		{
+			GameModule::<T>::reveal_result(1);
+		}
 
 		assert_eq!(GameModule::<T>::users::<AccountIdOf<T>>(caller).unwrap().nfts.xorange, 1);
 	}
@@ -143,15 +179,14 @@ mod benchmarks {
 			220000,
 			1
 		));
-		assert_ok!(GameModule::<T>::check_result(
-			RawOrigin::Root.into(),
-			220000,
-			1,
-			220000,
-			"test".as_bytes().to_vec().try_into().unwrap(),
-		));
+		reveal::<T>(1);
 		#[extrinsic_call]
-		list_nft(RawOrigin::Signed(caller.clone()), 0.into(), 0.into());
+		list_nft(
+			RawOrigin::Signed(caller.clone()),
+			0.into(),
+			0.into(),
+			ListingPrice::MinimumBid(Zero::zero()),
+		);
 
 		assert_eq!(GameModule::<T>::listings(0).unwrap().owner, caller);
 	}
@@ -170,17 +205,12 @@ mod benchmarks {
 			220000,
 			1
 		));
-		assert_ok!(GameModule::<T>::check_result(
-			RawOrigin::Root.into(),
-			220000,
-			1,
-			220000,
-			"test".as_bytes().to_vec().try_into().unwrap(),
-		));
+		reveal::<T>(1);
 		assert_ok!(GameModule::<T>::list_nft(
 			RawOrigin::Signed(caller.clone()).into(),
 			0.into(),
-			0.into()
+			0.into(),
+			ListingPrice::MinimumBid(Zero::zero()),
 		));
 		#[extrinsic_call]
 		delist_nft(RawOrigin::Signed(caller), 0);
@@ -202,17 +232,12 @@ mod benchmarks {
 			220000,
 			1
 		));
-		assert_ok!(GameModule::<T>::check_result(
-			RawOrigin::Root.into(),
-			220000,
-			1,
-			220000,
-			"test".as_bytes().to_vec().try_into().unwrap(),
-		));
+		reveal::<T>(1);
 		assert_ok!(GameModule::<T>::list_nft(
 			RawOrigin::Signed(caller.clone()).into(),
 			0.into(),
-			0.into()
+			0.into(),
+			ListingPrice::MinimumBid(Zero::zero()),
 		));
 		let caller2: T::AccountId = account("caller2", 0, 0);
 		let admin: T::AccountId = account("admin", 0, 0);
@@ -230,15 +255,10 @@ mod benchmarks {
 			220000,
 			3
 		));
-		assert_ok!(GameModule::<T>::check_result(
-			RawOrigin::Root.into(),
-			220000,
-			3,
-			220000,
-			"test".as_bytes().to_vec().try_into().unwrap(),
-		));
+		reveal::<T>(3);
+		let amount = bid::<T>(&caller2);
 		#[extrinsic_call]
-		make_offer(RawOrigin::Signed(caller2.clone()), 0, 0.into(), 1.into());
+		make_offer(RawOrigin::Signed(caller2.clone()), 0, Some((0.into(), 1.into())), amount);
 
 		assert_eq!(GameModule::<T>::offers(0).unwrap().owner, caller2);
 	}
@@ -257,17 +277,12 @@ mod benchmarks {
 			220000,
 			1
 		));
-		assert_ok!(GameModule::<T>::check_result(
-			RawOrigin::Root.into(),
-			220000,
-			1,
-			220000,
-			"test".as_bytes().to_vec().try_into().unwrap(),
-		));
+		reveal::<T>(1);
 		assert_ok!(GameModule::<T>::list_nft(
 			RawOrigin::Signed(caller.clone()).into(),
 			0.into(),
-			0.into()
+			0.into(),
+			ListingPrice::MinimumBid(Zero::zero()),
 		));
 		let caller2: T::AccountId = account("caller2", 0, 0);
 		let admin: T::AccountId = account("admin", 0, 0);
@@ -285,22 +300,17 @@ mod benchmarks {
 			220000,
 			3
 		));
-		assert_ok!(GameModule::<T>::check_result(
-			RawOrigin::Root.into(),
-			220000,
-			3,
-			220000,
-			"test".as_bytes().to_vec().try_into().unwrap(),
-		));
+		reveal::<T>(3);
 		assert_eq!(
 			GameModule::<T>::users::<AccountIdOf<T>>(caller2.clone()).unwrap().nfts.xorange,
 			1
 		);
+		let amount = bid::<T>(&caller2);
 		assert_ok!(GameModule::<T>::make_offer(
 			RawOrigin::Signed(caller2.clone()).into(),
 			0,
-			0.into(),
-			1.into()
+			Some((0.into(), 1.into())),
+			amount,
 		));
 
 		#[extrinsic_call]
@@ -313,11 +323,8 @@ mod benchmarks {
 	#[benchmark]
 	fn add_property() {
 		assert_ok!(GameModule::<T>::setup_game(RawOrigin::Root.into()));
-		let new_property = PropertyInfoData {
-			id: 147031382,
-			data: "nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(),
-			price: "kkjfkdjdkdjdkdjdk".as_bytes().to_vec().try_into().unwrap(),
-		};
+		lock_properties::<T>();
+		let new_property = property::<T>(147031384, GameModule::<T>::game_properties()[0].round);
 		#[extrinsic_call]
 		add_property(RawOrigin::Root, new_property);
 
@@ -327,27 +334,44 @@ mod benchmarks {
 	#[benchmark]
 	fn remove_property() {
 		assert_ok!(GameModule::<T>::setup_game(RawOrigin::Root.into()));
+		lock_properties::<T>();
 		#[extrinsic_call]
 		remove_property(RawOrigin::Root, 146480642);
 
 		assert_eq!(GameModule::<T>::game_properties().len(), 3);
 	}
 
+	#[benchmark]
+	fn import_properties(n: Linear<1, { T::MaxProperty::get() - 4 }>) {
+		assert_ok!(GameModule::<T>::setup_game(RawOrigin::Root.into()));
+		lock_properties::<T>();
+		let round = GameModule::<T>::game_properties()[0].round;
+		let properties = (1..=n).map(|id| property_v1::<T>(id, round)).collect::<Vec<_>>();
+		#[extrinsic_call]
+		import_properties(RawOrigin::Root, BoundedVec::truncate_from(properties));
+
+		assert_eq!(GameModule::<T>::game_properties().len() as u32, n + 4);
+	}
+
 	#[benchmark]
 	fn add_to_admins() {
 		assert_ok!(GameModule::<T>::setup_game(RawOrigin::Root.into()));
 		let new_admin: T::AccountId = account("new_admin", 1, 0);
 		#[extrinsic_call]
-		add_to_admins(RawOrigin::Root, new_admin);
+		add_to_admins(RawOrigin::Root, Role::Registrar, new_admin);
 	}
 
 	#[benchmark]
 	fn remove_from_admins() {
 		assert_ok!(GameModule::<T>::setup_game(RawOrigin::Root.into()));
 		let new_admin: T::AccountId = account("new_admin", 0, 0);
-		assert_ok!(GameModule::<T>::add_to_admins(RawOrigin::Root.into(), new_admin.clone()));
+		assert_ok!(GameModule::<T>::add_to_admins(
+			RawOrigin::Root.into(),
+			Role::Registrar,
+			new_admin.clone()
+		));
 		#[extrinsic_call]
-		remove_from_admins(RawOrigin::Root, new_admin);
+		remove_from_admins(RawOrigin::Root, Role::Registrar, new_admin);
 	}
 
 	#[benchmark]
@@ -359,9 +383,403 @@ mod benchmarks {
 		request_token(RawOrigin::Signed(caller));
 	}
 
+	#[benchmark]
+	fn set_scoring() {
+		let bands = (0..MaxScoreBands::get())
+			.map(|band| ScoreBand {
+				max_difference: band as u16 * 10,
+				points: 10,
+				won: true,
+				nft_chance: Default::default(),
+			})
+			.collect::<Vec<_>>();
+		let scoring = DifficultyScoring {
+			entry_points: 25,
+			expiry_blocks: 8,
+			bands: BoundedVec::truncate_from(bands),
+			miss_penalty: 25,
+		};
+		let scoring = ScoringTable {
+			practice: scoring.clone(),
+			player: scoring.clone(),
+			pro: scoring,
+			practise_round_limit: 5,
+		};
+		#[extrinsic_call]
+		set_scoring(RawOrigin::Root, scoring.clone());
+
+		assert_eq!(GameModule::<T>::scoring(), scoring);
+	}
+
+	#[benchmark]
+	fn schedule_round() {
+		let start = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		let prize = <T as Config>::Currency::minimum_balance();
+		let prizes = (0..T::MaxPrizes::get()).map(|_| prize).collect::<Vec<_>>();
+		#[extrinsic_call]
+		schedule_round(
+			RawOrigin::Root,
+			start,
+			start + 100u32.into(),
+			BoundedVec::truncate_from(prizes),
+		);
+
+		assert!(GameModule::<T>::next_round().is_some());
+	}
+
+	#[benchmark]
+	fn cancel_scheduled_round() {
+		let start = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		assert_ok!(GameModule::<T>::schedule_round(
+			RawOrigin::Root.into(),
+			start,
+			start + 100u32.into(),
+			Default::default(),
+		));
+		#[extrinsic_call]
+		cancel_scheduled_round(RawOrigin::Root);
+
+		assert!(GameModule::<T>::next_round().is_none());
+	}
+
+	#[benchmark]
+	fn end_round() {
+		let start = frame_system::Pallet::<T>::block_number() + 1u32.into();
+		let prize = <T as Config>::Currency::minimum_balance();
+		let prizes = (0..T::MaxPrizes::get()).map(|_| prize).collect::<Vec<_>>();
+		assert_ok!(GameModule::<T>::schedule_round(
+			RawOrigin::Root.into(),
+			start,
+			start + 100u32.into(),
+			BoundedVec::truncate_from(prizes),
+		));
+		current_block::<T>(start);
+		<T as Config>::Currency::make_free_balance_be(
+			&GameModule::<T>::account_id(),
+			prize.saturating_mul((T::MaxPrizes::get() + 10).into()),
+		);
+		let limit = T::LeaderboardLimit::get();
+		let leaderboard = (0..limit)
+			.map(|rank| (account::<T::AccountId>("player", rank, 0), limit - rank))
+			.collect::<Vec<_>>();
+		Leaderboard::<T>::put(BoundedVec::truncate_from(leaderboard));
+		#[extrinsic_call]
+		end_round(RawOrigin::Root);
+
+		assert!(!GameModule::<T>::round_active());
+		assert_eq!(GameModule::<T>::round_archive(1).len() as u32, limit);
+	}
+
+	#[benchmark]
+	fn buy_nft() {
+		let caller = create_setup::<T>();
+		current_block::<T>(30u32.into());
+		practise_round::<T>(caller.clone(), 0);
+		assert_ok!(GameModule::<T>::play_game(
+			RawOrigin::Signed(caller.clone()).into(),
+			crate::DifficultyLevel::Player
+		));
+		assert_ok!(GameModule::<T>::submit_answer(
+			RawOrigin::Signed(caller.clone()).into(),
+			220000,
+			1
+		));
+		reveal::<T>(1);
+		let buyer: T::AccountId = account("buyer", 0, 0);
+		let admin: T::AccountId = account("admin", 0, 0);
+		assert_ok!(GameModule::<T>::register_user(RawOrigin::Signed(admin).into(), buyer.clone()));
+		let price = bid::<T>(&buyer);
+		assert_ok!(GameModule::<T>::list_nft(
+			RawOrigin::Signed(caller.clone()).into(),
+			0.into(),
+			0.into(),
+			ListingPrice::Fixed(price),
+		));
+		#[extrinsic_call]
+		buy_nft(RawOrigin::Signed(buyer.clone()), 0);
+
+		assert!(GameModule::<T>::listings(0).is_none());
+		assert_eq!(GameModule::<T>::users::<AccountIdOf<T>>(buyer).unwrap().nfts.xorange, 1);
+	}
+
+	#[benchmark]
+	fn create_lobby() {
+		let caller = create_setup::<T>();
+		practised::<T>(&caller);
+		let invite = T::Hashing::hash(&INVITE_CODE);
+		#[extrinsic_call]
+		create_lobby(
+			RawOrigin::Signed(caller.clone()),
+			crate::DifficultyLevel::Player,
+			T::MaxLobbyPlayers::get(),
+			invite,
+		);
+
+		assert_eq!(GameModule::<T>::player_lobby(caller), Some(0));
+	}
+
+	#[benchmark]
+	fn join_lobby() {
+		let capacity = T::MaxLobbyPlayers::get();
+		open_lobby::<T>(capacity - 1, capacity);
+		let caller = lobby_player::<T>(capacity);
+		#[extrinsic_call]
+		join_lobby(RawOrigin::Signed(caller.clone()), 0, INVITE_CODE);
+
+		assert!(matches!(GameModule::<T>::lobbies(0).unwrap().state, LobbyState::Guessing { .. }));
+	}
+
+	#[benchmark]
+	fn leave_lobby(n: Linear<2, { T::MaxLobbyPlayers::get() - 1 }>) {
+		let caller = open_lobby::<T>(n, T::MaxLobbyPlayers::get());
+		#[extrinsic_call]
+		leave_lobby(RawOrigin::Signed(caller.clone()), 0);
+
+		assert!(GameModule::<T>::lobbies(0).is_none());
+		assert!(GameModule::<T>::player_lobby(caller).is_none());
+	}
+
+	#[benchmark]
+	fn start_lobby() {
+		let capacity = T::MaxLobbyPlayers::get();
+		let caller = open_lobby::<T>(capacity - 1, capacity);
+		#[extrinsic_call]
+		start_lobby(RawOrigin::Signed(caller), 0);
+
+		assert!(matches!(GameModule::<T>::lobbies(0).unwrap().state, LobbyState::Guessing { .. }));
+	}
+
+	#[benchmark]
+	fn queue_for_lobby() {
+		create_setup::<T>();
+		let mut index = 0;
+		let caller = loop {
+			let player = lobby_player::<T>(index);
+			if would_match::<T>(&player) {
+				break player;
+			}
+			assert_ok!(GameModule::<T>::queue_for_lobby(
+				RawOrigin::Signed(player).into(),
+				crate::DifficultyLevel::Player
+			));
+			index += 1;
+		};
+		#[extrinsic_call]
+		queue_for_lobby(RawOrigin::Signed(caller.clone()), crate::DifficultyLevel::Player);
+
+		assert_eq!(GameModule::<T>::player_lobby(caller), Some(0));
+	}
+
+	#[benchmark]
+	fn leave_lobby_queue() {
+		create_setup::<T>();
+		let caller = lobby_player::<T>(0);
+		assert_ok!(GameModule::<T>::queue_for_lobby(
+			RawOrigin::Signed(caller.clone()).into(),
+			crate::DifficultyLevel::Player
+		));
+		#[extrinsic_call]
+		leave_lobby_queue(RawOrigin::Signed(caller.clone()));
+
+		assert!(GameModule::<T>::matchmaking_entries(caller).is_none());
+	}
+
+	#[benchmark]
+	fn submit_sealed_guess() {
+		let caller = open_lobby::<T>(2, 2);
+		let round = lobby_round::<T>(0);
+		let sealed_guess = T::BenchmarkHelper::lock_price(round, 220000).try_into().unwrap();
+		#[extrinsic_call]
+		submit_sealed_guess(RawOrigin::Signed(caller.clone()), 0, sealed_guess);
+
+		assert!(GameModule::<T>::lobby_guesses(0, caller).is_some());
+	}
+
+	#[benchmark]
+	fn settle_lobby(n: Linear<2, { T::MaxLobbyPlayers::get() }>) {
+		open_lobby::<T>(n, n);
+		let round = lobby_round::<T>(0);
+		for (player, _) in GameModule::<T>::lobbies(0).unwrap().players {
+			let sealed_guess = T::BenchmarkHelper::lock_price(round, 220000).try_into().unwrap();
+			assert_ok!(GameModule::<T>::submit_sealed_guess(
+				RawOrigin::Signed(player).into(),
+				0,
+				sealed_guess
+			));
+		}
+		T::BenchmarkHelper::reveal_round(round);
+		#[block]
+		{
+			GameModule::<T>::settle_lobby(0);
+		}
+
+		assert!(GameModule::<T>::lobbies(0).is_none());
+	}
+
+	#[benchmark]
+	fn expire_game() {
+		let caller = create_setup::<T>();
+		current_block::<T>(30u32.into());
+		practise_round::<T>(caller.clone(), 0);
+		assert_ok!(GameModule::<T>::play_game(
+			RawOrigin::Signed(caller.clone()).into(),
+			crate::DifficultyLevel::Player
+		));
+		let now = frame_system::Pallet::<T>::block_number();
+		#[block]
+		{
+			GameModule::<T>::expire_game(1, now);
+		}
+
+		assert!(!GameModule::<T>::game_info(1).unwrap().state.is_open());
+	}
+
+	#[benchmark]
+	fn settle_game() {
+		let caller = create_setup::<T>();
+		current_block::<T>(30u32.into());
+		practise_round::<T>(caller.clone(), 0);
+		assert_ok!(GameModule::<T>::play_game(
+			RawOrigin::Signed(caller.clone()).into(),
+			crate::DifficultyLevel::Player
+		));
+		assert_ok!(GameModule::<T>::submit_answer(
+			RawOrigin::Signed(caller.clone()).into(),
+			220000,
+			1
+		));
+		let settlement_block = GameModule::<T>::game_info(1).unwrap().settlement_block;
+		#[block]
+		{
+			GameModule::<T>::expire_game(1, settlement_block);
+		}
+
+		assert_eq!(GameModule::<T>::game_info(1).unwrap().state, GameState::Voided);
+	}
+
+	#[benchmark]
+	fn cleanup_game() {
+		let caller = create_setup::<T>();
+		current_block::<T>(30u32.into());
+		practise_round::<T>(caller.clone(), 0);
+		assert!(GameModule::<T>::game_info(0).is_some());
+		#[block]
+		{
+			GameModule::<T>::cleanup_games(Weight::MAX);
+		}
+
+		assert!(GameModule::<T>::game_info(0).is_none());
+	}
+
+	#[benchmark]
+	fn register() -> Result<(), BenchmarkError> {
+		let deposit = T::RegistrationDeposit::get().ok_or(BenchmarkError::Weightless)?;
+		let caller: T::AccountId = whitelisted_caller();
+		assert_ok!(GameModule::<T>::setup_game(RawOrigin::Root.into()));
+		<T as Config>::Currency::make_free_balance_be(
+			&caller,
+			deposit.saturating_add(<T as Config>::Currency::minimum_balance()),
+		);
+		#[extrinsic_call]
+		register(RawOrigin::Signed(caller.clone()));
+
+		assert!(GameModule::<T>::users(caller.clone()).is_some());
+		assert_eq!(GameModule::<T>::registration_deposits(caller), Some(deposit));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn submit_result() {
+		answered_game::<T>();
+		#[extrinsic_call]
+		submit_result(RawOrigin::Root, 1, 220000);
+
+		assert!(GameModule::<T>::proposed_results(1).is_some());
+	}
+
+	#[benchmark]
+	fn dispute_result() {
+		answered_game::<T>();
+		assert_ok!(GameModule::<T>::submit_result(RawOrigin::Root.into(), 1, 220000));
+		#[extrinsic_call]
+		dispute_result(RawOrigin::Root, 1);
+
+		assert!(GameModule::<T>::proposed_results(1).unwrap().disputed);
+	}
+
+	#[benchmark]
+	fn finalise_result() {
+		answered_game::<T>();
+		assert_ok!(GameModule::<T>::submit_result(RawOrigin::Root.into(), 1, 220000));
+		#[block]
+		{
+			GameModule::<T>::finalise_result(1);
+		}
+
+		assert!(!GameModule::<T>::game_info(1).unwrap().state.is_open());
+	}
+
+	#[benchmark]
+	fn craft_nft() {
+		let caller = create_setup::<T>();
+		let collection_id = GameModule::<T>::round_collections(GameModule::<T>::current_round())[0];
+		let items = collected::<T>(&caller, collection_id, T::CraftingCost::get());
+		#[extrinsic_call]
+		craft_nft(RawOrigin::Signed(caller.clone()), collection_id, items.try_into().unwrap());
+
+		assert_eq!(GameModule::<T>::users(caller).unwrap().nfts.xorange, 0);
+	}
+
+	#[benchmark]
+	fn craft_trophy() {
+		let caller = create_setup::<T>();
+		let items = GameModule::<T>::round_collections(GameModule::<T>::current_round())
+			.into_iter()
+			.flat_map(|collection_id| collected::<T>(&caller, collection_id, 1))
+			.collect::<Vec<_>>();
+		#[extrinsic_call]
+		craft_trophy(RawOrigin::Signed(caller.clone()), items.try_into().unwrap());
+
+		assert!(GameModule::<T>::trophy_collection().is_some());
+	}
+
 	impl_benchmark_test_suite!(GameModule, crate::mock::new_test_ext(), crate::mock::Test);
 }
 
+/// Funds the bidder and returns an amount that leaves the account alive after paying it.
+fn bid<T: Config>(bidder: &T::AccountId) -> CurrencyBalanceOf<T> {
+	let amount = <T as Config>::Currency::minimum_balance().saturating_mul(100u32.into());
+	<T as Config>::Currency::make_free_balance_be(bidder, amount.saturating_mul(2u32.into()));
+	amount
+}
+
+/// Starts a game that has been answered and waits for its price.
+fn answered_game<T: Config>() {
+	let caller = create_setup::<T>();
+	current_block::<T>(30u32.into());
+	practise_round::<T>(caller.clone(), 0);
+	assert_ok!(GameModule::<T>::play_game(
+		RawOrigin::Signed(caller.clone()).into(),
+		crate::DifficultyLevel::Player
+	));
+	assert_ok!(GameModule::<T>::submit_answer(RawOrigin::Signed(caller).into(), 220000, 1));
+}
+
+/// Mints nfts of a collection to a player and returns their item ids.
+fn collected<T: Config>(
+	player: &T::AccountId,
+	collection_id: CollectionId<T>,
+	amount: u32,
+) -> Vec<ItemId<T>> {
+	(0..amount)
+		.map(|_| {
+			let item_id = GameModule::<T>::mint_nft(player, collection_id).unwrap();
+			assert_ok!(GameModule::<T>::add_user_color(player, collection_id));
+			item_id
+		})
+		.collect()
+}
+
 fn current_block<T: Config>(new_block: frame_system::pallet_prelude::BlockNumberFor<T>) {
 	while frame_system::Pallet::<T>::block_number() < new_block {
 		if frame_system::Pallet::<T>::block_number() > 0u32.into() {
@@ -376,3 +794,57 @@ fn current_block<T: Config>(new_block: frame_system::pallet_prelude::BlockNumber
 		GameModule::<T>::on_initialize(frame_system::Pallet::<T>::block_number());
 	}
 }
+
+const INVITE_CODE: [u8; 32] = [7; 32];
+
+/// Lets a player skip the practise round.
+fn practised<T: Config>(player: &T::AccountId) {
+	Users::<T>::mutate(player, |user| {
+		if let Some(user) = user {
+			user.practise_rounds = 1;
+		}
+	});
+}
+
+/// Registers a player who can join lobbies.
+fn lobby_player<T: Config>(index: u32) -> T::AccountId {
+	let player: T::AccountId = account("player", index, 0);
+	let admin: T::AccountId = account("admin", 0, 0);
+	assert_ok!(GameModule::<T>::register_user(RawOrigin::Signed(admin).into(), player.clone()));
+	practised::<T>(&player);
+	player
+}
+
+/// Opens lobby 0 with `players` players and returns its host.
+fn open_lobby<T: Config>(players: u32, capacity: u32) -> T::AccountId {
+	let host = create_setup::<T>();
+	practised::<T>(&host);
+	assert_ok!(GameModule::<T>::create_lobby(
+		RawOrigin::Signed(host.clone()).into(),
+		crate::DifficultyLevel::Player,
+		capacity,
+		T::Hashing::hash(&INVITE_CODE),
+	));
+	for index in 1..players {
+		let player = lobby_player::<T>(index);
+		assert_ok!(GameModule::<T>::join_lobby(RawOrigin::Signed(player).into(), 0, INVITE_CODE));
+	}
+	host
+}
+
+fn lobby_round<T: Config>(lobby_id: u32) -> RoundNumber {
+	match GameModule::<T>::lobbies(lobby_id).unwrap().state {
+		LobbyState::Guessing { property, .. } => property.round,
+		LobbyState::Open { .. } => panic!("lobby has not started"),
+	}
+}
+
+/// Checks without changing the queue if the player would complete a match.
+fn would_match<T: Config>(player: &T::AccountId) -> bool {
+	with_transaction(|| {
+		let matched = T::Matchmaker::add_queue(player.clone(), 0).is_ok() &&
+			!T::Matchmaker::try_match_bracket(0).is_empty();
+		TransactionOutcome::Rollback(Ok::<_, DispatchError>(matched))
+	})
+	.unwrap()
+}
@@ -0,0 +1,88 @@
+use crate::*;
+use frame_support::pallet_prelude::*;
+
+impl<T: Config> Pallet<T> {
+	/// Burns nfts of one color of the current round and mints a nft of a random other color.
+	pub(crate) fn do_craft_nft(
+		player: AccountIdOf<T>,
+		collection_id: CollectionId<T>,
+		items: &[ItemId<T>],
+	) -> DispatchResult {
+		Self::ensure_can_craft(&player)?;
+		ensure!(items.len() as u32 == T::CraftingCost::get(), Error::<T>::InvalidRecipe);
+		let collections = Self::round_collections(Self::current_round());
+		let source = collections
+			.iter()
+			.position(|id| *id == collection_id)
+			.ok_or(Error::<T>::CollectionUnknown)?;
+		for item_id in items {
+			Self::burn_nft(&player, collection_id, *item_id)?;
+		}
+		let (hashi, _) =
+			T::GameRandomness::random(&(b"craft", &player, collection_id, items).encode());
+		let u32_value = u32::from_le_bytes(
+			hashi.as_ref()[4..8].try_into().map_err(|_| Error::<T>::ConversionError)?,
+		);
+		// The crafted nft never has the color of the burned nfts.
+		let offset = 1 + u32_value as usize % collections.len().saturating_sub(1).max(1);
+		let crafted = collections[(source + offset) % collections.len()];
+		let item_id = Self::mint_nft(&player, crafted)?;
+		Self::add_user_color(&player, crafted)?;
+		Self::deposit_event(Event::<T>::NftCrafted {
+			player,
+			burned_collection: collection_id,
+			collection_id: crafted,
+			item_id,
+		});
+		Ok(())
+	}
+
+	/// Burns a nft of every color of the current round and mints a trophy to the player, which
+	/// gives bonus points.
+	///
+	/// `items[i]` has to be an item of the `i`th collection of the round.
+	pub(crate) fn do_craft_trophy(player: AccountIdOf<T>, items: &[ItemId<T>]) -> DispatchResult {
+		Self::ensure_can_craft(&player)?;
+		let collections = Self::round_collections(Self::current_round());
+		ensure!(items.len() == collections.len(), Error::<T>::InvalidRecipe);
+		for (collection_id, item_id) in collections.iter().zip(items) {
+			Self::burn_nft(&player, *collection_id, *item_id)?;
+		}
+		let collection_id = match Self::trophy_collection() {
+			Some(collection_id) => collection_id,
+			None => {
+				let collection_id = Self::create_collection()?;
+				TrophyCollection::<T>::put(collection_id);
+				collection_id
+			},
+		};
+		let item_id = Self::mint_nft(&player, collection_id)?;
+		let points = T::TrophyPoints::get();
+		let mut user = Self::users(&player).ok_or(Error::<T>::UserNotRegistered)?;
+		user.points = user.points.checked_add(points).ok_or(Error::<T>::ArithmeticOverflow)?;
+		Users::<T>::insert(&player, user.clone());
+		Self::update_leaderboard(player.clone(), user.points)?;
+		Self::deposit_event(Event::<T>::TrophyCrafted { player, collection_id, item_id, points });
+		Ok(())
+	}
+
+	/// Checks that the player is registered and the round is running.
+	fn ensure_can_craft(player: &AccountIdOf<T>) -> DispatchResult {
+		ensure!(Self::users(player).is_some(), Error::<T>::UserNotRegistered);
+		ensure!(RoundActive::<T>::get(), Error::<T>::NoActiveRound);
+		Ok(())
+	}
+
+	/// Burns a nft of the player and removes its color from the player.
+	fn burn_nft(
+		player: &AccountIdOf<T>,
+		collection_id: CollectionId<T>,
+		item_id: ItemId<T>,
+	) -> DispatchResult {
+		pallet_nfts::Pallet::<T>::do_burn(collection_id.into(), item_id.into(), |details| {
+			ensure!(details.owner == *player, Error::<T>::NoPermission);
+			Ok(())
+		})?;
+		Self::remove_user_color(player, collection_id)
+	}
+}
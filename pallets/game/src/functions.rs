@@ -1,6 +1,7 @@
 use crate::*;
-use frame_support::pallet_prelude::*;
+use frame_support::{pallet_prelude::*, storage::with_storage_layer};
 use frame_system::pallet_prelude::*;
+use sp_std::prelude::*;
 
 impl<T: Config> Pallet<T> {
 	/// Get the account id of the pallet
@@ -8,304 +9,198 @@ impl<T: Config> Pallet<T> {
 		<T as pallet::Config>::PalletId::get().into_account_truncating()
 	}
 
+	/// Stores a new player with the initial points.
+	pub fn do_register_user(player: AccountIdOf<T>) {
+		let current_block_number = <frame_system::Pallet<T>>::block_number();
+		let next_request = current_block_number.saturating_add(<T as Config>::RequestLimit::get());
+		let user = User {
+			points: T::InitialPoints::get(),
+			wins: Default::default(),
+			losses: Default::default(),
+			practise_rounds: Default::default(),
+			last_played_round: CurrentRound::<T>::get(),
+			next_token_request: next_request,
+			nfts: CollectedColors::default(),
+		};
+		Users::<T>::insert(player.clone(), user);
+		frame_system::Pallet::<T>::inc_providers(&player);
+		Self::deposit_event(Event::<T>::NewPlayerRegistered { player });
+	}
+
+	/// Tops the free balance of the player up to the faucet amount.
+	pub fn fund_player(player: &AccountIdOf<T>) {
+		let balance = <T as pallet::Config>::Currency::free_balance(player);
+		let missing = T::FaucetAmount::get().saturating_sub(balance);
+		if !missing.is_zero() {
+			let _ = <T as pallet::Config>::Currency::deposit_creating(player, missing);
+		}
+	}
+
 	/// checks if the signer has enough points to start a game.
 	pub fn check_enough_points(
 		signer: AccountIdOf<T>,
 		game_type: DifficultyLevel,
 	) -> DispatchResult {
-		if game_type == DifficultyLevel::Pro {
-			ensure!(
-				Self::users(signer.clone())
-					.ok_or(Error::<T>::UserNotRegistered)?
-					.practise_rounds > 0,
-				Error::<T>::NoPractise
-			);
-			ensure!(
-				Self::users(signer).ok_or(Error::<T>::UserNotRegistered)?.points >= 50,
-				Error::<T>::NotEnoughPoints
-			);
-		} else if game_type == DifficultyLevel::Player {
-			ensure!(
-				Self::users(signer.clone())
-					.ok_or(Error::<T>::UserNotRegistered)?
-					.practise_rounds > 0,
-				Error::<T>::NoPractise
-			);
-			ensure!(
-				Self::users(signer).ok_or(Error::<T>::UserNotRegistered)?.points >= 25,
-				Error::<T>::NotEnoughPoints
-			);
-		} else {
+		let user = Self::users(signer).ok_or(Error::<T>::UserNotRegistered)?;
+		let scoring = Self::scoring();
+		if game_type == DifficultyLevel::Practice {
 			ensure!(
-				Self::users(signer).ok_or(Error::<T>::UserNotRegistered)?.practise_rounds < 5,
+				user.practise_rounds < scoring.practise_round_limit,
 				Error::<T>::TooManyPractise
 			);
+		} else {
+			ensure!(user.practise_rounds > 0, Error::<T>::NoPractise);
 		}
+		ensure!(
+			user.points >= scoring.difficulty(&game_type).entry_points,
+			Error::<T>::NotEnoughPoints
+		);
 		Ok(())
 	}
 
 	/// checks the answer and distributes the rewards accordingly.
-	pub fn do_check_result(difference: u16, game_id: u32, secret: BoundedVec<u8, <T as Config>::StringLimit> ) -> DispatchResult {
-		let game_info = GameInfo::<T>::take(game_id).ok_or(Error::<T>::NoActiveGame)?;
+	pub fn do_check_result(difference: u16, game_id: u32, price: u32) -> DispatchResult {
+		let game_info = GameInfo::<T>::get(game_id).ok_or(Error::<T>::NoActiveGame)?;
+		ensure!(game_info.state == GameState::Answered, Error::<T>::NoActiveGame);
 		ensure!(game_info.guess.is_some(), Error::<T>::NoGuess);
-		if game_info.difficulty == DifficultyLevel::Pro {
-			match difference {
-				0..=10 => {
-					let (hashi, _) = T::GameRandomness::random(&[game_id as u8]);
-					let u32_value = u32::from_le_bytes(
-						hashi.as_ref()[4..8].try_into().map_err(|_| Error::<T>::ConversionError)?,
-					);
-					let random_number = (u32_value % 8)
-						.checked_add(
-							8 * (Self::current_round()
-								.checked_sub(1)
-								.ok_or(Error::<T>::ArithmeticUnderflow)?),
-						)
+		let scoring = Self::scoring().difficulty(&game_info.difficulty).clone();
+		let mut user =
+			Self::users(game_info.player.clone()).ok_or(Error::<T>::UserNotRegistered)?;
+		let band = scoring.band(difference);
+		let (points, won, nft) = match band {
+			Some(band) if band.won => {
+				let (hashi, _) = T::GameRandomness::random(&game_id.encode());
+				let roll = u32::from_le_bytes(
+					hashi.as_ref()[0..4].try_into().map_err(|_| Error::<T>::ConversionError)?,
+				) % 100;
+				if roll < band.nft_chance.deconstruct().into() {
+					let (points, collection_id, item_id) =
+						Self::drop_nft(&game_info.player, &mut user, hashi)?;
+					(points, true, Some((collection_id, item_id)))
+				} else {
+					user.points = user
+						.points
+						.checked_add(band.points)
 						.ok_or(Error::<T>::ArithmeticOverflow)?;
-					let collection_id: <T as pallet::Config>::CollectionId = random_number.into();
-					let next_item_id = NextColorId::<T>::get(collection_id);
-					let item_id: ItemId<T> = next_item_id.into();
-					let next_item_id =
-						next_item_id.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					NextColorId::<T>::insert(collection_id, next_item_id);
-					pallet_nfts::Pallet::<T>::do_mint(
-						collection_id.into(),
-						item_id.into(),
-						Some(Self::account_id()),
-						game_info.player.clone(),
-						Self::default_item_config(),
-						|_, _| Ok(()),
-					)?;
-					let pallet_origin: OriginFor<T> = RawOrigin::Signed(Self::account_id()).into();
-					pallet_nfts::Pallet::<T>::lock_item_transfer(
-						pallet_origin,
-						collection_id.into(),
-						item_id.into(),
-					)?;
-					let mut user = Self::users(game_info.player.clone())
-						.ok_or(Error::<T>::UserNotRegistered)?;
-					let color = Self::collection_color(collection_id)
-						.ok_or(Error::<T>::CollectionUnknown)?;
-					user.add_nft_color(color.clone())?;
-					let points = user.calculate_points(color);
-					user.points =
-						user.points.checked_add(points).ok_or(Error::<T>::ArithmeticOverflow)?;
-					user.wins = user.wins.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					Users::<T>::insert(game_info.player.clone(), user.clone());
-					if user.has_four_of_all_colors() {
-						Self::end_game(game_info.player.clone())?;
-					}
-					Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points, won: true, nft_received: true });
-				},
-				11..=30 => {
-					let mut user = Self::users(game_info.player.clone())
-						.ok_or(Error::<T>::UserNotRegistered)?;
-					user.points =
-						user.points.checked_add(50).ok_or(Error::<T>::ArithmeticOverflow)?;
-					user.wins = user.wins.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					Users::<T>::insert(game_info.player.clone(), user);
-					Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points: 50, won: true, nft_received: false });
-				},
-				31..=50 => {
-					let mut user = Self::users(game_info.player.clone())
-						.ok_or(Error::<T>::UserNotRegistered)?;
-					user.points =
-						user.points.checked_add(30).ok_or(Error::<T>::ArithmeticOverflow)?;
-					user.wins = user.wins.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					Users::<T>::insert(game_info.player.clone(), user);
-					Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points: 30, won: true, nft_received: false });
-				},
-				51..=100 => {
-					let mut user = Self::users(game_info.player.clone())
-						.ok_or(Error::<T>::UserNotRegistered)?;
-					user.points =
-						user.points.checked_add(10).ok_or(Error::<T>::ArithmeticOverflow)?;
-					user.wins = user.wins.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					Users::<T>::insert(game_info.player.clone(), user);
-					Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points: 10, won: true, nft_received: false });
-				},
-				101..=150 => {
-					let mut user = Self::users(game_info.player.clone())
-						.ok_or(Error::<T>::UserNotRegistered)?;
-					user.points =
-						user.points.checked_sub(10).ok_or(Error::<T>::ArithmeticUnderflow)?;
-					user.losses = user.losses.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					Users::<T>::insert(game_info.player.clone(), user);
-					Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points: 10, won: false, nft_received: false });
-				},
-				151..=200 => {
-					let mut user = Self::users(game_info.player.clone())
-						.ok_or(Error::<T>::UserNotRegistered)?;
-					user.points =
-						user.points.checked_sub(20).ok_or(Error::<T>::ArithmeticUnderflow)?;
-					user.losses = user.losses.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					Users::<T>::insert(game_info.player.clone(), user);
-					Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points: 20, won: false, nft_received: false });
-				},
-				201..=250 => {
-					let mut user = Self::users(game_info.player.clone())
-						.ok_or(Error::<T>::UserNotRegistered)?;
-					user.points =
-						user.points.checked_sub(30).ok_or(Error::<T>::ArithmeticUnderflow)?;
-					user.losses = user.losses.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					Users::<T>::insert(game_info.player.clone(), user);
-					Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points: 30, won: false, nft_received: false });
-				},
-				251..=300 => {
-					let mut user = Self::users(game_info.player.clone())
-						.ok_or(Error::<T>::UserNotRegistered)?;
-					user.points =
-						user.points.checked_sub(40).ok_or(Error::<T>::ArithmeticUnderflow)?;
-					user.losses = user.losses.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					Users::<T>::insert(game_info.player.clone(), user);
-					Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points: 40, won: false, nft_received: false });
-				},
-				_ => {
-					let mut user = Self::users(game_info.player.clone())
-						.ok_or(Error::<T>::UserNotRegistered)?;
-					user.points =
-						user.points.checked_sub(50).ok_or(Error::<T>::ArithmeticUnderflow)?;
-					user.losses = user.losses.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					Users::<T>::insert(game_info.player.clone(), user);
-					Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points: 50, won: false, nft_received: false });
-				},
-			}
-		} else if game_info.difficulty == DifficultyLevel::Player {
-			match difference {
-				0..=10 => {
-					let (hashi, _) = T::GameRandomness::random(&[game_id as u8]);
-					let u32_value = u32::from_le_bytes(
-						hashi.as_ref()[4..8].try_into().map_err(|_| Error::<T>::ConversionError)?,
-					);
-					let random_number = (u32_value % 8)
-						.checked_add(
-							8 * (Self::current_round()
-								.checked_sub(1)
-								.ok_or(Error::<T>::ArithmeticUnderflow)?),
-						)
-						.ok_or(Error::<T>::ArithmeticOverflow)?;
-					let collection_id: <T as pallet::Config>::CollectionId = random_number.into();
-					let next_item_id = NextColorId::<T>::get(collection_id);
-					let item_id: ItemId<T> = next_item_id.into();
-					let next_item_id =
-						next_item_id.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					NextColorId::<T>::insert(collection_id, next_item_id);
-					pallet_nfts::Pallet::<T>::do_mint(
-						collection_id.into(),
-						item_id.into(),
-						Some(Self::account_id()),
-						game_info.player.clone(),
-						Self::default_item_config(),
-						|_, _| Ok(()),
-					)?;
-					let pallet_origin: OriginFor<T> = RawOrigin::Signed(Self::account_id()).into();
-					pallet_nfts::Pallet::<T>::lock_item_transfer(
-						pallet_origin,
-						collection_id.into(),
-						item_id.into(),
-					)?;
-					let mut user = Self::users(game_info.player.clone())
-						.ok_or(Error::<T>::UserNotRegistered)?;
-					let color = Self::collection_color(collection_id)
-						.ok_or(Error::<T>::CollectionUnknown)?;
-					user.add_nft_color(color.clone())?;
-					let points = user.calculate_points(color);
-					user.points =
-						user.points.checked_add(points).ok_or(Error::<T>::ArithmeticOverflow)?;
-					user.wins = user.wins.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					Users::<T>::insert(game_info.player.clone(), user.clone());
-					if user.has_four_of_all_colors() {
-						Self::end_game(game_info.player.clone())?;
-					}
-					Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points, won: true, nft_received: true });
-				},
-				11..=30 => {
-					let mut user = Self::users(game_info.player.clone())
-						.ok_or(Error::<T>::UserNotRegistered)?;
-					user.points =
-						user.points.checked_add(25).ok_or(Error::<T>::ArithmeticUnderflow)?;
-					user.wins = user.wins.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					Users::<T>::insert(game_info.player.clone(), user);
-					Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points: 25, won: true, nft_received: false });
-				},
-				31..=50 => {
-					let mut user = Self::users(game_info.player.clone())
-						.ok_or(Error::<T>::UserNotRegistered)?;
-					user.points =
-						user.points.checked_add(15).ok_or(Error::<T>::ArithmeticUnderflow)?;
-					user.wins = user.wins.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					Users::<T>::insert(game_info.player.clone(), user);
-					Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points: 15, won: true, nft_received: false });
-				},
-				51..=100 => {
-					let mut user = Self::users(game_info.player.clone())
-						.ok_or(Error::<T>::UserNotRegistered)?;
-					user.points =
-						user.points.checked_add(5).ok_or(Error::<T>::ArithmeticUnderflow)?;
-					user.wins = user.wins.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					Users::<T>::insert(game_info.player.clone(), user);
-					Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points: 5, won: true, nft_received: false });
-				},
-				101..=150 => {
-					let mut user = Self::users(game_info.player.clone())
-						.ok_or(Error::<T>::UserNotRegistered)?;
-					user.points =
-						user.points.checked_sub(5).ok_or(Error::<T>::ArithmeticUnderflow)?;
-					user.losses = user.losses.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					Users::<T>::insert(game_info.player.clone(), user);
-					Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points: 5, won: false, nft_received: false });
-				},
-				151..=200 => {
-					let mut user = Self::users(game_info.player.clone())
-						.ok_or(Error::<T>::UserNotRegistered)?;
-					user.points =
-						user.points.checked_sub(10).ok_or(Error::<T>::ArithmeticUnderflow)?;
-					user.losses = user.losses.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					Users::<T>::insert(game_info.player.clone(), user);
-					Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points: 10, won: false, nft_received: false });
-				},
-				201..=250 => {
-					let mut user = Self::users(game_info.player.clone())
-						.ok_or(Error::<T>::UserNotRegistered)?;
-					user.points =
-						user.points.checked_sub(15).ok_or(Error::<T>::ArithmeticUnderflow)?;
-					user.losses = user.losses.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					Users::<T>::insert(game_info.player.clone(), user);
-					Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points: 15, won: false, nft_received: false });
-				},
-				251..=300 => {
-					let mut user = Self::users(game_info.player.clone())
-						.ok_or(Error::<T>::UserNotRegistered)?;
-					user.points =
-						user.points.checked_sub(20).ok_or(Error::<T>::ArithmeticUnderflow)?;
-					user.losses = user.losses.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					Users::<T>::insert(game_info.player.clone(), user);
-					Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points: 20, won: false, nft_received: false });
-				},
-				_ => {
-					let mut user = Self::users(game_info.player.clone())
-						.ok_or(Error::<T>::UserNotRegistered)?;
-					user.points =
-						user.points.checked_sub(25).ok_or(Error::<T>::ArithmeticUnderflow)?;
-					user.losses = user.losses.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-					Users::<T>::insert(game_info.player.clone(), user);
-					Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points: 25, won: false, nft_received: false });
-				},
-			}
-		} else {
-			let mut user =
-				Self::users(game_info.player.clone()).ok_or(Error::<T>::UserNotRegistered)?;
-			user.points = user.points.checked_add(5).ok_or(Error::<T>::ArithmeticUnderflow)?;
+					(band.points, true, None)
+				}
+			},
+			band => {
+				let points = band.map_or(scoring.miss_penalty, |band| band.points);
+				user.points =
+					user.points.checked_sub(points).ok_or(Error::<T>::ArithmeticUnderflow)?;
+				(points, false, None)
+			},
+		};
+		if !won {
+			user.losses = user.losses.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
+		} else if game_info.difficulty == DifficultyLevel::Practice {
 			user.practise_rounds =
-				user.practise_rounds.checked_add(1).ok_or(Error::<T>::ArithmeticUnderflow)?;
-			Users::<T>::insert(game_info.player.clone(), user);
-			Self::deposit_event(Event::<T>::ResultChecked { game_id, secret, points: 5, won: true, nft_received: false });
+				user.practise_rounds.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
+		} else {
+			user.wins = user.wins.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
+		}
+		Users::<T>::insert(game_info.player.clone(), user.clone());
+		Self::deposit_event(Event::<T>::ResultChecked {
+			game_id,
+			player: game_info.player.clone(),
+			property_id: game_info.property.id,
+			difficulty: game_info.difficulty.clone(),
+			price,
+			band: band.map(|band| band.max_difference),
+			points: if won { PointsDelta::Gained(points) } else { PointsDelta::Lost(points) },
+			total_points: user.points,
+			nft,
+		});
+		Self::update_leaderboard(game_info.player.clone(), user.points)?;
+		let player = game_info.player.clone();
+		Self::finish_game(game_id, game_info, GameState::Scored { points, won });
+		if nft.is_some() && user.has_four_of_all_colors() {
+			Self::end_game(player)?;
 		}
-		let user = Self::users(game_info.player.clone()).ok_or(Error::<T>::UserNotRegistered)?;
-		Self::update_leaderboard(game_info.player, user.points)?;
 		Ok(())
 	}
 
+	/// Mints a nft of a random color to the player and returns the points the player receives
+	/// and the minted nft.
+	fn drop_nft(
+		player: &AccountIdOf<T>,
+		user: &mut User<T>,
+		hashi: T::Hash,
+	) -> Result<(u32, CollectionId<T>, ItemId<T>), DispatchError> {
+		let u32_value = u32::from_le_bytes(
+			hashi.as_ref()[4..8].try_into().map_err(|_| Error::<T>::ConversionError)?,
+		);
+		let collections = Self::round_collections(Self::current_round());
+		let collection_id = *(u32_value as usize)
+			.checked_rem(collections.len())
+			.and_then(|index| collections.get(index))
+			.ok_or(Error::<T>::CollectionUnknown)?;
+		let item_id = Self::mint_nft(player, collection_id)?;
+		let color = Self::collection_color(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+		user.add_nft_color(color.clone())?;
+		let points = user.calculate_points(color);
+		user.points = user.points.checked_add(points).ok_or(Error::<T>::ArithmeticOverflow)?;
+		Ok((points, collection_id, item_id))
+	}
+
+	/// Mints the next item of a collection to the player and locks it.
+	pub(crate) fn mint_nft(
+		player: &AccountIdOf<T>,
+		collection_id: CollectionId<T>,
+	) -> Result<ItemId<T>, DispatchError> {
+		let next_item_id = NextColorId::<T>::get(collection_id);
+		let item_id: ItemId<T> = next_item_id.into();
+		let next_item_id = next_item_id.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
+		NextColorId::<T>::insert(collection_id, next_item_id);
+		pallet_nfts::Pallet::<T>::do_mint(
+			collection_id.into(),
+			item_id.into(),
+			Some(Self::account_id()),
+			player.clone(),
+			Self::default_item_config(),
+			|_, _| Ok(()),
+		)?;
+		let pallet_origin: OriginFor<T> = RawOrigin::Signed(Self::account_id()).into();
+		pallet_nfts::Pallet::<T>::lock_item_transfer(
+			pallet_origin,
+			collection_id.into(),
+			item_id.into(),
+		)?;
+		Ok(item_id)
+	}
+
+	/// Draws a random property whose price is still locked when the game ends.
+	///
+	/// Properties are drawn without replacement, so a property is played at most once per
+	/// round.
+	pub(crate) fn draw_property(subject: &[u8]) -> Result<PropertyInfoData<T>, DispatchError> {
+		let mut game_properties = GameProperties::<T>::get();
+		// Properties whose price gets revealed before the game ends can't be guessed anymore.
+		let reveal_limit = T::Timelock::latest_round().saturating_add(T::RevealDelay::get());
+		game_properties.retain(|property| property.round > reveal_limit);
+		let mut drawn = DrawnProperties::<T>::get();
+		drawn.retain(|id| game_properties.iter().any(|property| property.id == *id));
+		let pool = game_properties
+			.iter()
+			.filter(|property| !drawn.contains(&property.id))
+			.collect::<Vec<_>>();
+		ensure!(!pool.is_empty(), Error::<T>::NoProperty);
+		let (hashi, _) = T::GameRandomness::random(subject);
+		let u32_value = u32::from_le_bytes(
+			hashi.as_ref()[4..8].try_into().map_err(|_| Error::<T>::ConversionError)?,
+		);
+		let property = pool[u32_value as usize % pool.len()].clone();
+		let remaining = pool.len().saturating_sub(1) as u32;
+		drawn.try_push(property.id).map_err(|_| Error::<T>::TooManyTest)?;
+		GameProperties::<T>::put(game_properties);
+		DrawnProperties::<T>::put(drawn);
+		if remaining <= T::PropertyPoolThreshold::get() {
+			Self::deposit_event(Event::<T>::PropertyPoolLow { remaining });
+		}
+		Ok(property)
+	}
+
 	pub fn update_leaderboard(user_id: AccountIdOf<T>, new_points: u32) -> DispatchResult {
 		let mut leaderboard = Self::leaderboard();
 		let leaderboard_size = leaderboard.len();
@@ -332,53 +227,344 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Exchanges the color of a nft the holder gave away for the color of a received nft.
 	pub fn swap_user_points(
 		nft_holder: AccountIdOf<T>,
 		collection_id_add: CollectionId<T>,
 		collection_id_sub: CollectionId<T>,
 	) -> DispatchResult {
-		let mut user = Self::users(nft_holder.clone()).ok_or(Error::<T>::UserNotRegistered)?;
-		let color_add =
-			Self::collection_color(collection_id_add).ok_or(Error::<T>::CollectionUnknown)?;
-		let color_sub =
-			Self::collection_color(collection_id_sub).ok_or(Error::<T>::CollectionUnknown)?;
-		user.add_nft_color(color_add.clone())?;
-		let points = user.calculate_points(color_add);
+		Self::remove_user_color(&nft_holder, collection_id_sub)?;
+		Self::add_user_color(&nft_holder, collection_id_add)
+	}
+
+	/// Moves the color of a sold nft from the seller to the buyer.
+	pub fn transfer_user_points(
+		seller: &AccountIdOf<T>,
+		buyer: &AccountIdOf<T>,
+		collection_id: CollectionId<T>,
+	) -> DispatchResult {
+		Self::remove_user_color(seller, collection_id)?;
+		Self::add_user_color(buyer, collection_id)
+	}
+
+	/// Adds the color of a received nft to the holder and ends the round if the holder has
+	/// collected four nfts of all colors.
+	pub(crate) fn add_user_color(
+		nft_holder: &AccountIdOf<T>,
+		collection_id: CollectionId<T>,
+	) -> DispatchResult {
+		let Some(mut user) = Self::color_holder(nft_holder, collection_id)? else {
+			return Ok(());
+		};
+		let color = Self::collection_color(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+		user.add_nft_color(color.clone())?;
+		let points = user.calculate_points(color);
 		user.points = user.points.checked_add(points).ok_or(Error::<T>::ArithmeticOverflow)?;
-		user.sub_nft_color(color_sub.clone())?;
-		let points = user.subtracting_calculate_points(color_sub);
-		user.points = user.points.checked_sub(points).ok_or(Error::<T>::ArithmeticOverflow)?;
-		Users::<T>::insert(nft_holder.clone(), user.clone());
+		Users::<T>::insert(nft_holder, user.clone());
 		Self::update_leaderboard(nft_holder.clone(), user.points)?;
 		if user.has_four_of_all_colors() {
-			Self::end_game(nft_holder)?;
+			Self::end_game(nft_holder.clone())?;
 		}
 		Ok(())
 	}
 
+	/// Removes the color of a nft the holder gave away.
+	pub(crate) fn remove_user_color(
+		nft_holder: &AccountIdOf<T>,
+		collection_id: CollectionId<T>,
+	) -> DispatchResult {
+		let Some(mut user) = Self::color_holder(nft_holder, collection_id)? else {
+			return Ok(());
+		};
+		let color = Self::collection_color(collection_id).ok_or(Error::<T>::CollectionUnknown)?;
+		user.sub_nft_color(color.clone())?;
+		let points = user.subtracting_calculate_points(color);
+		user.points = user.points.saturating_sub(points);
+		Users::<T>::insert(nft_holder, user.clone());
+		Self::update_leaderboard(nft_holder.clone(), user.points)
+	}
+
+	/// The user data of a nft holder whose points depend on the nft.
+	///
+	/// Returns `None` if the holder is not registered or the nft is from an earlier round, as
+	/// those nfts don't count for the points of the current round.
+	fn color_holder(
+		nft_holder: &AccountIdOf<T>,
+		collection_id: CollectionId<T>,
+	) -> Result<Option<User<T>>, DispatchError> {
+		if Self::users(nft_holder.clone()).is_none() ||
+			!Self::round_collections(Self::current_round()).contains(&collection_id)
+		{
+			return Ok(None);
+		}
+		Self::join_current_round(nft_holder)?;
+		Ok(Self::users(nft_holder.clone()))
+	}
+
+	/// Returns a nft held by the pallet to an account and locks it again.
+	pub(crate) fn release_nft(
+		receiver: AccountIdOf<T>,
+		collection_id: CollectionId<T>,
+		item_id: ItemId<T>,
+	) -> DispatchResult {
+		pallet_nfts::Pallet::<T>::do_transfer(
+			collection_id.into(),
+			item_id.into(),
+			receiver,
+			|_, _| Ok(()),
+		)?;
+		let pallet_origin: OriginFor<T> = RawOrigin::Signed(Self::account_id()).into();
+		pallet_nfts::Pallet::<T>::lock_item_transfer(
+			pallet_origin,
+			collection_id.into(),
+			item_id.into(),
+		)
+	}
+
+	/// Returns the nft and the reserved amount of an offer to its owner.
+	pub(crate) fn release_offer(offer: OfferInfo<CollectionId<T>, ItemId<T>, T>) -> DispatchResult {
+		<T as pallet::Config>::Currency::unreserve(&offer.owner, offer.amount);
+		if let Some((collection_id, item_id)) = offer.nft {
+			Self::release_nft(offer.owner, collection_id, item_id)?;
+		}
+		Ok(())
+	}
+
+	/// Pays the seller from the reserved balance of the buyer, the marketplace fee goes to the
+	/// pallet account.
+	///
+	/// Returns the fee.
+	pub(crate) fn pay_seller(
+		buyer: &AccountIdOf<T>,
+		seller: &AccountIdOf<T>,
+		amount: CurrencyBalanceOf<T>,
+	) -> Result<CurrencyBalanceOf<T>, DispatchError> {
+		let fee = T::MarketplaceFee::get().mul_floor(amount);
+		for (receiver, value) in
+			[(Self::account_id(), fee), (seller.clone(), amount.saturating_sub(fee))]
+		{
+			let missing = <T as pallet::Config>::Currency::repatriate_reserved(
+				buyer,
+				&receiver,
+				value,
+				BalanceStatus::Free,
+			)?;
+			ensure!(missing.is_zero(), Error::<T>::ArithmeticUnderflow);
+		}
+		Ok(fee)
+	}
+
 	/// Handles the case if the player did not answer on time.
 	pub fn no_answer_result(game_info: GameData<T>, game_id: u32) -> DispatchResult {
-		if game_info.difficulty == DifficultyLevel::Pro {
-			let mut user =
-				Self::users(game_info.player.clone()).ok_or(Error::<T>::UserNotRegistered)?;
-			user.points = user.points.checked_sub(50).ok_or(Error::<T>::ArithmeticUnderflow)?;
-			user.losses = user.losses.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-			Users::<T>::insert(game_info.player.clone(), user);
-			Self::deposit_event(Event::<T>::NoAnswer { game_id, points: 50 });
-		} else if game_info.difficulty == DifficultyLevel::Player {
-			let mut user =
-				Self::users(game_info.player.clone()).ok_or(Error::<T>::UserNotRegistered)?;
-			user.points = user.points.checked_sub(25).ok_or(Error::<T>::ArithmeticUnderflow)?;
-			user.losses = user.losses.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-			Users::<T>::insert(game_info.player.clone(), user);
-			Self::deposit_event(Event::<T>::NoAnswer { game_id, points: 25 });
+		// Unanswered practise rounds don't count as a loss.
+		if game_info.difficulty == DifficultyLevel::Practice {
+			Self::finish_game(game_id, game_info, GameState::Voided);
+			return Ok(());
 		}
+		let points = Self::scoring().difficulty(&game_info.difficulty).miss_penalty;
+		let mut user =
+			Self::users(game_info.player.clone()).ok_or(Error::<T>::UserNotRegistered)?;
+		user.points = user.points.checked_sub(points).ok_or(Error::<T>::ArithmeticUnderflow)?;
+		user.losses = user.losses.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
+		let total_points = user.points;
+		let player = game_info.player.clone();
+		Users::<T>::insert(&player, user);
+		Self::finish_game(game_id, game_info, GameState::Scored { points, won: false });
+		Self::deposit_event(Event::<T>::NoAnswer {
+			game_id,
+			player,
+			penalty: points,
+			total_points,
+		});
 		Ok(())
 	}
 
+	/// Handles a game that reached its ending block or its settlement block and returns the
+	/// weight that was used.
+	pub(crate) fn expire_game(game_id: u32, now: BlockNumberFor<T>) -> Weight {
+		let Some(game_info) = GameInfo::<T>::get(game_id) else {
+			return T::DbWeight::get().reads(1);
+		};
+		match game_info.state {
+			GameState::Pending => {
+				let penalised =
+					with_storage_layer(|| Self::no_answer_result(game_info.clone(), game_id));
+				if penalised.is_err() {
+					Self::finish_game(game_id, game_info, GameState::Voided);
+				}
+				<T as pallet::Config>::WeightInfo::expire_game()
+			},
+			GameState::Answered => {
+				// Answered games wait for their price until the settlement block.
+				if now < game_info.settlement_block &&
+					GamesExpiring::<T>::try_append(game_info.settlement_block, game_id).is_ok()
+				{
+					return T::DbWeight::get().reads_writes(2, 1);
+				}
+				Self::settle_game(game_id, game_info)
+			},
+			GameState::Scored { .. } | GameState::Voided => T::DbWeight::get().reads(1),
+		}
+	}
+
+	/// Scores an answered game if its price can be revealed by now, otherwise voids it
+	/// without changing the points of the player.
+	fn settle_game(game_id: u32, game_info: GameData<T>) -> Weight {
+		PendingReveals::<T>::mutate(|pending| pending.retain(|(_, id)| *id != game_id));
+		if T::Timelock::is_revealed(game_info.property.round) {
+			Self::reveal_result(game_id);
+			return <T as pallet::Config>::WeightInfo::settle_game()
+				.saturating_add(<T as pallet::Config>::WeightInfo::reveal_result());
+		}
+		Self::finish_game(game_id, game_info, GameState::Voided);
+		Self::deposit_event(Event::<T>::GameRefunded { game_id });
+		<T as pallet::Config>::WeightInfo::settle_game()
+	}
+
+	/// Stores the final state of a game and queues the game for removal.
+	///
+	/// If the queue is full, the game is removed right away.
+	pub(crate) fn finish_game(game_id: u32, mut game_info: GameData<T>, state: GameState) {
+		if FinishedGames::<T>::try_append(game_id).is_ok() {
+			game_info.state = state;
+			GameInfo::<T>::insert(game_id, game_info);
+		} else {
+			GameInfo::<T>::remove(game_id);
+			ProposedResults::<T>::remove(game_id);
+		}
+	}
+
+	/// Removes finished games as long as the remaining weight of the block allows it.
+	pub(crate) fn cleanup_games(remaining_weight: Weight) -> Weight {
+		let mut weight = T::DbWeight::get().reads_writes(1, 1);
+		if weight.any_gt(remaining_weight) {
+			return Weight::zero();
+		}
+		let finished = FinishedGames::<T>::get();
+		if finished.is_empty() {
+			return T::DbWeight::get().reads(1);
+		}
+		let game_weight = <T as pallet::Config>::WeightInfo::cleanup_game();
+		let mut removed = 0;
+		for game_id in finished.iter() {
+			if weight.saturating_add(game_weight).any_gt(remaining_weight) {
+				break;
+			}
+			GameInfo::<T>::remove(game_id);
+			ProposedResults::<T>::remove(game_id);
+			weight.saturating_accrue(game_weight);
+			removed += 1;
+		}
+		if removed > 0 {
+			let remaining = finished.into_inner().split_off(removed);
+			FinishedGames::<T>::put(BoundedVec::truncate_from(remaining));
+		}
+		weight
+	}
+
+	/// Ends the current round with the player who collected four nfts of all colors.
 	pub fn end_game(winner: AccountIdOf<T>) -> DispatchResult {
+		let user = Self::users(winner.clone()).ok_or(Error::<T>::UserNotRegistered)?;
+		// Late results of an earlier round can't end the current one.
+		if Self::round_active() && user.last_played_round == Self::current_round() {
+			Self::do_close_round(Some(winner));
+		}
+		Ok(())
+	}
+
+	/// Creates a new nft collection owned by the pallet.
+	pub(crate) fn create_collection() -> Result<CollectionId<T>, DispatchError> {
+		if pallet_nfts::NextCollectionId::<T>::get().is_none() {
+			pallet_nfts::NextCollectionId::<T>::set(
+				<T as pallet_nfts::Config>::CollectionId::initial_value(),
+			);
+		};
+		let collection_id = pallet_nfts::NextCollectionId::<T>::get().unwrap();
+		let next_collection_id = collection_id.increment();
+		pallet_nfts::NextCollectionId::<T>::set(next_collection_id);
+		let collection_id: CollectionId<T> = collection_id.into();
+		let pallet_id = Self::account_id();
+		pallet_nfts::Pallet::<T>::do_create_collection(
+			collection_id.into(),
+			pallet_id.clone(),
+			pallet_id.clone(),
+			Self::default_collection_config(),
+			T::CollectionDeposit::get(),
+			pallet_nfts::Event::Created {
+				creator: pallet_id.clone(),
+				owner: pallet_id,
+				collection: collection_id.into(),
+			},
+		)?;
+		Ok(collection_id)
+	}
+
+	/// Starts a new round with new collections for all colors.
+	pub(crate) fn do_start_round(schedule: Option<RoundSchedule<T>>) -> DispatchResult {
+		let round = Self::current_round().checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
+		let mut collections = BoundedVec::new();
+		for x in 0..8 {
+			let collection_id = Self::create_collection()?;
+			let color = NftColor::from_index(x).ok_or(Error::<T>::InvalidIndex)?;
+			CollectionColor::<T>::insert(collection_id, color);
+			collections.try_push(collection_id).map_err(|_| Error::<T>::InvalidIndex)?;
+		}
+		RoundCollections::<T>::insert(round, collections);
+		DrawnProperties::<T>::kill();
+		let end = schedule.as_ref().map(|schedule| schedule.end);
+		if let Some(schedule) = schedule {
+			RoundSchedules::<T>::insert(round, schedule);
+		}
+		CurrentRound::<T>::put(round);
+		RoundActive::<T>::put(true);
+		Self::deposit_event(Event::<T>::RoundStarted { round, end });
+		Ok(())
+	}
+
+	/// Ends the current round, archives its leaderboard and pays the prizes.
+	///
+	/// Without a `champion` the leader of the leaderboard becomes the champion of the round.
+	pub(crate) fn do_close_round(champion: Option<AccountIdOf<T>>) {
+		let round = Self::current_round();
 		RoundActive::<T>::put(false);
-		RoundChampion::<T>::insert(Self::current_round(), winner);
+		let leaderboard = Leaderboard::<T>::take();
+		let champion = champion.or_else(|| leaderboard.first().map(|(player, _)| player.clone()));
+		if let Some(champion) = &champion {
+			RoundChampion::<T>::insert(round, champion);
+		}
+		let prizes = Self::round_schedules(round).map(|schedule| schedule.prizes);
+		for ((winner, _), amount) in leaderboard.iter().zip(prizes.unwrap_or_default()) {
+			let paid = <T as pallet::Config>::Currency::transfer(
+				&Self::account_id(),
+				winner,
+				amount,
+				ExistenceRequirement::KeepAlive,
+			);
+			let winner = winner.clone();
+			if paid.is_ok() {
+				Self::deposit_event(Event::<T>::PrizePaid { round, winner, amount });
+			} else {
+				Self::deposit_event(Event::<T>::PrizeNotPaid { round, winner, amount });
+			}
+		}
+		RoundArchive::<T>::insert(round, leaderboard);
+		Self::deposit_event(Event::<T>::RoundEnded { round, champion });
+	}
+
+	/// Lets a user take part in the current round.
+	///
+	/// If the user last played in an earlier round, the points of that round are archived and
+	/// the points and nfts of the user are reset.
+	pub(crate) fn join_current_round(player: &AccountIdOf<T>) -> DispatchResult {
+		let mut user = Self::users(player.clone()).ok_or(Error::<T>::UserNotRegistered)?;
+		let current_round = Self::current_round();
+		if current_round != user.last_played_round {
+			RoundPoints::<T>::insert(user.last_played_round, player, user.points);
+			user.points = T::InitialPoints::get();
+			user.nfts = Default::default();
+			user.last_played_round = current_round;
+			Users::<T>::insert(player, user);
+		}
 		Ok(())
 	}
 
@@ -1,5 +1,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
 /// Edit this file to define custom logic or remove it if it is not needed.
 /// Learn more about FRAME and the core library of Substrate FRAME pallets:
 /// <https://docs.substrate.io/reference/frame-pallets/>
@@ -15,23 +17,30 @@ mod tests;
 mod benchmarking;
 pub mod weights;
 pub use weights::*;
+pub mod crafting;
 pub mod functions;
+pub mod lobby;
+pub mod oracle;
 pub mod properties;
+pub mod roles;
+pub mod timelock;
 pub mod types;
 
 type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 type BalanceOf<T> = <<T as pallet_nfts::Config>::Currency as Currency<
 	<T as frame_system::Config>::AccountId,
 >>::Balance;
+type CurrencyBalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
 use frame_support::{
-	traits::{Currency, Incrementable, ReservableCurrency},
+	traits::{BalanceStatus, Currency, ExistenceRequirement, Incrementable, ReservableCurrency},
 	PalletId,
 };
 
 use frame_support::sp_runtime::{
-	traits::{AccountIdConversion, StaticLookup},
-	Saturating,
+	traits::{AccountIdConversion, Hash, StaticLookup, Zero},
+	Percent, Saturating,
 };
 
 use pallet_nfts::{
@@ -44,20 +53,31 @@ use enumflags2::BitFlags;
 
 use frame_support::traits::Randomness;
 
+use pallet_matchmaker::MatchFunc;
+
+pub use oracle::*;
+pub use roles::*;
+pub use timelock::*;
 pub use types::*;
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use frame_support::pallet_prelude::*;
-	use frame_system::pallet_prelude::*;
+	use frame_support::{pallet_prelude::*, storage::with_storage_layer};
+	use frame_system::{
+		offchain::{AppCrypto, CreateSignedTransaction, SignedPayload},
+		pallet_prelude::*,
+	};
+	use sp_runtime::traits::IdentifyAccount;
+	use sp_std::vec::Vec;
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
 	/// Configure the pallet by specifying the parameters and types on which it depends.
 	#[pallet::config]
-	pub trait Config: frame_system::Config + pallet_nfts::Config //+ pallet_babe::Config
+	pub trait Config:
+		frame_system::Config + pallet_nfts::Config + CreateSignedTransaction<Call<Self>>
 	{
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
@@ -65,8 +85,16 @@ pub mod pallet {
 		type Currency: Currency<AccountIdOf<Self>> + ReservableCurrency<AccountIdOf<Self>>;
 		/// Type representing the weight of this pallet
 		type WeightInfo: WeightInfo;
-		/// Origin who can create a new game.
+		/// Origin who can grant and revoke the roles of the admins.
 		type GameOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Origin who can register players.
+		type RegistrarOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Origin who can add and remove properties.
+		type PropertyCuratorOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Origin who can award points for results.
+		type ResultsOracleOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Origin who can start, schedule and end rounds and update the scoring.
+		type SeasonManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 		/// Collection id type from pallet nfts.
 		type CollectionId: IsType<<Self as pallet_nfts::Config>::CollectionId>
 			+ Parameter
@@ -100,10 +128,68 @@ pub mod pallet {
 		/// The maximum length of leaderboard.
 		#[pallet::constant]
 		type LeaderboardLimit: Get<u32>;
+		/// The maximum amount of admins per role.
 		#[pallet::constant]
 		type MaxAdmins: Get<u32>;
 		/// The amount of time until player can request more token.
+		#[pallet::constant]
 		type RequestLimit: Get<BlockNumberFor<Self>>;
+		/// The free balance the faucet tops a player up to.
+		#[pallet::constant]
+		type FaucetAmount: Get<CurrencyBalanceOf<Self>>;
+		/// The deposit reserved from players who register themselves. Players can only be
+		/// registered by the registrar if this is `None`.
+		#[pallet::constant]
+		type RegistrationDeposit: Get<Option<CurrencyBalanceOf<Self>>>;
+		/// Decrypts the timelocked property prices with the drand beacon.
+		type Timelock: TimelockDecrypter;
+		/// The minimum amount of drand rounds between starting a game and revealing its price.
+		#[pallet::constant]
+		type RevealDelay: Get<RoundNumber>;
+		/// The points a user starts a round with.
+		#[pallet::constant]
+		type InitialPoints: Get<u32>;
+		/// The maximum amount of leaderboard ranks that receive a prize at the end of a round.
+		#[pallet::constant]
+		type MaxPrizes: Get<u32>;
+		/// The share of a nft sale that goes to the pallet account.
+		#[pallet::constant]
+		type MarketplaceFee: Get<Percent>;
+		/// The amount of blocks a listing stays open.
+		#[pallet::constant]
+		type ListingDuration: Get<BlockNumberFor<Self>>;
+		/// The amount of blocks after the end of a game until an answered game is settled, even
+		/// if the price hasn't been revealed yet.
+		#[pallet::constant]
+		type SettlementPeriod: Get<BlockNumberFor<Self>>;
+		/// `PropertyPoolLow` is emitted once no more than this amount of properties can still
+		/// be drawn in the current round.
+		#[pallet::constant]
+		type PropertyPoolThreshold: Get<u32>;
+		/// Matches players who queue for a lobby.
+		type Matchmaker: MatchFunc<AccountIdOf<Self>>;
+		/// The maximum amount of players in a lobby.
+		#[pallet::constant]
+		type MaxLobbyPlayers: Get<u32>;
+		/// The keys the offchain worker signs the results of the oracle with.
+		type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+		/// The source of the true property prices for the oracle.
+		type PriceSource: PriceSource;
+		/// The amount of blocks a result of the oracle can be disputed before it is final.
+		#[pallet::constant]
+		type DisputeWindow: Get<BlockNumberFor<Self>>;
+		/// The priority of the unsigned transactions of the oracle.
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+		/// The amount of nfts of one color that are burned to craft a nft of another color.
+		#[pallet::constant]
+		type CraftingCost: Get<u32>;
+		/// The points a player receives for crafting a trophy from a nft of every color.
+		#[pallet::constant]
+		type TrophyPoints: Get<u32>;
+		/// Helper to lock and reveal prices in the benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper;
 	}
 
 	pub type CollectionId<T> = <T as Config>::CollectionId;
@@ -125,11 +211,60 @@ pub mod pallet {
 	pub(super) type RoundChampion<T: Config> =
 		StorageMap<_, Blake2_128Concat, u32, AccountIdOf<T>, OptionQuery>;
 
+	/// The round that is scheduled to start next.
+	#[pallet::storage]
+	#[pallet::getter(fn next_round)]
+	pub type NextRound<T: Config> = StorageValue<_, RoundSchedule<T>, OptionQuery>;
+
+	/// Mapping of a round to its schedule, if it has been started by the scheduler.
+	#[pallet::storage]
+	#[pallet::getter(fn round_schedules)]
+	pub type RoundSchedules<T: Config> =
+		StorageMap<_, Blake2_128Concat, u32, RoundSchedule<T>, OptionQuery>;
+
+	/// Mapping of a round to its collections, ordered by color.
+	#[pallet::storage]
+	#[pallet::getter(fn round_collections)]
+	pub type RoundCollections<T: Config> =
+		StorageMap<_, Blake2_128Concat, u32, BoundedVec<CollectionId<T>, ConstU32<8>>, ValueQuery>;
+
+	/// Mapping of a round to its final leaderboard.
+	#[pallet::storage]
+	#[pallet::getter(fn round_archive)]
+	pub type RoundArchive<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		u32,
+		BoundedVec<(AccountIdOf<T>, u32), <T as Config>::LeaderboardLimit>,
+		ValueQuery,
+	>;
+
+	/// The points a user had at the end of a round.
+	///
+	/// Stored once the user starts playing in a later round.
+	#[pallet::storage]
+	#[pallet::getter(fn round_points)]
+	pub type RoundPoints<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		u32,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		u32,
+		OptionQuery,
+	>;
+
 	/// The next item id in a collection.
 	#[pallet::storage]
 	pub(super) type NextColorId<T: Config> =
 		StorageMap<_, Blake2_128Concat, <T as pallet::Config>::CollectionId, u32, ValueQuery>;
 
+	/// The collection of the trophies crafted from a nft of every color.
+	#[pallet::storage]
+	#[pallet::getter(fn trophy_collection)]
+	pub type TrophyCollection<T: Config> =
+		StorageValue<_, <T as pallet::Config>::CollectionId, OptionQuery>;
+
 	/// Mapping of a collection to the correlated color.
 	#[pallet::storage]
 	#[pallet::getter(fn collection_color)]
@@ -201,54 +336,247 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
-	/// A List of test properties
+	/// Games with a submitted answer, waiting for the drand round of their property.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_reveals)]
+	pub type PendingReveals<T: Config> =
+		StorageValue<_, BoundedVec<(RoundNumber, u32), T::MaxOngoingGames>, ValueQuery>;
+
+	/// Mapping of an answered game to the price proposed by the oracle.
+	#[pallet::storage]
+	#[pallet::getter(fn proposed_results)]
+	pub type ProposedResults<T: Config> =
+		StorageMap<_, Blake2_128Concat, u32, ProposedResult<T>, OptionQuery>;
+
+	/// Stores the games whose proposed result becomes final on a given block.
+	#[pallet::storage]
+	pub type ResultsFinalising<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		BlockNumberFor<T>,
+		BoundedVec<u32, T::MaxOngoingGames>,
+		ValueQuery,
+	>;
+
+	/// Finished games whose data is removed once there is weight left in a block.
+	#[pallet::storage]
+	#[pallet::getter(fn finished_games)]
+	pub type FinishedGames<T: Config> =
+		StorageValue<_, BoundedVec<u32, T::MaxOngoingGames>, ValueQuery>;
+
+	/// The catalogue of properties that can be played.
 	#[pallet::storage]
 	#[pallet::getter(fn game_properties)]
 	pub type GameProperties<T: Config> =
 		StorageValue<_, BoundedVec<PropertyInfoData<T>, T::MaxProperty>, ValueQuery>;
 
-	/// Vector of admins who can register users.
+	/// Ids of the properties that have already been drawn in the current round.
+	#[pallet::storage]
+	#[pallet::getter(fn drawn_properties)]
+	pub type DrawnProperties<T: Config> =
+		StorageValue<_, BoundedVec<u32, T::MaxProperty>, ValueQuery>;
+
+	/// Id of the next lobby.
+	#[pallet::storage]
+	#[pallet::getter(fn next_lobby_id)]
+	pub(super) type NextLobbyId<T> = StorageValue<_, u32, ValueQuery>;
+
+	/// Mapping of lobby id to the lobby data.
+	#[pallet::storage]
+	#[pallet::getter(fn lobbies)]
+	pub type Lobbies<T: Config> = StorageMap<_, Blake2_128Concat, u32, LobbyData<T>, OptionQuery>;
+
+	/// The lobby a player takes part in.
+	#[pallet::storage]
+	#[pallet::getter(fn player_lobby)]
+	pub type PlayerLobby<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, u32, OptionQuery>;
+
+	/// The sealed guesses of the players of a lobby.
+	#[pallet::storage]
+	#[pallet::getter(fn lobby_guesses)]
+	pub type LobbyGuesses<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		u32,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		SealedGuess<T>,
+		OptionQuery,
+	>;
+
+	/// The entry points of players waiting in the matchmaking queue.
+	#[pallet::storage]
+	#[pallet::getter(fn matchmaking_entries)]
+	pub type MatchmakingEntries<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, u32, OptionQuery>;
+
+	/// Lobbies that are settled once their drand round has been revealed.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_lobbies)]
+	pub type PendingLobbies<T: Config> =
+		StorageValue<_, BoundedVec<(RoundNumber, u32), T::MaxOngoingGames>, ValueQuery>;
+
+	/// The scoring of the difficulty levels.
+	#[pallet::storage]
+	#[pallet::getter(fn scoring)]
+	pub type Scoring<T: Config> = StorageValue<_, ScoringTable, ValueQuery>;
+
+	/// Mapping of a role to the admins holding it.
 	#[pallet::storage]
 	#[pallet::getter(fn admins)]
 	pub type Admins<T: Config> =
-		StorageValue<_, BoundedVec<AccountIdOf<T>, T::MaxAdmins>, ValueQuery>;
+		StorageMap<_, Blake2_128Concat, Role, BoundedVec<AccountIdOf<T>, T::MaxAdmins>, ValueQuery>;
+
+	/// Mapping of a player who registered themselves to the reserved deposit.
+	#[pallet::storage]
+	#[pallet::getter(fn registration_deposits)]
+	pub type RegistrationDeposits<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, CurrencyBalanceOf<T>, OptionQuery>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// The initial catalogue of properties.
+		pub properties: Vec<VersionedProperty<T>>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			Pallet::<T>::do_import_properties(self.properties.iter().cloned().map(Into::into))
+				.expect("genesis properties have unique ids and fit into MaxProperty");
+		}
+	}
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// A user has received points.
-		PointsReceived { receiver: AccountIdOf<T>, amount: u32 },
+		PointsReceived { receiver: AccountIdOf<T>, amount: u32, total_points: u32 },
 		/// A game has started.
 		GameStarted { player: AccountIdOf<T>, game_id: u32, ending_block: BlockNumberFor<T> },
 		/// An answer has been submitted.
 		AnswerSubmitted { player: AccountIdOf<T>, game_id: u32, guess: u32 },
-		/// The result has been checked.
-		ResultChecked { game_id: u32, secret: BoundedVec<u8, <T as Config>::StringLimit>, points: u32, won: bool, nft_received: bool },
+		/// The result of a game has been checked.
+		///
+		/// `band` is the maximum difference in per mille of the band the guess fell into, `None`
+		/// if the guess missed all bands. `nft` is the nft the player received.
+		ResultChecked {
+			game_id: u32,
+			player: AccountIdOf<T>,
+			property_id: u32,
+			difficulty: DifficultyLevel,
+			price: u32,
+			band: Option<u16>,
+			points: PointsDelta,
+			total_points: u32,
+			nft: Option<(CollectionId<T>, ItemId<T>)>,
+		},
+		/// The price of a game could not be revealed, the game has been voided.
+		RevealFailed { game_id: u32 },
 		/// No Answer has been submitted.
-		NoAnswer { game_id: u32, points: u32 },
+		NoAnswer { game_id: u32, player: AccountIdOf<T>, penalty: u32, total_points: u32 },
+		/// The price of an answered game wasn't revealed in time, the game has been voided.
+		GameRefunded { game_id: u32 },
+		/// The oracle proposed the price of an answered game.
+		ResultProposed { game_id: u32, price: u32, final_block: BlockNumberFor<T> },
+		/// A proposed result has been disputed, the game waits for its price to be revealed.
+		ResultDisputed { game_id: u32 },
+		/// Nfts of one color have been burned to craft a nft of another color.
+		NftCrafted {
+			player: AccountIdOf<T>,
+			burned_collection: CollectionId<T>,
+			collection_id: CollectionId<T>,
+			item_id: ItemId<T>,
+		},
+		/// A nft of every color has been burned to craft a trophy.
+		TrophyCrafted {
+			player: AccountIdOf<T>,
+			collection_id: CollectionId<T>,
+			item_id: ItemId<T>,
+			points: u32,
+		},
+		/// Only few properties are left to be drawn in this round. More can be added with
+		/// `add_property` or `import_properties`.
+		PropertyPoolLow { remaining: u32 },
 		/// A nft has been listed.
-		NftListed { owner: AccountIdOf<T>, collection_id: CollectionId<T>, item_id: ItemId<T> },
+		NftListed {
+			owner: AccountIdOf<T>,
+			listing_id: u32,
+			collection_id: CollectionId<T>,
+			item_id: ItemId<T>,
+			price: ListingPrice<CurrencyBalanceOf<T>>,
+			expiry: BlockNumberFor<T>,
+		},
 		/// A nft has been delisted.
 		NftDelisted { owner: AccountIdOf<T>, collection_id: CollectionId<T>, item_id: ItemId<T> },
 		/// An offer has been made.
 		OfferMade {
 			owner: AccountIdOf<T>,
 			listing_id: u32,
-			collection_id: CollectionId<T>,
-			item_id: ItemId<T>,
+			offer_id: u32,
+			nft: Option<(CollectionId<T>, ItemId<T>)>,
+			amount: CurrencyBalanceOf<T>,
 		},
 		/// An offer has been withdrawn.
 		OfferWithdrawn { owner: AccountIdOf<T>, offer_id: u32 },
-		/// An offer has been handled.
-		OfferHandeld { offer_id: u32, offer: Offer },
+		/// An offer has been accepted or rejected.
+		OfferHandled { offer_id: u32, listing_id: u32, offer: Offer },
+		/// A lobby has been opened.
+		LobbyCreated { lobby_id: u32, host: AccountIdOf<T>, capacity: u32, entry_points: u32 },
+		/// A player has joined a lobby.
+		LobbyJoined { lobby_id: u32, player: AccountIdOf<T> },
+		/// A player has left an open lobby.
+		LobbyLeft { lobby_id: u32, player: AccountIdOf<T> },
+		/// The host has closed an open lobby.
+		LobbyCancelled { lobby_id: u32 },
+		/// A player is waiting for the matchmaker.
+		QueuedForLobby { player: AccountIdOf<T>, entry_points: u32 },
+		/// A player has stopped waiting for the matchmaker.
+		LeftLobbyQueue { player: AccountIdOf<T>, entry_points: u32 },
+		/// The players of a lobby can submit their guesses.
+		LobbyStarted { lobby_id: u32, players: u32, ending_block: BlockNumberFor<T> },
+		/// A player has submitted a sealed guess.
+		SealedGuessSubmitted { lobby_id: u32, player: AccountIdOf<T> },
+		/// The closest guess has won the pot of a lobby.
+		LobbySettled { lobby_id: u32, winner: AccountIdOf<T>, price: u32, pot: u32 },
+		/// A lobby could not be settled, the players got their entry points back.
+		LobbyVoided { lobby_id: u32 },
+		/// A listed nft has been sold.
+		NftSold {
+			listing_id: u32,
+			seller: AccountIdOf<T>,
+			buyer: AccountIdOf<T>,
+			price: CurrencyBalanceOf<T>,
+			fee: CurrencyBalanceOf<T>,
+		},
 		/// A new player has been registered.
 		NewPlayerRegistered { player: AccountIdOf<T> },
 		/// A new admins has been added.
-		NewAdminAdded { new_admin: AccountIdOf<T> },
+		NewAdminAdded { role: Role, new_admin: AccountIdOf<T> },
 		/// An admin has been removed.
-		AdminRemoved { admin: AccountIdOf<T> },
+		AdminRemoved { role: Role, admin: AccountIdOf<T> },
 		/// The user received token.
 		TokenReceived { player: AccountIdOf<T> },
+		/// Properties have been imported into the catalogue.
+		PropertiesImported { count: u32 },
+		/// The scoring of the difficulty levels has been updated.
+		ScoringUpdated,
+		/// A round has been scheduled.
+		RoundScheduled { start: BlockNumberFor<T>, end: BlockNumberFor<T> },
+		/// The scheduled round has been cancelled.
+		ScheduledRoundCancelled,
+		/// A round has started.
+		RoundStarted { round: u32, end: Option<BlockNumberFor<T>> },
+		/// The scheduled round could not be started.
+		RoundStartFailed,
+		/// A round has ended.
+		RoundEnded { round: u32, champion: Option<AccountIdOf<T>> },
+		/// A prize has been paid to a player of the leaderboard.
+		PrizePaid { round: u32, winner: AccountIdOf<T>, amount: CurrencyBalanceOf<T> },
+		/// A prize could not be paid from the pallet account.
+		PrizeNotPaid { round: u32, winner: AccountIdOf<T>, amount: CurrencyBalanceOf<T> },
 	}
 
 	// Errors inform users that something went wrong.
@@ -297,8 +625,60 @@ pub mod pallet {
 		TooManyAdmins,
 		/// The user has to wait to request token.
 		CantRequestToken,
+		/// Players can't register themselves.
+		SelfRegistrationDisabled,
+		/// The oracle already proposed a result for this game.
+		ResultAlreadyProposed,
+		/// There is no proposed result for this game.
+		NoProposedResult,
+		/// The result would only be final after the settlement deadline of the game.
+		ResultTooLate,
+		/// The nfts don't match the recipe.
+		InvalidRecipe,
 		/// There has been no guess from the player.
 		NoGuess,
+		/// The price of the property has already been revealed.
+		PriceRevealed,
+		/// A property with this id is already in the catalogue.
+		PropertyIdTaken,
+		/// The bands of a difficulty level are empty or not ordered.
+		InvalidScoring,
+		/// There is already a round scheduled.
+		RoundAlreadyScheduled,
+		/// There is no round scheduled.
+		NoRoundScheduled,
+		/// The round has to start in the future and end after its start.
+		InvalidSchedule,
+		/// The listing has expired.
+		ListingExpired,
+		/// The listing is not sold for a fixed price.
+		NotForSale,
+		/// The listing does not take offers.
+		NoOffersAccepted,
+		/// The offered amount is below the minimum bid of the listing.
+		BidTooLow,
+		/// An offer needs an amount or a nft.
+		EmptyOffer,
+		/// This lobby does not exist.
+		LobbyDoesNotExist,
+		/// A lobby needs between two and the maximum amount of players.
+		InvalidLobbySize,
+		/// Lobbies can't be played in practise mode.
+		InvalidLobbyDifficulty,
+		/// The invite code does not match the lobby.
+		WrongInviteCode,
+		/// The player already takes part in a lobby or waits for one.
+		AlreadyInLobby,
+		/// The player does not take part in this lobby.
+		NotInLobby,
+		/// The player is not waiting for the matchmaker.
+		NotQueuedForLobby,
+		/// The lobby has already started.
+		LobbyNotOpen,
+		/// The lobby has not started yet.
+		LobbyNotStarted,
+		/// The time to submit a guess is over.
+		GuessingClosed,
 	}
 
 	#[pallet::hooks]
@@ -307,69 +687,136 @@ pub mod pallet {
 			let mut weight = T::DbWeight::get().reads_writes(1, 1);
 			let ended_games = GamesExpiring::<T>::take(n);
 
-			// Checks if there is a voting for a loan expiring in this block.
-			ended_games.iter().for_each(|index| {
-				weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
-				let game_info = <GameInfo<T>>::take(index);
-				if let Some(game_info) = game_info {
-					if game_info.guess.is_none() {
-						let _ = Self::no_answer_result(game_info, *index);
-					} else {
-						GameInfo::<T>::insert(index, game_info);
-					}
-				}
+			// Penalises unanswered games and settles answered games past their deadline.
+			ended_games.iter().for_each(|game_id| {
+				weight = weight.saturating_add(Self::expire_game(*game_id, n));
+			});
+
+			// Scores the games whose proposed result hasn't been disputed.
+			let finalising = ResultsFinalising::<T>::take(n);
+			weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+			finalising.iter().for_each(|game_id| {
+				weight = weight.saturating_add(Self::finalise_result(*game_id));
 			});
+
+			// Scores the answered games whose price can be decrypted by now.
+			weight = weight.saturating_add(T::DbWeight::get().reads(1));
+			let latest_round = T::Timelock::latest_round();
+			// Games whose signature isn't on chain yet stay pending until their deadline.
+			let (revealed, pending): (Vec<_>, Vec<_>) = PendingReveals::<T>::get()
+				.into_iter()
+				.partition(|(round, _)| *round <= latest_round && T::Timelock::is_revealed(*round));
+			if !revealed.is_empty() {
+				weight = weight.saturating_add(T::DbWeight::get().writes(1));
+				PendingReveals::<T>::put(BoundedVec::truncate_from(pending));
+				revealed.iter().for_each(|(_, game_id)| {
+					weight =
+						weight.saturating_add(<T as pallet::Config>::WeightInfo::reveal_result());
+					Self::reveal_result(*game_id);
+				});
+			}
+
+			// Settles the lobbies whose price can be decrypted by now.
+			weight = weight.saturating_add(T::DbWeight::get().reads(1));
+			// Lobbies whose round is still missing once the beacon is `RevealDelay` rounds past it
+			// are voided.
+			let (revealed, pending): (Vec<_>, Vec<_>) =
+				PendingLobbies::<T>::get().into_iter().partition(|(round, _)| {
+					*round <= latest_round &&
+						(T::Timelock::is_revealed(*round) ||
+							latest_round > round.saturating_add(T::RevealDelay::get()))
+				});
+			if !revealed.is_empty() {
+				weight = weight.saturating_add(T::DbWeight::get().writes(1));
+				PendingLobbies::<T>::put(BoundedVec::truncate_from(pending));
+				revealed.iter().for_each(|(_, lobby_id)| {
+					weight = weight.saturating_add(
+						<T as pallet::Config>::WeightInfo::settle_lobby(T::MaxLobbyPlayers::get()),
+					);
+					Self::settle_lobby(*lobby_id);
+				});
+			}
+
+			// Closes the current round at its end and starts the scheduled one.
+			weight = weight.saturating_add(T::DbWeight::get().reads(4));
+			let round_ended = RoundSchedules::<T>::get(CurrentRound::<T>::get())
+				.is_some_and(|schedule| schedule.end <= n);
+			if RoundActive::<T>::get() && round_ended {
+				weight = weight.saturating_add(<T as pallet::Config>::WeightInfo::end_round());
+				Self::do_close_round(None);
+			}
+			if let Some(schedule) = NextRound::<T>::get().filter(|schedule| schedule.start <= n) {
+				weight = weight
+					.saturating_add(<T as pallet::Config>::WeightInfo::end_round())
+					.saturating_add(<T as pallet::Config>::WeightInfo::setup_game());
+				NextRound::<T>::kill();
+				if RoundActive::<T>::get() {
+					Self::do_close_round(None);
+				}
+				if with_storage_layer(|| Self::do_start_round(Some(schedule))).is_err() {
+					Self::deposit_event(Event::<T>::RoundStartFailed);
+				}
+			}
 			weight
 		}
+
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::cleanup_games(remaining_weight)
+		}
+
+		fn offchain_worker(n: BlockNumberFor<T>) {
+			Self::run_oracle(n);
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			let Call::submit_result_unsigned { payload, signature } = call else {
+				return InvalidTransaction::Call.into();
+			};
+			if !SignedPayload::<T>::verify::<T::AuthorityId>(payload, signature.clone()) {
+				return InvalidTransaction::BadProof.into();
+			}
+			let oracle = payload.public.clone().into_account();
+			if !Admins::<T>::get(Role::ResultsOracle).contains(&oracle) {
+				return InvalidTransaction::BadSigner.into();
+			}
+			if Self::ensure_can_propose(payload.game_id).is_err() {
+				return InvalidTransaction::Stale.into();
+			}
+			ValidTransaction::with_tag_prefix("GameOracle")
+				.priority(T::UnsignedPriority::get())
+				.and_provides(payload.game_id)
+				.longevity(5)
+				.propagate(true)
+				.build()
+		}
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Creates the setup for a new game.
 		///
-		/// The origin must be the sudo.
+		/// Ends the active round and starts a new one without an end block.
+		///
+		/// The origin must be the season manager.
 		#[pallet::call_index(0)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::setup_game())]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::setup_game()
+			.saturating_add(<T as pallet::Config>::WeightInfo::end_round()))]
 		pub fn setup_game(origin: OriginFor<T>) -> DispatchResult {
-			T::GameOrigin::ensure_origin(origin)?;
-			for x in 0..8 {
-				if pallet_nfts::NextCollectionId::<T>::get().is_none() {
-					pallet_nfts::NextCollectionId::<T>::set(
-						<T as pallet_nfts::Config>::CollectionId::initial_value(),
-					);
-				};
-				let collection_id = pallet_nfts::NextCollectionId::<T>::get().unwrap();
-				let next_collection_id = collection_id.increment();
-				pallet_nfts::NextCollectionId::<T>::set(next_collection_id);
-				let collection_id: CollectionId<T> = collection_id.into();
-				let pallet_id: AccountIdOf<T> =
-					<T as pallet::Config>::PalletId::get().into_account_truncating();
-				pallet_nfts::Pallet::<T>::do_create_collection(
-					collection_id.into(),
-					pallet_id.clone(),
-					pallet_id.clone(),
-					Self::default_collection_config(),
-					T::CollectionDeposit::get(),
-					pallet_nfts::Event::Created {
-						creator: pallet_id.clone(),
-						owner: pallet_id,
-						collection: collection_id.into(),
-					},
-				)?;
-				let color = NftColor::from_index(x).ok_or(Error::<T>::InvalidIndex)?;
-				CollectionColor::<T>::insert(collection_id, color);
+			T::SeasonManagerOrigin::ensure_origin(origin)?;
+			if RoundActive::<T>::get() {
+				Self::do_close_round(None);
 			}
-			Self::create_game_properties()?;
-			let mut round = CurrentRound::<T>::get();
-			round = round.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-			CurrentRound::<T>::put(round);
-			RoundActive::<T>::put(true);
-			Ok(())
+			Self::do_start_round(None)
 		}
 
 		/// Registers a player and gives him initialy 50 points.
 		///
-		/// The origin must be the sudo.
+		/// The origin must be the registrar.
 		///
 		/// Parameters:
 		/// - `player`: The AccountId of the user who gets registered.
@@ -378,39 +825,21 @@ pub mod pallet {
 		#[pallet::call_index(1)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::register_user())]
 		pub fn register_user(origin: OriginFor<T>, player: AccountIdOf<T>) -> DispatchResult {
-			let signer = ensure_signed(origin)?;
-			ensure!(Admins::<T>::get().contains(&signer), Error::<T>::NoPermission);
+			T::RegistrarOrigin::ensure_origin(origin)?;
 			ensure!(Users::<T>::get(player.clone()).is_none(), Error::<T>::PlayerAlreadyRegistered);
-			let current_block_number = <frame_system::Pallet<T>>::block_number();
-			let next_request =
-				current_block_number.saturating_add(<T as Config>::RequestLimit::get());
-			let user = User {
-				points: 50,
-				wins: Default::default(),
-				losses: Default::default(),
-				practise_rounds: Default::default(),
-				last_played_round: Default::default(),
-				next_token_request: next_request,
-				nfts: CollectedColors::default(),
-			};
-			<T as pallet::Config>::Currency::make_free_balance_be(
-				&player,
-				10000000000000u64.try_into().map_err(|_| Error::<T>::ConversionError)?,
-			);
-			Users::<T>::insert(player.clone(), user);
-			frame_system::Pallet::<T>::inc_providers(&player);
-			Self::deposit_event(Event::<T>::NewPlayerRegistered { player });
+			Self::fund_player(&player);
+			Self::do_register_user(player);
 			Ok(())
 		}
 
 		/// Gives points to a user.
 		///
-		/// The origin must be the sudo.
+		/// The origin must be the results oracle.
 		///
 		/// Parameters:
 		/// - `receiver`: The AccountId of the user who gets points.
 		///
-		/// Emits `LocationCreated` event when succesfful.
+		/// Emits `PointsReceived` event when succesfful.
 		#[pallet::call_index(2)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::give_points())]
 		pub fn give_points(
@@ -418,12 +847,13 @@ pub mod pallet {
 			receiver: AccountIdOf<T>,
 			amount: u32,
 		) -> DispatchResult {
-			T::GameOrigin::ensure_origin(origin)?;
+			T::ResultsOracleOrigin::ensure_origin(origin)?;
 			let mut user =
 				Users::<T>::get(receiver.clone()).ok_or(Error::<T>::UserNotRegistered)?;
 			user.points = user.points.checked_add(amount).ok_or(Error::<T>::ArithmeticOverflow)?;
+			let total_points = user.points;
 			Users::<T>::insert(receiver.clone(), user);
-			Self::deposit_event(Event::<T>::PointsReceived { receiver, amount: 100 });
+			Self::deposit_event(Event::<T>::PointsReceived { receiver, amount, total_points });
 			Ok(())
 		}
 
@@ -439,76 +869,46 @@ pub mod pallet {
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::play_game())]
 		#[pallet::feeless_if(|origin: &OriginFor<T>, game_type: &DifficultyLevel| -> bool {
 			if let Ok(signer) = ensure_signed(origin.clone()) {
-				match game_type {
-					DifficultyLevel::Pro => {
-						if let Some(user) = Users::<T>::get(&signer) {
-							if user.points >= 50 {
-								return true;
-							}
-						}
-					},
-					DifficultyLevel::Player => {
-						if let Some(user) = Users::<T>::get(&signer) {
-							if user.points >= 25 {
-								return true;
-							}
-						}
-					},
-					DifficultyLevel::Practice => {
-						return true;
-					}
-				}
+				return Self::check_enough_points(signer, game_type.clone()).is_ok();
 			}
 			false
 		})]
 		pub fn play_game(origin: OriginFor<T>, game_type: DifficultyLevel) -> DispatchResult {
 			let signer = ensure_signed(origin)?;
-			Self::check_enough_points(signer.clone(), game_type.clone())?;
 			ensure!(RoundActive::<T>::get(), Error::<T>::NoActiveRound);
-			let mut user = Users::<T>::get(signer.clone()).ok_or(Error::<T>::UserNotRegistered)?;
-			let current_round = CurrentRound::<T>::get();
-			if current_round != user.last_played_round {
-				user.nfts = Default::default();
-				user.last_played_round = current_round;
-				Users::<T>::insert(signer.clone(), user);
-			}
+			Self::join_current_round(&signer)?;
+			Self::check_enough_points(signer.clone(), game_type.clone())?;
 			let game_id = GameId::<T>::get();
 			let current_block_number = <frame_system::Pallet<T>>::block_number();
     
 			// Determine expiry block based on game type
-			let expiry_block = if game_type == DifficultyLevel::Player {
-				current_block_number.saturating_add(8u32.into())
-			} else if game_type == DifficultyLevel::Pro {
-				current_block_number.saturating_add(5u32.into())
-			} else {
-				current_block_number.saturating_add(10u32.into())
-			};
+			let expiry_blocks = Self::scoring().difficulty(&game_type).expiry_blocks;
+			let expiry_block = current_block_number.saturating_add(expiry_blocks.into());
 		
 			GamesExpiring::<T>::try_mutate(expiry_block, |keys| {
 				keys.try_push(game_id).map_err(|_| Error::<T>::TooManyGames)?;
 				Ok::<(), DispatchError>(())
 			})?;
 			
-			let (hashi, _) = T::GameRandomness::random(&[(game_id % 256) as u8]);
-			let u32_value = u32::from_le_bytes(
-				hashi.as_ref()[4..8].try_into().map_err(|_| Error::<T>::ConversionError)?,
-			);
-			let mut game_properties = GameProperties::<T>::take();
-			let random_number = u32_value as usize % game_properties.len();
-			let property = game_properties[random_number].clone();
-			let game_datas =
-				GameData { difficulty: game_type, player: signer.clone(), property: property, guess: None };
-			game_properties.retain(|property| property.id as usize != random_number);
-			GameProperties::<T>::put(game_properties);
+			let property = Self::draw_property(&[(game_id % 256) as u8])?;
+			let game_datas = GameData {
+				difficulty: game_type,
+				player: signer.clone(),
+				property,
+				guess: None,
+				state: GameState::Pending,
+				settlement_block: expiry_block.saturating_add(T::SettlementPeriod::get()),
+			};
 			GameInfo::<T>::insert(game_id, game_datas);
 			let next_game_id = game_id.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
 			GameId::<T>::put(next_game_id);
-			// Submit the encrypted property data and delete the price
 			Self::deposit_event(Event::<T>::GameStarted { player: signer, game_id, ending_block: expiry_block });
 			Ok(())
 		}
 
-		/// Checks the answer of the player and handles rewards accordingly.
+		/// Submits the answer of the player.
+		///
+		/// The answer is checked automatically once the price of the property has been revealed.
 		///
 		/// The origin must be Signed and the sender must have sufficient funds free.
 		///
@@ -522,7 +922,7 @@ pub mod pallet {
 		#[pallet::feeless_if(|origin: &OriginFor<T>, guess: &u32, game_id: &u32| -> bool {
 			if let Ok(signer) = ensure_signed(origin.clone()) {
 				if let Some(game_info) = GameInfo::<T>::get(*game_id) {
-					if signer == game_info.player {
+					if signer == game_info.player && game_info.state.is_open() {
 						return true;
 					}
 				}
@@ -533,49 +933,21 @@ pub mod pallet {
 			let signer = ensure_signed(origin)?;
 			let mut game_info = GameInfo::<T>::get(game_id).ok_or(Error::<T>::NoActiveGame)?;
 			ensure!(signer == game_info.player, Error::<T>::NoThePlayer);
+			ensure!(game_info.state.is_open(), Error::<T>::NoActiveGame);
+			let round = game_info.property.round;
+			ensure!(T::Timelock::latest_round() < round, Error::<T>::PriceRevealed);
+			ensure!(!ProposedResults::<T>::contains_key(game_id), Error::<T>::PriceRevealed);
+			if game_info.state == GameState::Pending {
+				PendingReveals::<T>::try_append((round, game_id))
+					.map_err(|_| Error::<T>::TooManyGames)?;
+			}
 			game_info.guess = Some(guess);
+			game_info.state = GameState::Answered;
 			GameInfo::<T>::insert(game_id, game_info);
 			Self::deposit_event(Event::<T>::AnswerSubmitted { player: signer, game_id, guess });
 			Ok(())
 		}
 
-		/// Checks the answer of the player and handles rewards accordingly.
-		///
-		/// The origin must be root.
-		///
-		/// Parameters:
-		/// - `guess`: The answer of the player.
-		/// - `game_id`: The id of the game that the result should be compared to.
-		/// - `price`: The price of the property.
-		/// - `secret`: The secret to decrypt the price and property data.
-		///
-		/// Emits `ResultChecked` event when succesfful.
-		#[pallet::call_index(5)]
-		#[pallet::weight(<T as pallet::Config>::WeightInfo::check_result())]
-		pub fn check_result(
-			origin: OriginFor<T>,
-			guess: u32,
-			game_id: u32,
-			price: u32,
-			secret: BoundedVec<u8, <T as Config>::StringLimit>,
-		) -> DispatchResult {
-			T::GameOrigin::ensure_origin(origin)?;
-			let difference_value = ((price as i32)
-				.checked_sub(guess as i32)
-				.ok_or(Error::<T>::ArithmeticUnderflow)?)
-			.checked_mul(1000)
-			.ok_or(Error::<T>::MultiplyError)?
-			.checked_div(price as i32)
-			.ok_or(Error::<T>::DivisionError)?
-			.abs();
-			Self::do_check_result(
-				difference_value.try_into().map_err(|_| Error::<T>::ConversionError)?,
-				game_id,
-				secret,
-			)?;
-			Ok(())
-		}
-
 		/// Lists a nft from the user.
 		///
 		/// The origin must be Signed and the sender must have sufficient funds free.
@@ -583,6 +955,7 @@ pub mod pallet {
 		/// Parameters:
 		/// - `collection_id`: The collection id of the nft that will be listed.
 		/// - `item_id`: The item id of the nft that will be listed.
+		/// - `price`: Either a fixed price or the minimum bid for offers.
 		///
 		/// Emits `NftListed` event when succesfful.
 		#[pallet::call_index(6)]
@@ -591,6 +964,7 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			collection_id: CollectionId<T>,
 			item_id: ItemId<T>,
+			price: ListingPrice<CurrencyBalanceOf<T>>,
 		) -> DispatchResult {
 			let signer = ensure_signed(origin.clone())?;
 			let pallet_lookup = <T::Lookup as StaticLookup>::unlookup(Self::account_id());
@@ -611,18 +985,30 @@ pub mod pallet {
 				item_id.into(),
 				pallet_lookup,
 			)?;
-			let listing_info = ListingInfo { owner: signer.clone(), collection_id, item_id };
-			let mut listing_id = NextListingId::<T>::get();
+			let expiry =
+				<frame_system::Pallet<T>>::block_number().saturating_add(T::ListingDuration::get());
+			let listing_info =
+				ListingInfo { owner: signer.clone(), collection_id, item_id, price, expiry };
+			let listing_id = NextListingId::<T>::get();
 			Listings::<T>::insert(listing_id, listing_info);
-			listing_id = listing_id.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-			NextListingId::<T>::put(listing_id);
-			Self::deposit_event(Event::<T>::NftListed { owner: signer, collection_id, item_id });
+			let next_listing_id =
+				listing_id.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
+			NextListingId::<T>::put(next_listing_id);
+			Self::deposit_event(Event::<T>::NftListed {
+				owner: signer,
+				listing_id,
+				collection_id,
+				item_id,
+				price,
+				expiry,
+			});
 			Ok(())
 		}
 
 		/// Delists a nft from the user.
 		///
-		/// The origin must be Signed and the sender must have sufficient funds free.
+		/// The origin must be Signed and the sender must be the owner of the listing, once the
+		/// listing has expired anyone can delist it. The nft is returned to the owner.
 		///
 		/// Parameters:
 		/// - `listing_id`: The listing id of the listing.
@@ -634,35 +1020,33 @@ pub mod pallet {
 			let signer = ensure_signed(origin.clone())?;
 			let listing_info =
 				Listings::<T>::take(listing_id).ok_or(Error::<T>::ListingDoesNotExist)?;
-			ensure!(listing_info.owner == signer, Error::<T>::NoPermission);
-			pallet_nfts::Pallet::<T>::do_transfer(
-				listing_info.collection_id.into(),
-				listing_info.item_id.into(),
-				signer.clone(),
-				|_, _| Ok(()),
-			)?;
-			let pallet_origin: OriginFor<T> = RawOrigin::Signed(Self::account_id()).into();
-			pallet_nfts::Pallet::<T>::lock_item_transfer(
-				pallet_origin,
-				listing_info.collection_id.into(),
-				listing_info.item_id.into(),
+			ensure!(
+				listing_info.owner == signer ||
+					listing_info.is_expired(<frame_system::Pallet<T>>::block_number()),
+				Error::<T>::NoPermission
+			);
+			Self::release_nft(
+				listing_info.owner.clone(),
+				listing_info.collection_id,
+				listing_info.item_id,
 			)?;
 			Self::deposit_event(Event::<T>::NftDelisted {
-				owner: signer,
+				owner: listing_info.owner,
 				collection_id: listing_info.collection_id,
 				item_id: listing_info.item_id,
 			});
 			Ok(())
 		}
 
-		/// Makes an offer for a nft listing.
+		/// Makes an offer for a nft listing with a minimum bid.
 		///
-		/// The origin must be Signed and the sender must have sufficient funds free.
+		/// The origin must be Signed and the sender must have sufficient funds free. The
+		/// offered amount is reserved until the offer is handled or withdrawn.
 		///
 		/// Parameters:
 		/// - `listing_id`: The listing id of the listing.
-		/// - `collection_id`: The collection id of the nft that will be offered.
-		/// - `item_id`: The item id of the nft that will be offered.
+		/// - `nft`: The collection id and item id of the nft that will be offered.
+		/// - `amount`: The amount that will be offered, at least the minimum bid of the listing.
 		///
 		/// Emits `OfferMade` event when succesfful.
 		#[pallet::call_index(8)]
@@ -670,42 +1054,55 @@ pub mod pallet {
 		pub fn make_offer(
 			origin: OriginFor<T>,
 			listing_id: u32,
-			collection_id: CollectionId<T>,
-			item_id: ItemId<T>,
+			nft: Option<(CollectionId<T>, ItemId<T>)>,
+			amount: CurrencyBalanceOf<T>,
 		) -> DispatchResult {
 			let signer = ensure_signed(origin.clone())?;
-			ensure!(Listings::<T>::get(listing_id).is_some(), Error::<T>::ListingDoesNotExist);
-			let pallet_lookup = <T::Lookup as StaticLookup>::unlookup(Self::account_id());
-			let pallet_origin: OriginFor<T> = RawOrigin::Signed(Self::account_id()).into();
-			pallet_nfts::Pallet::<T>::unlock_item_transfer(
-				pallet_origin,
-				collection_id.into(),
-				item_id.into(),
-			)?;
-			pallet_nfts::Pallet::<T>::transfer(
-				origin,
-				collection_id.into(),
-				item_id.into(),
-				pallet_lookup,
-			)?;
-			let offer_info =
-				OfferInfo { owner: signer.clone(), listing_id, collection_id, item_id };
+			let listing_info =
+				Listings::<T>::get(listing_id).ok_or(Error::<T>::ListingDoesNotExist)?;
+			ensure!(
+				!listing_info.is_expired(<frame_system::Pallet<T>>::block_number()),
+				Error::<T>::ListingExpired
+			);
+			let ListingPrice::MinimumBid(minimum_bid) = listing_info.price else {
+				return Err(Error::<T>::NoOffersAccepted.into());
+			};
+			ensure!(amount >= minimum_bid, Error::<T>::BidTooLow);
+			ensure!(nft.is_some() || !amount.is_zero(), Error::<T>::EmptyOffer);
+			<T as pallet::Config>::Currency::reserve(&signer, amount)?;
+			if let Some((collection_id, item_id)) = nft {
+				let pallet_lookup = <T::Lookup as StaticLookup>::unlookup(Self::account_id());
+				let pallet_origin: OriginFor<T> = RawOrigin::Signed(Self::account_id()).into();
+				pallet_nfts::Pallet::<T>::unlock_item_transfer(
+					pallet_origin,
+					collection_id.into(),
+					item_id.into(),
+				)?;
+				pallet_nfts::Pallet::<T>::transfer(
+					origin,
+					collection_id.into(),
+					item_id.into(),
+					pallet_lookup,
+				)?;
+			}
+			let offer_info = OfferInfo { owner: signer.clone(), listing_id, nft, amount };
 			let offer_id = NextOfferId::<T>::get();
 			Offers::<T>::insert(offer_id, offer_info);
-			let offer_id = offer_id.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
-			NextOfferId::<T>::put(offer_id);
+			let next_offer_id = offer_id.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
+			NextOfferId::<T>::put(next_offer_id);
 			Self::deposit_event(Event::<T>::OfferMade {
 				owner: signer,
 				listing_id,
-				collection_id,
-				item_id,
+				offer_id,
+				nft,
+				amount,
 			});
 			Ok(())
 		}
 
 		/// Withdraw an offer.
 		///
-		/// The origin must be Signed and the sender must have sufficient funds free.
+		/// The origin must be Signed and the sender must be the owner of the offer.
 		///
 		/// Parameters:
 		/// - `offer_id`: The id of the offer.
@@ -715,99 +1112,85 @@ pub mod pallet {
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::make_offer())]
 		pub fn withdraw_offer(origin: OriginFor<T>, offer_id: u32) -> DispatchResult {
 			let signer = ensure_signed(origin.clone())?;
-			let offer_details = Offers::<T>::get(offer_id).ok_or(Error::<T>::OfferDoesNotExist)?;
+			let offer_details = Offers::<T>::take(offer_id).ok_or(Error::<T>::OfferDoesNotExist)?;
 			ensure!(offer_details.owner == signer, Error::<T>::NoPermission);
-			pallet_nfts::Pallet::<T>::do_transfer(
-				offer_details.collection_id.into(),
-				offer_details.item_id.into(),
-				signer.clone(),
-				|_, _| Ok(()),
-			)?;
-			let pallet_origin: OriginFor<T> = RawOrigin::Signed(Self::account_id()).into();
-			pallet_nfts::Pallet::<T>::lock_item_transfer(
-				pallet_origin,
-				offer_details.collection_id.into(),
-				offer_details.item_id.into(),
-			)?;
-			Offers::<T>::take(offer_id).ok_or(Error::<T>::OfferDoesNotExist)?;
+			Self::release_offer(offer_details)?;
 			Self::deposit_event(Event::<T>::OfferWithdrawn { owner: signer, offer_id });
 			Ok(())
 		}
 
 		/// Handles an offer for a nft listing.
 		///
-		/// The origin must be Signed and the sender must have sufficient funds free.
+		/// The origin must be Signed and the sender must be the owner of the listing. Accepting
+		/// an offer pays the offered amount minus the marketplace fee to the seller and swaps
+		/// the nfts.
 		///
 		/// Parameters:
 		/// - `offer_id`: The id of the offer.
 		/// - `offer`: Must be either Accept or Reject.
 		///
-		/// Emits `OfferHandeld` event when succesfful.
+		/// Emits `OfferHandled` event when succesfful.
 		#[pallet::call_index(10)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::handle_offer())]
 		pub fn handle_offer(origin: OriginFor<T>, offer_id: u32, offer: Offer) -> DispatchResult {
 			let signer = ensure_signed(origin.clone())?;
 			let offer_details = Offers::<T>::take(offer_id).ok_or(Error::<T>::OfferDoesNotExist)?;
-			let listing_details = Listings::<T>::get(offer_details.listing_id)
-				.ok_or(Error::<T>::ListingDoesNotExist)?;
+			let listing_id = offer_details.listing_id;
+			let listing_details =
+				Listings::<T>::get(listing_id).ok_or(Error::<T>::ListingDoesNotExist)?;
 			ensure!(listing_details.owner == signer, Error::<T>::NoPermission);
-			let pallet_origin: OriginFor<T> = RawOrigin::Signed(Self::account_id()).into();
 			if offer == Offer::Accept {
-				pallet_nfts::Pallet::<T>::do_transfer(
-					listing_details.collection_id.into(),
-					listing_details.item_id.into(),
-					offer_details.owner.clone(),
-					|_, _| Ok(()),
-				)?;
-				pallet_nfts::Pallet::<T>::lock_item_transfer(
-					pallet_origin.clone(),
-					listing_details.collection_id.into(),
-					listing_details.item_id.into(),
+				ensure!(
+					!listing_details.is_expired(<frame_system::Pallet<T>>::block_number()),
+					Error::<T>::ListingExpired
+				);
+				Listings::<T>::remove(offer_details.listing_id);
+				let fee = Self::pay_seller(
+					&offer_details.owner,
+					&listing_details.owner,
+					offer_details.amount,
 				)?;
-				pallet_nfts::Pallet::<T>::do_transfer(
-					offer_details.collection_id.into(),
-					offer_details.item_id.into(),
-					listing_details.owner.clone(),
-					|_, _| Ok(()),
-				)?;
-				pallet_nfts::Pallet::<T>::lock_item_transfer(
-					pallet_origin,
-					offer_details.collection_id.into(),
-					offer_details.item_id.into(),
-				)?;
-				Listings::<T>::take(offer_details.listing_id)
-					.ok_or(Error::<T>::ListingDoesNotExist)?;
-
-				Self::swap_user_points(
+				Self::release_nft(
 					offer_details.owner.clone(),
 					listing_details.collection_id,
-					offer_details.collection_id,
-				)?;
-				Self::swap_user_points(
-					signer.clone(),
-					offer_details.collection_id,
-					listing_details.collection_id,
+					listing_details.item_id,
 				)?;
+				if let Some((collection_id, item_id)) = offer_details.nft {
+					Self::release_nft(listing_details.owner.clone(), collection_id, item_id)?;
+					Self::swap_user_points(
+						offer_details.owner.clone(),
+						listing_details.collection_id,
+						collection_id,
+					)?;
+					Self::swap_user_points(
+						signer.clone(),
+						collection_id,
+						listing_details.collection_id,
+					)?;
+				} else {
+					Self::transfer_user_points(
+						&signer,
+						&offer_details.owner,
+						listing_details.collection_id,
+					)?;
+				}
+				Self::deposit_event(Event::<T>::NftSold {
+					listing_id: offer_details.listing_id,
+					seller: signer,
+					buyer: offer_details.owner,
+					price: offer_details.amount,
+					fee,
+				});
 			} else {
-				pallet_nfts::Pallet::<T>::do_transfer(
-					offer_details.collection_id.into(),
-					offer_details.item_id.into(),
-					offer_details.owner,
-					|_, _| Ok(()),
-				)?;
-				pallet_nfts::Pallet::<T>::lock_item_transfer(
-					pallet_origin,
-					offer_details.collection_id.into(),
-					offer_details.item_id.into(),
-				)?;
+				Self::release_offer(offer_details)?;
 			}
-			Self::deposit_event(Event::<T>::OfferHandeld { offer_id, offer });
+			Self::deposit_event(Event::<T>::OfferHandled { offer_id, listing_id, offer });
 			Ok(())
 		}
 
 		/// Add a new property and the price.
 		///
-		/// The origin must be the sudo.
+		/// The origin must be the property curator.
 		///
 		/// Parameters:
 		/// - `property`: The new property that will be added.
@@ -815,64 +1198,73 @@ pub mod pallet {
 		#[pallet::call_index(11)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_property())]
 		pub fn add_property(origin: OriginFor<T>, property: PropertyInfoData<T>) -> DispatchResult {
-			T::GameOrigin::ensure_origin(origin)?;
-			GameProperties::<T>::try_append(property.clone())
-				.map_err(|_| Error::<T>::TooManyTest)?;
+			T::PropertyCuratorOrigin::ensure_origin(origin)?;
+			Self::do_import_properties([property])?;
 			Ok(())
 		}
 
 		/// Remove a new property and the price.
 		///
-		/// The origin must be the sudo.
+		/// The origin must be the property curator.
 		///
 		/// Parameters:
 		/// - `id`: The id of the property that should be removed.
 		#[pallet::call_index(12)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_property())]
 		pub fn remove_property(origin: OriginFor<T>, id: u32) -> DispatchResult {
-			T::GameOrigin::ensure_origin(origin)?;
+			T::PropertyCuratorOrigin::ensure_origin(origin)?;
 			let mut properties = GameProperties::<T>::take();
 			properties.retain(|property| property.id != id);
 			GameProperties::<T>::put(properties);
 			Ok(())
 		}
 
-		/// Adds an account to the admins.
+		/// Grants a role to an account.
 		///
-		/// The origin must be the sudo.
+		/// The origin must be the game origin.
 		///
 		/// Parameters:
+		/// - `role`: The role granted to the account.
 		/// - `new_admin`: The address of the new account added to the list.
 		///
 		/// Emits `NewAdminAdded` event when succesfful
 		#[pallet::call_index(13)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_to_admins())]
-		pub fn add_to_admins(origin: OriginFor<T>, new_admin: AccountIdOf<T>) -> DispatchResult {
+		pub fn add_to_admins(
+			origin: OriginFor<T>,
+			role: Role,
+			new_admin: AccountIdOf<T>,
+		) -> DispatchResult {
 			T::GameOrigin::ensure_origin(origin)?;
-			ensure!(!Admins::<T>::get().contains(&new_admin), Error::<T>::AccountAlreadyAdmin,);
-			Admins::<T>::try_append(new_admin.clone()).map_err(|_| Error::<T>::TooManyAdmins)?;
-			Self::deposit_event(Event::<T>::NewAdminAdded { new_admin });
+			ensure!(!Admins::<T>::get(role).contains(&new_admin), Error::<T>::AccountAlreadyAdmin,);
+			Admins::<T>::try_append(role, new_admin.clone())
+				.map_err(|_| Error::<T>::TooManyAdmins)?;
+			Self::deposit_event(Event::<T>::NewAdminAdded { role, new_admin });
 			Ok(())
 		}
 
-		/// Removes an account from the admins.
+		/// Revokes a role from an account.
 		///
-		/// The origin must be the sudo.
+		/// The origin must be the game origin.
 		///
 		/// Parameters:
+		/// - `role`: The role revoked from the account.
 		/// - `admin`: The address of the admin removed from the admins.
 		///
-		/// Emits `UserRemoved` event when succesfful
+		/// Emits `AdminRemoved` event when succesfful
 		#[pallet::call_index(14)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_from_admins())]
-		pub fn remove_from_admins(origin: OriginFor<T>, admin: AccountIdOf<T>) -> DispatchResult {
+		pub fn remove_from_admins(
+			origin: OriginFor<T>,
+			role: Role,
+			admin: AccountIdOf<T>,
+		) -> DispatchResult {
 			T::GameOrigin::ensure_origin(origin)?;
-			ensure!(Admins::<T>::get().contains(&admin), Error::<T>::NotAdmin);
-			let mut admins = Admins::<T>::get();
-			let index = admins.iter().position(|x| *x == admin).ok_or(Error::<T>::InvalidIndex)?;
+			let mut admins = Admins::<T>::get(role);
+			let index = admins.iter().position(|x| *x == admin).ok_or(Error::<T>::NotAdmin)?;
 			admins.remove(index);
-			Admins::<T>::put(admins);
-			Self::deposit_event(Event::<T>::AdminRemoved { admin });
+			Admins::<T>::insert(role, admins);
+			Self::deposit_event(Event::<T>::AdminRemoved { role, admin });
 			Ok(())
 		}
 
@@ -891,13 +1283,484 @@ pub mod pallet {
 			let next_request =
 				current_block_number.saturating_add(<T as Config>::RequestLimit::get());
 			user.next_token_request = next_request;
-			<T as pallet::Config>::Currency::make_free_balance_be(
-				&signer,
-				10000000000000u64.try_into().map_err(|_| Error::<T>::ConversionError)?,
-			);
+			Self::fund_player(&signer);
 			Users::<T>::insert(signer.clone(), user);
 			Self::deposit_event(Event::<T>::TokenReceived { player: signer });
 			Ok(())
 		}
+
+		/// Imports a batch of properties into the catalogue.
+		///
+		/// The origin must be the property curator.
+		///
+		/// Parameters:
+		/// - `properties`: The properties in any version of the import schema, their ids must not
+		///   be in the catalogue yet.
+		///
+		/// Emits `PropertiesImported` event when succesfful.
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::import_properties(properties.len() as u32))]
+		pub fn import_properties(
+			origin: OriginFor<T>,
+			properties: BoundedVec<VersionedProperty<T>, T::MaxProperty>,
+		) -> DispatchResult {
+			T::PropertyCuratorOrigin::ensure_origin(origin)?;
+			let count = properties.len() as u32;
+			Self::do_import_properties(properties.into_iter().map(Into::into))?;
+			Self::deposit_event(Event::<T>::PropertiesImported { count });
+			Ok(())
+		}
+
+		/// Updates the scoring of the difficulty levels.
+		///
+		/// The origin must be the season manager.
+		///
+		/// Parameters:
+		/// - `scoring`: The new scoring, the bands of each difficulty level must be ordered by
+		///   their maximum difference.
+		///
+		/// Emits `ScoringUpdated` event when succesfful.
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_scoring())]
+		pub fn set_scoring(origin: OriginFor<T>, scoring: ScoringTable) -> DispatchResult {
+			T::SeasonManagerOrigin::ensure_origin(origin)?;
+			ensure!(scoring.is_valid(), Error::<T>::InvalidScoring);
+			Scoring::<T>::put(scoring);
+			Self::deposit_event(Event::<T>::ScoringUpdated);
+			Ok(())
+		}
+
+		/// Schedules the next round.
+		///
+		/// At the start block the active round is ended and the new round starts with new
+		/// collections. At the end block the round is ended and the prizes are paid from the
+		/// pallet account.
+		///
+		/// The origin must be the season manager.
+		///
+		/// Parameters:
+		/// - `start`: The block the round starts.
+		/// - `end`: The block the round ends.
+		/// - `prizes`: The prizes of the leaderboard ranks, starting with the first rank.
+		///
+		/// Emits `RoundScheduled` event when succesfful.
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::schedule_round())]
+		pub fn schedule_round(
+			origin: OriginFor<T>,
+			start: BlockNumberFor<T>,
+			end: BlockNumberFor<T>,
+			prizes: BoundedVec<CurrencyBalanceOf<T>, T::MaxPrizes>,
+		) -> DispatchResult {
+			T::SeasonManagerOrigin::ensure_origin(origin)?;
+			ensure!(NextRound::<T>::get().is_none(), Error::<T>::RoundAlreadyScheduled);
+			let current_block_number = <frame_system::Pallet<T>>::block_number();
+			ensure!(start > current_block_number && end > start, Error::<T>::InvalidSchedule);
+			NextRound::<T>::put(RoundSchedule { start, end, prizes });
+			Self::deposit_event(Event::<T>::RoundScheduled { start, end });
+			Ok(())
+		}
+
+		/// Cancels the scheduled round.
+		///
+		/// The origin must be the season manager.
+		///
+		/// Emits `ScheduledRoundCancelled` event when succesfful.
+		#[pallet::call_index(19)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::cancel_scheduled_round())]
+		pub fn cancel_scheduled_round(origin: OriginFor<T>) -> DispatchResult {
+			T::SeasonManagerOrigin::ensure_origin(origin)?;
+			NextRound::<T>::take().ok_or(Error::<T>::NoRoundScheduled)?;
+			Self::deposit_event(Event::<T>::ScheduledRoundCancelled);
+			Ok(())
+		}
+
+		/// Ends the active round before its end block.
+		///
+		/// The origin must be the season manager.
+		///
+		/// Emits `RoundEnded` event when succesfful.
+		#[pallet::call_index(20)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::end_round())]
+		pub fn end_round(origin: OriginFor<T>) -> DispatchResult {
+			T::SeasonManagerOrigin::ensure_origin(origin)?;
+			ensure!(RoundActive::<T>::get(), Error::<T>::NoActiveRound);
+			Self::do_close_round(None);
+			Ok(())
+		}
+
+		/// Buys a nft that is listed for a fixed price.
+		///
+		/// The origin must be Signed and the sender must have sufficient funds free. The seller
+		/// receives the price minus the marketplace fee.
+		///
+		/// Parameters:
+		/// - `listing_id`: The listing id of the listing.
+		///
+		/// Emits `NftSold` event when succesfful.
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::buy_nft())]
+		pub fn buy_nft(origin: OriginFor<T>, listing_id: u32) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			let listing_info =
+				Listings::<T>::take(listing_id).ok_or(Error::<T>::ListingDoesNotExist)?;
+			ensure!(listing_info.owner != signer, Error::<T>::NoPermission);
+			ensure!(
+				!listing_info.is_expired(<frame_system::Pallet<T>>::block_number()),
+				Error::<T>::ListingExpired
+			);
+			let ListingPrice::Fixed(price) = listing_info.price else {
+				return Err(Error::<T>::NotForSale.into());
+			};
+			<T as pallet::Config>::Currency::reserve(&signer, price)?;
+			let fee = Self::pay_seller(&signer, &listing_info.owner, price)?;
+			Self::release_nft(signer.clone(), listing_info.collection_id, listing_info.item_id)?;
+			Self::transfer_user_points(&listing_info.owner, &signer, listing_info.collection_id)?;
+			Self::deposit_event(Event::<T>::NftSold {
+				listing_id,
+				seller: listing_info.owner,
+				buyer: signer,
+				price,
+				fee,
+			});
+			Ok(())
+		}
+
+		/// Opens a lobby that players join with an invite code.
+		///
+		/// The origin must be Signed and the sender must be a registered player. Every player
+		/// pays the entry points of the difficulty level into the pot of the lobby.
+		///
+		/// Parameters:
+		/// - `difficulty`: The difficulty level of the lobby, can't be `Practice`.
+		/// - `capacity`: The amount of players, the lobby starts once it is full.
+		/// - `invite`: The hash of the invite code.
+		///
+		/// Emits `LobbyCreated` event when succesfful.
+		#[pallet::call_index(22)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::create_lobby())]
+		pub fn create_lobby(
+			origin: OriginFor<T>,
+			difficulty: DifficultyLevel,
+			capacity: u32,
+			invite: T::Hash,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			ensure!(
+				(2..=T::MaxLobbyPlayers::get()).contains(&capacity),
+				Error::<T>::InvalidLobbySize
+			);
+			ensure!(difficulty != DifficultyLevel::Practice, Error::<T>::InvalidLobbyDifficulty);
+			let entry_points = Self::pay_lobby_entry(&signer, &difficulty)?;
+			let lobby_id = NextLobbyId::<T>::get();
+			let lobby = LobbyData {
+				difficulty,
+				state: LobbyState::Open { host: signer.clone(), capacity, invite },
+				players: BoundedVec::truncate_from(sp_std::vec![(signer.clone(), entry_points)]),
+			};
+			Lobbies::<T>::insert(lobby_id, lobby);
+			PlayerLobby::<T>::insert(&signer, lobby_id);
+			let next_lobby_id = lobby_id.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
+			NextLobbyId::<T>::put(next_lobby_id);
+			Self::deposit_event(Event::<T>::LobbyCreated {
+				lobby_id,
+				host: signer,
+				capacity,
+				entry_points,
+			});
+			Ok(())
+		}
+
+		/// Joins an open lobby.
+		///
+		/// The origin must be Signed and the sender must be a registered player.
+		///
+		/// Parameters:
+		/// - `lobby_id`: The id of the lobby.
+		/// - `code`: The invite code of the lobby.
+		///
+		/// Emits `LobbyJoined` event when succesfful.
+		#[pallet::call_index(23)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::join_lobby())]
+		pub fn join_lobby(origin: OriginFor<T>, lobby_id: u32, code: [u8; 32]) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			let mut lobby = Lobbies::<T>::get(lobby_id).ok_or(Error::<T>::LobbyDoesNotExist)?;
+			let LobbyState::Open { capacity, invite, .. } = lobby.state else {
+				return Err(Error::<T>::LobbyNotOpen.into());
+			};
+			ensure!(T::Hashing::hash(&code) == invite, Error::<T>::WrongInviteCode);
+			let entry_points = Self::pay_lobby_entry(&signer, &lobby.difficulty)?;
+			lobby
+				.players
+				.try_push((signer.clone(), entry_points))
+				.map_err(|_| Error::<T>::InvalidLobbySize)?;
+			PlayerLobby::<T>::insert(&signer, lobby_id);
+			Self::deposit_event(Event::<T>::LobbyJoined { lobby_id, player: signer });
+			if lobby.players.len() as u32 >= capacity {
+				return Self::do_start_lobby(lobby_id, lobby.difficulty, lobby.players);
+			}
+			Lobbies::<T>::insert(lobby_id, lobby);
+			Ok(())
+		}
+
+		/// Leaves an open lobby and returns the entry points.
+		///
+		/// The origin must be Signed and the sender must be a player of the lobby. If the host
+		/// leaves, the lobby is closed and all players get their entry points back.
+		///
+		/// Parameters:
+		/// - `lobby_id`: The id of the lobby.
+		///
+		/// Emits `LobbyLeft` or `LobbyCancelled` event when succesfful.
+		#[pallet::call_index(24)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::leave_lobby(T::MaxLobbyPlayers::get()))]
+		pub fn leave_lobby(origin: OriginFor<T>, lobby_id: u32) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			let mut lobby = Lobbies::<T>::get(lobby_id).ok_or(Error::<T>::LobbyDoesNotExist)?;
+			let LobbyState::Open { host, .. } = &lobby.state else {
+				return Err(Error::<T>::LobbyNotOpen.into());
+			};
+			if *host == signer {
+				Lobbies::<T>::remove(lobby_id);
+				Self::refund_lobby(&lobby)?;
+				Self::deposit_event(Event::<T>::LobbyCancelled { lobby_id });
+			} else {
+				let position = lobby
+					.players
+					.iter()
+					.position(|(player, _)| *player == signer)
+					.ok_or(Error::<T>::NotInLobby)?;
+				let (_, entry_points) = lobby.players.remove(position);
+				Self::refund_lobby_entry(&signer, entry_points)?;
+				Lobbies::<T>::insert(lobby_id, lobby);
+				Self::deposit_event(Event::<T>::LobbyLeft { lobby_id, player: signer });
+			}
+			Ok(())
+		}
+
+		/// Starts an open lobby before it is full.
+		///
+		/// The origin must be Signed and the sender must be the host of the lobby, which needs
+		/// at least two players.
+		///
+		/// Parameters:
+		/// - `lobby_id`: The id of the lobby.
+		///
+		/// Emits `LobbyStarted` event when succesfful.
+		#[pallet::call_index(25)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::start_lobby())]
+		pub fn start_lobby(origin: OriginFor<T>, lobby_id: u32) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			let lobby = Lobbies::<T>::get(lobby_id).ok_or(Error::<T>::LobbyDoesNotExist)?;
+			let LobbyState::Open { host, .. } = &lobby.state else {
+				return Err(Error::<T>::LobbyNotOpen.into());
+			};
+			ensure!(*host == signer, Error::<T>::NoPermission);
+			ensure!(lobby.players.len() >= 2, Error::<T>::InvalidLobbySize);
+			Self::do_start_lobby(lobby_id, lobby.difficulty, lobby.players)
+		}
+
+		/// Waits for the matchmaker to put the player into a lobby.
+		///
+		/// The origin must be Signed and the sender must be a registered player. The entry
+		/// points of the difficulty level are paid when queuing, the lobby starts as soon as
+		/// the matchmaker found enough players of the same difficulty level.
+		///
+		/// Parameters:
+		/// - `difficulty`: The difficulty level of the lobby, can't be `Practice`.
+		///
+		/// Emits `QueuedForLobby` event when succesfful.
+		#[pallet::call_index(26)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::queue_for_lobby())]
+		pub fn queue_for_lobby(
+			origin: OriginFor<T>,
+			difficulty: DifficultyLevel,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			let bracket = Self::lobby_bracket(&difficulty)?;
+			let entry_points = Self::pay_lobby_entry(&signer, &difficulty)?;
+			T::Matchmaker::add_queue(signer.clone(), bracket)?;
+			MatchmakingEntries::<T>::insert(&signer, entry_points);
+			Self::deposit_event(Event::<T>::QueuedForLobby { player: signer, entry_points });
+			let players = T::Matchmaker::try_match_bracket(bracket);
+			if !players.is_empty() {
+				Self::create_matched_lobby(players, difficulty)?;
+			}
+			Ok(())
+		}
+
+		/// Submits the sealed guess of a player.
+		///
+		/// The guess is the SCALE encoded price, timelock encrypted against the drand round of
+		/// the property. It can be replaced until the ending block of the lobby.
+		///
+		/// The origin must be Signed and the sender must be a player of the lobby.
+		///
+		/// Parameters:
+		/// - `lobby_id`: The id of the lobby.
+		/// - `sealed_guess`: The encrypted guess.
+		///
+		/// Emits `SealedGuessSubmitted` event when succesfful.
+		#[pallet::call_index(27)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::submit_sealed_guess())]
+		pub fn submit_sealed_guess(
+			origin: OriginFor<T>,
+			lobby_id: u32,
+			sealed_guess: SealedGuess<T>,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			let lobby = Lobbies::<T>::get(lobby_id).ok_or(Error::<T>::LobbyDoesNotExist)?;
+			let LobbyState::Guessing { property, ending_block } = &lobby.state else {
+				return Err(Error::<T>::LobbyNotStarted.into());
+			};
+			ensure!(lobby.has_player(&signer), Error::<T>::NotInLobby);
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() <= *ending_block,
+				Error::<T>::GuessingClosed
+			);
+			ensure!(T::Timelock::latest_round() < property.round, Error::<T>::PriceRevealed);
+			LobbyGuesses::<T>::insert(lobby_id, &signer, sealed_guess);
+			Self::deposit_event(Event::<T>::SealedGuessSubmitted { lobby_id, player: signer });
+			Ok(())
+		}
+
+		/// Registers the caller as a player against a deposit.
+		///
+		/// The origin must be Signed and the sender must have sufficient funds free to reserve
+		/// the registration deposit.
+		///
+		/// Emits `NewPlayerRegistered` event when succesfful.
+		#[pallet::call_index(28)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::register())]
+		pub fn register(origin: OriginFor<T>) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			let deposit =
+				T::RegistrationDeposit::get().ok_or(Error::<T>::SelfRegistrationDisabled)?;
+			ensure!(Users::<T>::get(signer.clone()).is_none(), Error::<T>::PlayerAlreadyRegistered);
+			<T as pallet::Config>::Currency::reserve(&signer, deposit)?;
+			RegistrationDeposits::<T>::insert(signer.clone(), deposit);
+			Self::do_register_user(signer);
+			Ok(())
+		}
+
+		/// Proposes the price of an answered game.
+		///
+		/// The origin must be the results oracle. The result is final once the dispute window
+		/// is over.
+		///
+		/// Parameters:
+		/// - `game_id`: The id of the answered game.
+		/// - `price`: The true price of the property of the game.
+		///
+		/// Emits `ResultProposed` event when succesfful.
+		#[pallet::call_index(29)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::submit_result())]
+		pub fn submit_result(origin: OriginFor<T>, game_id: u32, price: u32) -> DispatchResult {
+			T::ResultsOracleOrigin::ensure_origin(origin)?;
+			Self::do_propose_result(game_id, price)
+		}
+
+		/// Proposes the price of an answered game on behalf of an oracle key.
+		///
+		/// The origin must be None. The payload must be signed by an oracle key whose account
+		/// holds the results oracle role, which is checked when the transaction is validated.
+		///
+		/// Parameters:
+		/// - `payload`: The id of the game, the price and the public key of the oracle.
+		/// - `signature`: The signature of the payload.
+		///
+		/// Emits `ResultProposed` event when succesfful.
+		#[pallet::call_index(30)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::submit_result())]
+		pub fn submit_result_unsigned(
+			origin: OriginFor<T>,
+			payload: ResultPayload<T::Public>,
+			_signature: T::Signature,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+			Self::do_propose_result(payload.game_id, payload.price)
+		}
+
+		/// Disputes the result the oracle proposed for a game.
+		///
+		/// The origin must be the game origin. The game is scored once its price is revealed
+		/// by the drand beacon instead.
+		///
+		/// Parameters:
+		/// - `game_id`: The id of the game.
+		///
+		/// Emits `ResultDisputed` event when succesfful.
+		#[pallet::call_index(31)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::dispute_result())]
+		pub fn dispute_result(origin: OriginFor<T>, game_id: u32) -> DispatchResult {
+			T::GameOrigin::ensure_origin(origin)?;
+			ProposedResults::<T>::try_mutate(game_id, |proposal| -> DispatchResult {
+				let proposal = proposal
+					.as_mut()
+					.filter(|proposal| !proposal.disputed)
+					.ok_or(Error::<T>::NoProposedResult)?;
+				proposal.disputed = true;
+				Ok(())
+			})?;
+			Self::deposit_event(Event::<T>::ResultDisputed { game_id });
+			Ok(())
+		}
+
+		/// Burns nfts of one color to craft a nft of a random other color.
+		///
+		/// The origin must be Signed and the sender must be a registered player. The nfts have
+		/// to be from the current round.
+		///
+		/// Parameters:
+		/// - `collection_id`: The collection of the burned nfts.
+		/// - `items`: The items to burn, exactly `CraftingCost` of them.
+		///
+		/// Emits `NftCrafted` event when succesfful.
+		#[pallet::call_index(32)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::craft_nft())]
+		pub fn craft_nft(
+			origin: OriginFor<T>,
+			collection_id: CollectionId<T>,
+			items: BoundedVec<ItemId<T>, T::CraftingCost>,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			Self::do_craft_nft(signer, collection_id, &items)
+		}
+
+		/// Burns a nft of every color to craft a trophy, which gives bonus points.
+		///
+		/// The origin must be Signed and the sender must be a registered player. The nfts have
+		/// to be from the current round.
+		///
+		/// Parameters:
+		/// - `items`: One item of every collection of the round, in the order of the collections.
+		///
+		/// Emits `TrophyCrafted` event when succesfful.
+		#[pallet::call_index(33)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::craft_trophy())]
+		pub fn craft_trophy(
+			origin: OriginFor<T>,
+			items: BoundedVec<ItemId<T>, ConstU32<8>>,
+		) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			Self::do_craft_trophy(signer, &items)
+		}
+
+		/// Stops waiting for the matchmaker and returns the entry points.
+		///
+		/// The origin must be Signed and the sender must be queued for a lobby.
+		///
+		/// Emits `LeftLobbyQueue` event when succesfful.
+		#[pallet::call_index(34)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::leave_lobby_queue())]
+		pub fn leave_lobby_queue(origin: OriginFor<T>) -> DispatchResult {
+			let signer = ensure_signed(origin)?;
+			let entry_points =
+				MatchmakingEntries::<T>::take(&signer).ok_or(Error::<T>::NotQueuedForLobby)?;
+			T::Matchmaker::remove_queue(signer.clone());
+			Self::refund_lobby_entry(&signer, entry_points)?;
+			Self::deposit_event(Event::<T>::LeftLobbyQueue { player: signer, entry_points });
+			Ok(())
+		}
 	}
 }
@@ -0,0 +1,175 @@
+use crate::*;
+use frame_support::{pallet_prelude::*, storage::with_storage_layer};
+use frame_system::pallet_prelude::*;
+use sp_std::prelude::*;
+
+impl<T: Config> Pallet<T> {
+	/// Takes the entry points of a difficulty level from a player who joins a lobby.
+	pub(crate) fn pay_lobby_entry(
+		player: &AccountIdOf<T>,
+		difficulty: &DifficultyLevel,
+	) -> Result<u32, DispatchError> {
+		ensure!(RoundActive::<T>::get(), Error::<T>::NoActiveRound);
+		ensure!(
+			!PlayerLobby::<T>::contains_key(player) &&
+				!MatchmakingEntries::<T>::contains_key(player),
+			Error::<T>::AlreadyInLobby
+		);
+		Self::join_current_round(player)?;
+		Self::check_enough_points(player.clone(), difficulty.clone())?;
+		let entry_points = Self::scoring().difficulty(difficulty).entry_points;
+		let mut user = Self::users(player.clone()).ok_or(Error::<T>::UserNotRegistered)?;
+		user.points =
+			user.points.checked_sub(entry_points).ok_or(Error::<T>::ArithmeticUnderflow)?;
+		Users::<T>::insert(player, user.clone());
+		Self::update_leaderboard(player.clone(), user.points)?;
+		Ok(entry_points)
+	}
+
+	/// Returns the entry points to a player and releases the player from the lobby.
+	pub(crate) fn refund_lobby_entry(player: &AccountIdOf<T>, entry_points: u32) -> DispatchResult {
+		PlayerLobby::<T>::remove(player);
+		Self::join_current_round(player)?;
+		let mut user = Self::users(player.clone()).ok_or(Error::<T>::UserNotRegistered)?;
+		user.points = user.points.saturating_add(entry_points);
+		Users::<T>::insert(player, user.clone());
+		Self::update_leaderboard(player.clone(), user.points)
+	}
+
+	/// Returns the entry points to all players of a lobby.
+	pub(crate) fn refund_lobby(lobby: &LobbyData<T>) -> DispatchResult {
+		for (player, entry_points) in &lobby.players {
+			Self::refund_lobby_entry(player, *entry_points)?;
+		}
+		Ok(())
+	}
+
+	/// Returns the matchmaking bracket of a difficulty level.
+	pub(crate) fn lobby_bracket(difficulty: &DifficultyLevel) -> Result<u8, DispatchError> {
+		match difficulty {
+			DifficultyLevel::Practice => Err(Error::<T>::InvalidLobbyDifficulty.into()),
+			DifficultyLevel::Player => Ok(0),
+			DifficultyLevel::Pro => Ok(1),
+		}
+	}
+
+	/// Opens a lobby for the players the matchmaker has found.
+	pub(crate) fn create_matched_lobby(
+		players: Vec<AccountIdOf<T>>,
+		difficulty: DifficultyLevel,
+	) -> DispatchResult {
+		let lobby_id = NextLobbyId::<T>::get();
+		let mut entries = BoundedVec::new();
+		for player in players {
+			let entry_points =
+				MatchmakingEntries::<T>::take(&player).ok_or(Error::<T>::NotQueuedForLobby)?;
+			entries
+				.try_push((player.clone(), entry_points))
+				.map_err(|_| Error::<T>::InvalidLobbySize)?;
+			PlayerLobby::<T>::insert(&player, lobby_id);
+			Self::deposit_event(Event::<T>::LobbyJoined { lobby_id, player });
+		}
+		let next_lobby_id = lobby_id.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
+		NextLobbyId::<T>::put(next_lobby_id);
+		Self::do_start_lobby(lobby_id, difficulty, entries)
+	}
+
+	/// Draws the property of a lobby and lets its players submit their guesses.
+	pub(crate) fn do_start_lobby(
+		lobby_id: u32,
+		difficulty: DifficultyLevel,
+		players: BoundedVec<(AccountIdOf<T>, u32), T::MaxLobbyPlayers>,
+	) -> DispatchResult {
+		let expiry_blocks = Self::scoring().difficulty(&difficulty).expiry_blocks;
+		let ending_block =
+			<frame_system::Pallet<T>>::block_number().saturating_add(expiry_blocks.into());
+		let property = Self::draw_property(&(b"lobby", lobby_id).encode())?;
+		PendingLobbies::<T>::try_append((property.round, lobby_id))
+			.map_err(|_| Error::<T>::TooManyGames)?;
+		let player_count = players.len() as u32;
+		let state = LobbyState::Guessing { property, ending_block };
+		Lobbies::<T>::insert(lobby_id, LobbyData { difficulty, state, players });
+		Self::deposit_event(Event::<T>::LobbyStarted {
+			lobby_id,
+			players: player_count,
+			ending_block,
+		});
+		Ok(())
+	}
+
+	/// Pays the pot of a lobby to the closest guess once the price has been revealed.
+	///
+	/// Lobbies whose price can't be decrypted or without any valid guess are voided and the
+	/// players get their entry points back.
+	pub(crate) fn settle_lobby(lobby_id: u32) {
+		let Some(lobby) = Lobbies::<T>::take(lobby_id) else {
+			return;
+		};
+		let guesses = LobbyGuesses::<T>::drain_prefix(lobby_id).collect::<Vec<_>>();
+		let settled = with_storage_layer(|| Self::do_settle_lobby(lobby_id, &lobby, &guesses));
+		if settled.is_err() {
+			let _ = with_storage_layer(|| Self::refund_lobby(&lobby));
+			lobby.players.iter().for_each(|(player, _)| PlayerLobby::<T>::remove(player));
+			Self::deposit_event(Event::<T>::LobbyVoided { lobby_id });
+		}
+	}
+
+	fn do_settle_lobby(
+		lobby_id: u32,
+		lobby: &LobbyData<T>,
+		guesses: &[(AccountIdOf<T>, SealedGuess<T>)],
+	) -> DispatchResult {
+		let LobbyState::Guessing { property, .. } = &lobby.state else {
+			return Err(Error::<T>::LobbyNotStarted.into());
+		};
+		let price = Self::reveal_price(property).ok_or(Error::<T>::NoProperty)?;
+		let mut closest = Vec::new();
+		let mut closest_difference = u16::MAX;
+		for (player, sealed_guess) in guesses {
+			if !lobby.has_player(player) {
+				continue;
+			}
+			let Some(guess) = T::Timelock::decrypt(property.round, sealed_guess)
+				.and_then(|guess| u32::decode(&mut &guess[..]).ok())
+			else {
+				continue;
+			};
+			let difference = Self::price_difference(price, guess)?;
+			if difference < closest_difference {
+				closest_difference = difference;
+				closest.clear();
+			}
+			if difference == closest_difference {
+				closest.push(player.clone());
+			}
+		}
+		let winner = Self::break_tie(lobby_id, closest).ok_or(Error::<T>::NoGuess)?;
+		let pot = lobby.pot();
+		for (player, _) in &lobby.players {
+			PlayerLobby::<T>::remove(player);
+			Self::join_current_round(player)?;
+			let mut user = Self::users(player.clone()).ok_or(Error::<T>::UserNotRegistered)?;
+			if *player == winner {
+				user.points = user.points.saturating_add(pot);
+				user.wins = user.wins.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
+			} else {
+				user.losses = user.losses.checked_add(1).ok_or(Error::<T>::ArithmeticOverflow)?;
+			}
+			Users::<T>::insert(player, user.clone());
+			Self::update_leaderboard(player.clone(), user.points)?;
+		}
+		Self::deposit_event(Event::<T>::LobbySettled { lobby_id, winner, price, pot });
+		Ok(())
+	}
+
+	/// Picks the winner among equally close guesses with the randomness of the beacon.
+	fn break_tie(lobby_id: u32, mut closest: Vec<AccountIdOf<T>>) -> Option<AccountIdOf<T>> {
+		if closest.len() > 1 {
+			let (random, _) = T::GameRandomness::random(&(b"lobby_tie", lobby_id).encode());
+			let random = u32::from_le_bytes(random.as_ref().get(0..4)?.try_into().ok()?);
+			let index = random as usize % closest.len();
+			return Some(closest.swap_remove(index));
+		}
+		closest.pop()
+	}
+}
@@ -1,10 +1,17 @@
 use crate as pallet_game;
-use frame_support::{parameter_types, traits::AsEnsureOriginWithArg, PalletId};
+use codec::Encode;
+use frame_support::{
+	parameter_types,
+	traits::{AsEnsureOriginWithArg, EitherOfDiverse},
+	PalletId,
+};
+use pallet_game::{EnsureRole, Role, RoundNumber, TimelockDecrypter};
 use pallet_nfts::PalletFeatures;
-use sp_core::ConstU32;
+use sp_core::{ConstU32, ConstU64, ConstU8};
 use sp_runtime::{
-	traits::{AccountIdLookup, BlakeTwo256, IdentifyAccount, Verify},
-	BuildStorage, MultiSignature,
+	testing::TestXt,
+	traits::{AccountIdLookup, BlakeTwo256, Extrinsic as ExtrinsicT, IdentifyAccount, Verify},
+	BuildStorage, MultiSignature, Percent,
 };
 pub type BlockNumber = u64;
 pub type AccountId = <<Signature as Verify>::Signer as IdentifyAccount>::AccountId;
@@ -28,6 +35,7 @@ frame_support::construct_runtime!(
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Nfts: pallet_nfts::{Pallet, Call, Storage, Event<T>},
 		RandomnessCollectiveFlip: pallet_insecure_randomness_collective_flip,
+		MatchmakerModule: pallet_matchmaker,
 	}
 );
 
@@ -133,6 +141,76 @@ parameter_types! {
 	pub const LeaderLimit: u32 = 10;
 	pub const MaxAdmin: u32 = 10;
 	pub const RequestLimits: BlockNumber = 180;
+	pub const RevealDelay: RoundNumber = 10;
+	pub const InitialPoints: u32 = 50;
+	pub const MaxPrizes: u32 = 3;
+	pub const MarketplaceFee: Percent = Percent::from_percent(10);
+	pub const ListingDuration: BlockNumber = 100;
+	pub const SettlementPeriod: BlockNumber = 20;
+	pub const PropertyPoolThreshold: u32 = 1;
+	pub const MaxLobbyPlayers: u32 = 8;
+	pub const FaucetAmount: u32 = 10;
+	pub const RegistrationDeposit: Option<u32> = Some(5);
+	pub const RegistrarRole: Role = Role::Registrar;
+	pub const PropertyCuratorRole: Role = Role::PropertyCurator;
+	pub const ResultsOracleRole: Role = Role::ResultsOracle;
+	pub const SeasonManagerRole: Role = Role::SeasonManager;
+	pub const DisputeWindow: BlockNumber = 5;
+	pub const PriceEndpoint: &'static str = "http://localhost:8080/prices";
+	pub const CraftingCost: u32 = 3;
+	pub const TrophyPoints: u32 = 1000;
+}
+
+thread_local! {
+	static LATEST_ROUND: core::cell::RefCell<RoundNumber> = core::cell::RefCell::new(0);
+	static MISSING_ROUNDS: core::cell::RefCell<Vec<RoundNumber>> = core::cell::RefCell::new(Vec::new());
+}
+
+/// A beacon whose rounds are advanced by the tests.
+///
+/// Its "ciphertexts" are the plain SCALE encoded prices, which it hands out once their round
+/// has been reached.
+pub struct MockTimelock;
+
+impl MockTimelock {
+	pub fn set_latest_round(round: RoundNumber) {
+		LATEST_ROUND.with(|latest| *latest.borrow_mut() = round);
+	}
+
+	/// Leaves out the signature of `round`, as if the beacon had moved past it within one block.
+	pub fn skip_round(round: RoundNumber) {
+		MISSING_ROUNDS.with(|missing| missing.borrow_mut().push(round));
+	}
+
+	pub fn lock_price(price: u32) -> Vec<u8> {
+		price.encode()
+	}
+}
+
+impl TimelockDecrypter for MockTimelock {
+	fn latest_round() -> RoundNumber {
+		LATEST_ROUND.with(|latest| *latest.borrow())
+	}
+
+	fn is_revealed(round: RoundNumber) -> bool {
+		round <= Self::latest_round() &&
+			MISSING_ROUNDS.with(|missing| !missing.borrow().contains(&round))
+	}
+
+	fn decrypt(round: RoundNumber, ciphertext: &[u8]) -> Option<Vec<u8>> {
+		Self::is_revealed(round).then(|| ciphertext.to_vec())
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_game::BenchmarkHelper for MockTimelock {
+	fn lock_price(_round: RoundNumber, price: u32) -> Vec<u8> {
+		Self::lock_price(price)
+	}
+
+	fn reveal_round(round: RoundNumber) {
+		Self::set_latest_round(round.max(Self::latest_round()));
+	}
 }
 
 /// Configure the pallet-game in pallets/game.
@@ -141,6 +219,14 @@ impl pallet_game::Config for Test {
 	type Currency = Balances;
 	type WeightInfo = pallet_game::weights::SubstrateWeight<Test>;
 	type GameOrigin = EnsureRoot<Self::AccountId>;
+	type RegistrarOrigin =
+		EitherOfDiverse<EnsureRoot<Self::AccountId>, EnsureRole<Test, RegistrarRole>>;
+	type PropertyCuratorOrigin =
+		EitherOfDiverse<EnsureRoot<Self::AccountId>, EnsureRole<Test, PropertyCuratorRole>>;
+	type ResultsOracleOrigin =
+		EitherOfDiverse<EnsureRoot<Self::AccountId>, EnsureRole<Test, ResultsOracleRole>>;
+	type SeasonManagerOrigin =
+		EitherOfDiverse<EnsureRoot<Self::AccountId>, EnsureRole<Test, SeasonManagerRole>>;
 	type CollectionId = u32;
 	type ItemId = u32;
 	type MaxProperty = MaxProperties;
@@ -151,6 +237,61 @@ impl pallet_game::Config for Test {
 	type LeaderboardLimit = LeaderLimit;
 	type MaxAdmins = MaxAdmin;
 	type RequestLimit = RequestLimits;
+	type FaucetAmount = FaucetAmount;
+	type RegistrationDeposit = RegistrationDeposit;
+	type Timelock = MockTimelock;
+	type RevealDelay = RevealDelay;
+	type InitialPoints = InitialPoints;
+	type MaxPrizes = MaxPrizes;
+	type MarketplaceFee = MarketplaceFee;
+	type ListingDuration = ListingDuration;
+	type SettlementPeriod = SettlementPeriod;
+	type PropertyPoolThreshold = PropertyPoolThreshold;
+	type Matchmaker = MatchmakerModule;
+	type MaxLobbyPlayers = MaxLobbyPlayers;
+	type AuthorityId = pallet_game::crypto::OracleAuthId;
+	type PriceSource = pallet_game::HttpPriceSource<PriceEndpoint>;
+	type DisputeWindow = DisputeWindow;
+	type UnsignedPriority = ConstU64<100>;
+	type CraftingCost = CraftingCost;
+	type TrophyPoints = TrophyPoints;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MockTimelock;
+}
+
+pub type Extrinsic = TestXt<RuntimeCall, ()>;
+
+impl frame_system::offchain::SigningTypes for Test {
+	type Public = <Signature as Verify>::Signer;
+	type Signature = Signature;
+}
+
+impl<LocalCall> frame_system::offchain::SendTransactionTypes<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	type OverarchingCall = RuntimeCall;
+	type Extrinsic = Extrinsic;
+}
+
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	fn create_transaction<C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+		call: RuntimeCall,
+		_public: <Signature as Verify>::Signer,
+		_account: AccountId,
+		nonce: u32,
+	) -> Option<(RuntimeCall, <Extrinsic as ExtrinsicT>::SignaturePayload)> {
+		Some((call, (nonce.into(), ())))
+	}
+}
+
+impl pallet_matchmaker::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AmountPlayers = ConstU8<2>;
+	type AmountBrackets = ConstU8<2>;
 }
 
 // Build genesis storage according to the mock runtime.
@@ -0,0 +1,201 @@
+use crate::*;
+use frame_support::{pallet_prelude::*, storage::with_storage_layer};
+use frame_system::{
+	offchain::{SendUnsignedTransaction, SignedPayload, Signer, SigningTypes},
+	pallet_prelude::*,
+};
+use sp_core::crypto::KeyTypeId;
+use sp_runtime::offchain::{
+	http,
+	storage::{MutateStorageError, StorageRetrievalError, StorageValueRef},
+	Duration,
+};
+use sp_std::{marker::PhantomData, prelude::*};
+
+/// The key type of the keys that sign the results of the oracle.
+pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"gorc");
+
+/// The amount of answered games the offchain worker looks up per block.
+const GAMES_PER_BLOCK: usize = 5;
+
+/// The amount of blocks until the offchain worker looks up the price of a game again.
+const RETRY_BLOCKS: u32 = 5;
+
+/// The time the price source has to answer a request.
+const REQUEST_TIMEOUT_MS: u64 = 2_000;
+
+/// The keys of the oracle.
+///
+/// An oracle key can only submit results if its account holds the `ResultsOracle` role.
+pub mod crypto {
+	use super::KEY_TYPE;
+	use sp_core::sr25519::Signature as Sr25519Signature;
+	use sp_runtime::{
+		app_crypto::{app_crypto, sr25519},
+		traits::Verify,
+		MultiSignature, MultiSigner,
+	};
+	app_crypto!(sr25519, KEY_TYPE);
+
+	pub struct OracleAuthId;
+
+	impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for OracleAuthId {
+		type RuntimeAppPublic = Public;
+		type GenericSignature = sp_core::sr25519::Signature;
+		type GenericPublic = sp_core::sr25519::Public;
+	}
+
+	impl frame_system::offchain::AppCrypto<<Sr25519Signature as Verify>::Signer, Sr25519Signature>
+		for OracleAuthId
+	{
+		type RuntimeAppPublic = Public;
+		type GenericSignature = sp_core::sr25519::Signature;
+		type GenericPublic = sp_core::sr25519::Public;
+	}
+}
+
+/// A source for the true prices of the properties, queried by the offchain worker.
+pub trait PriceSource {
+	/// Fetches the price of the property `property_id`.
+	///
+	/// Returns `None` if the price is not known or the source can't be reached.
+	fn fetch_price(property_id: u32) -> Option<u32>;
+}
+
+/// The answer of the HTTP price source.
+#[derive(serde::Deserialize)]
+struct PriceResponse {
+	price: u32,
+}
+
+/// Fetches the prices with a `GET` request to `{Endpoint}/{property_id}`.
+///
+/// The endpoint answers with a JSON object like `{"price": 220000}`.
+pub struct HttpPriceSource<Endpoint>(PhantomData<Endpoint>);
+
+impl<Endpoint: Get<&'static str>> PriceSource for HttpPriceSource<Endpoint> {
+	fn fetch_price(property_id: u32) -> Option<u32> {
+		let url = alloc::format!("{}/{}", Endpoint::get(), property_id);
+		let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(REQUEST_TIMEOUT_MS));
+		let pending = http::Request::get(&url).deadline(deadline).send().ok()?;
+		let response = pending.try_wait(deadline).ok()?.ok()?;
+		if response.code != 200 {
+			return None;
+		}
+		let body = response.body().collect::<Vec<u8>>();
+		serde_json::from_slice::<PriceResponse>(&body)
+			.ok()
+			.map(|response| response.price)
+	}
+}
+
+/// A result of a game signed by an oracle key.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ResultPayload<Public> {
+	pub game_id: u32,
+	pub price: u32,
+	pub public: Public,
+}
+
+impl<T: SigningTypes> SignedPayload<T> for ResultPayload<T::Public> {
+	fn public(&self) -> T::Public {
+		self.public.clone()
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Proposes the price of an answered game, which is final once the dispute window is over.
+	pub(crate) fn do_propose_result(game_id: u32, price: u32) -> DispatchResult {
+		let final_block = Self::ensure_can_propose(game_id)?;
+		ResultsFinalising::<T>::try_append(final_block, game_id)
+			.map_err(|_| Error::<T>::TooManyGames)?;
+		ProposedResults::<T>::insert(
+			game_id,
+			ProposedResult { price, final_block, disputed: false },
+		);
+		Self::deposit_event(Event::<T>::ResultProposed { game_id, price, final_block });
+		Ok(())
+	}
+
+	/// Checks that a result can be proposed for the game and returns the block it is final at.
+	pub(crate) fn ensure_can_propose(game_id: u32) -> Result<BlockNumberFor<T>, DispatchError> {
+		let game_info = GameInfo::<T>::get(game_id).ok_or(Error::<T>::NoActiveGame)?;
+		ensure!(game_info.state == GameState::Answered, Error::<T>::NoActiveGame);
+		ensure!(!ProposedResults::<T>::contains_key(game_id), Error::<T>::ResultAlreadyProposed);
+		let final_block =
+			<frame_system::Pallet<T>>::block_number().saturating_add(T::DisputeWindow::get());
+		ensure!(final_block < game_info.settlement_block, Error::<T>::ResultTooLate);
+		Ok(final_block)
+	}
+
+	/// Scores a game with its proposed price if it hasn't been disputed, and returns the weight
+	/// that was used.
+	///
+	/// Disputed results are kept until the game is removed, so the guess stays locked.
+	pub(crate) fn finalise_result(game_id: u32) -> Weight {
+		let Some(proposal) =
+			ProposedResults::<T>::get(game_id).filter(|proposal| !proposal.disputed)
+		else {
+			return T::DbWeight::get().reads(1);
+		};
+		ProposedResults::<T>::remove(game_id);
+		let Some(game_info) =
+			GameInfo::<T>::get(game_id).filter(|game_info| game_info.state == GameState::Answered)
+		else {
+			return T::DbWeight::get().reads_writes(2, 1);
+		};
+		PendingReveals::<T>::mutate(|pending| pending.retain(|(_, id)| *id != game_id));
+		let checked = with_storage_layer(|| -> DispatchResult {
+			let guess = game_info.guess.ok_or(Error::<T>::NoGuess)?;
+			let difference = Self::price_difference(proposal.price, guess)?;
+			Self::do_check_result(difference, game_id, proposal.price)
+		});
+		if checked.is_err() {
+			Self::finish_game(game_id, game_info, GameState::Voided);
+			Self::deposit_event(Event::<T>::RevealFailed { game_id });
+		}
+		<T as pallet::Config>::WeightInfo::finalise_result()
+	}
+
+	/// Looks up the prices of answered games and submits them as results signed by an oracle
+	/// key.
+	pub(crate) fn run_oracle(block_number: BlockNumberFor<T>) {
+		let signer = Signer::<T, T::AuthorityId>::any_account();
+		if !signer.can_sign() {
+			return;
+		}
+		PendingReveals::<T>::get()
+			.into_iter()
+			.map(|(_, game_id)| game_id)
+			.filter(|game_id| Self::ensure_can_propose(*game_id).is_ok())
+			.filter(|game_id| Self::claim_lookup(*game_id, block_number))
+			.take(GAMES_PER_BLOCK)
+			.for_each(|game_id| {
+				let Some(game_info) = GameInfo::<T>::get(game_id) else {
+					return;
+				};
+				let Some(price) = T::PriceSource::fetch_price(game_info.property.id) else {
+					return;
+				};
+				let _ = signer.send_unsigned_transaction(
+					|account| ResultPayload { game_id, price, public: account.public.clone() },
+					|payload, signature| Call::submit_result_unsigned { payload, signature },
+				);
+			});
+	}
+
+	/// Records in the offchain storage that the price of the game is looked up in this block.
+	///
+	/// Returns `false` if it has been looked up less than `RETRY_BLOCKS` blocks ago.
+	fn claim_lookup(game_id: u32, block_number: BlockNumberFor<T>) -> bool {
+		let key = (b"pallet-game::oracle", game_id).encode();
+		let result = StorageValueRef::persistent(&key).mutate(
+			|last: Result<Option<BlockNumberFor<T>>, StorageRetrievalError>| match last {
+				Ok(Some(last)) if block_number < last.saturating_add(RETRY_BLOCKS.into()) =>
+					Err(()),
+				_ => Ok(block_number),
+			},
+		);
+		!matches!(result, Err(MutateStorageError::ValueFunctionFailed(_)))
+	}
+}
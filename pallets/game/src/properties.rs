@@ -1,32 +1,57 @@
 use crate::*;
-use frame_support::pallet_prelude::*;
+use frame_support::{pallet_prelude::*, storage::with_storage_layer};
+use sp_std::collections::btree_set::BTreeSet;
 
 impl<T: Config> Pallet<T> {
-	pub(crate) fn create_game_properties() -> DispatchResult {
-		let new_property = PropertyInfoData {
-			id: 147229391,
-			data: "nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(),
+	/// Decrypts the price of an answered game and checks the answer.
+	///
+	/// Games whose price can't be decrypted or checked are voided.
+	pub(crate) fn reveal_result(game_id: u32) {
+		let Some(game_info) =
+			GameInfo::<T>::get(game_id).filter(|game_info| game_info.state == GameState::Answered)
+		else {
+			return;
 		};
-		GameProperties::<T>::try_append(new_property.clone())
-			.map_err(|_| Error::<T>::TooManyTest)?;
-		let new_property = PropertyInfoData {
-			id: 146480642,
-			data: "nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(),
-		};
-		GameProperties::<T>::try_append(new_property.clone())
-			.map_err(|_| Error::<T>::TooManyTest)?;
-		let new_property = PropertyInfoData {
-			id: 147031382,
-			data: "nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(),
-		};
-		GameProperties::<T>::try_append(new_property.clone())
-			.map_err(|_| Error::<T>::TooManyTest)?;
-		let new_property = PropertyInfoData {
-			id: 147031382,
-			data: "nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(),
-		};
-		GameProperties::<T>::try_append(new_property.clone())
-			.map_err(|_| Error::<T>::TooManyTest)?;
-		Ok(())
+		let checked = with_storage_layer(|| -> DispatchResult {
+			let guess = game_info.guess.ok_or(Error::<T>::NoGuess)?;
+			let price = Self::reveal_price(&game_info.property).ok_or(Error::<T>::NoProperty)?;
+			let difference = Self::price_difference(price, guess)?;
+			Self::do_check_result(difference, game_id, price)
+		});
+		if checked.is_err() {
+			Self::finish_game(game_id, game_info, GameState::Voided);
+			Self::deposit_event(Event::<T>::RevealFailed { game_id });
+		}
+	}
+
+	/// Appends properties to the catalogue, making sure that their ids are unique.
+	pub(crate) fn do_import_properties(
+		properties: impl IntoIterator<Item = PropertyInfoData<T>>,
+	) -> DispatchResult {
+		GameProperties::<T>::try_mutate(|catalogue| -> DispatchResult {
+			let mut ids = catalogue.iter().map(|known| known.id).collect::<BTreeSet<_>>();
+			for property in properties {
+				ensure!(ids.insert(property.id), Error::<T>::PropertyIdTaken);
+				catalogue.try_push(property).map_err(|_| Error::<T>::TooManyTest)?;
+			}
+			Ok(())
+		})
+	}
+
+	/// Decrypts the timelocked price of a property.
+	pub fn reveal_price(property: &PropertyInfoData<T>) -> Option<u32> {
+		let price = T::Timelock::decrypt(property.round, &property.price)?;
+		u32::decode(&mut &price[..]).ok()
+	}
+
+	/// The difference between guess and price in per mille of the price.
+	pub fn price_difference(price: u32, guess: u32) -> Result<u16, DispatchError> {
+		let difference = (price as i64)
+			.saturating_sub(guess as i64)
+			.saturating_mul(1000)
+			.checked_div(price as i64)
+			.ok_or(Error::<T>::DivisionError)?
+			.unsigned_abs();
+		Ok(difference.try_into().unwrap_or(u16::MAX))
 	}
 }
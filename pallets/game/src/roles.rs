@@ -0,0 +1,35 @@
+use crate::*;
+#[cfg(feature = "runtime-benchmarks")]
+use frame_support::sp_runtime::traits::TrailingZeroInput;
+use frame_support::{pallet_prelude::*, traits::EnsureOrigin};
+use sp_std::marker::PhantomData;
+
+type RuntimeOriginOf<T> = <T as frame_system::Config>::RuntimeOrigin;
+
+/// Ensures that the origin is signed by an admin holding the role `R` and returns the admin.
+pub struct EnsureRole<T, R>(PhantomData<(T, R)>);
+
+impl<T: Config, R: Get<Role>> EnsureOrigin<RuntimeOriginOf<T>> for EnsureRole<T, R> {
+	type Success = AccountIdOf<T>;
+
+	fn try_origin(o: RuntimeOriginOf<T>) -> Result<Self::Success, RuntimeOriginOf<T>> {
+		let origin: Result<RawOrigin<AccountIdOf<T>>, RuntimeOriginOf<T>> = o.into();
+		origin.and_then(|o| match o {
+			RawOrigin::Signed(who) if Admins::<T>::get(R::get()).contains(&who) => Ok(who),
+			r => Err(RuntimeOriginOf::<T>::from(r)),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<RuntimeOriginOf<T>, ()> {
+		let who = AccountIdOf::<T>::decode(&mut TrailingZeroInput::zeroes()).map_err(|_| ())?;
+		Admins::<T>::try_mutate(R::get(), |admins| {
+			if admins.contains(&who) {
+				return Ok(());
+			}
+			admins.try_push(who.clone())
+		})
+		.map_err(|_| ())?;
+		Ok(RawOrigin::Signed(who).into())
+	}
+}
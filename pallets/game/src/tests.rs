@@ -1,9 +1,66 @@
-use crate::{mock::*, Error, Event, PropertyInfoData};
+use crate::{
+	mock::*, DifficultyScoring, Error, Event, GameProperties, GameState, ListingPrice, PointsDelta,
+	PropertyInfoData, PropertyInfoDataV1, ResultPayload, Role, RoundNumber, ScoreBand,
+	ScoringTable, TimelockDecrypter, VersionedProperty,
+};
+use codec::Decode;
 use frame_support::{
 	assert_noop, assert_ok,
-	traits::{OnFinalize, OnInitialize},
+	pallet_prelude::{InvalidTransaction, TransactionSource, ValidateUnsigned},
+	traits::{Currency, Get, OffchainWorker, OnFinalize, OnIdle, OnInitialize, ReservableCurrency},
+	weights::Weight,
+	BoundedVec,
+};
+use frame_system::offchain::SignedPayload;
+use sp_core::offchain::{
+	testing::{PendingRequest, TestOffchainExt, TestTransactionPoolExt},
+	OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
 };
-use sp_runtime::{traits::BadOrigin, DispatchError, ModuleError};
+use sp_keystore::{testing::MemoryKeystore, Keystore, KeystoreExt};
+use sp_runtime::{
+	traits::{BadOrigin, Hash, IdentifyAccount},
+	BuildStorage, DispatchError, ModuleError, MultiSigner, Percent,
+};
+
+const PRICE: u32 = 220_000;
+const PROPERTY_IDS: [u32; 4] = [147229391, 146480642, 147031382, 147031383];
+
+fn property(id: u32, round: RoundNumber) -> PropertyInfoData<Test> {
+	PropertyInfoData {
+		id,
+		data: "nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(),
+		region: "Bristol".as_bytes().to_vec().try_into().unwrap(),
+		features: "3 bedrooms, garden".as_bytes().to_vec().try_into().unwrap(),
+		round,
+		price: MockTimelock::lock_price(PRICE).try_into().unwrap(),
+	}
+}
+
+/// The property in the import schema.
+fn property_v1(id: u32, round: RoundNumber) -> VersionedProperty<Test> {
+	let PropertyInfoData { id, data, region, features, round, price } = property(id, round);
+	VersionedProperty::V1(PropertyInfoDataV1 { id, data, region, features, round, price })
+}
+
+/// Replaces the properties with ones locked to a round that is far enough in the future.
+fn lock_properties() {
+	let round = MockTimelock::latest_round() + <RevealDelay as Get<RoundNumber>>::get() + 1;
+	let properties = PROPERTY_IDS.iter().map(|id| property(*id, round)).collect::<Vec<_>>();
+	GameProperties::<Test>::put(BoundedVec::truncate_from(properties));
+	crate::DrawnProperties::<Test>::kill();
+}
+
+fn setup_game() {
+	assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
+	lock_properties();
+}
+
+/// Publishes the round of the game's property and lets the next block check the result.
+fn reveal(game_id: u32) {
+	MockTimelock::set_latest_round(GameModule::game_info(game_id).unwrap().property.round);
+	run_to_block(System::block_number() + 1);
+	lock_properties();
+}
 
 fn practise_round(player: AccountId, game_id: u32) {
 	assert_ok!(GameModule::play_game(
@@ -12,13 +69,7 @@ fn practise_round(player: AccountId, game_id: u32) {
 	));
 	assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed(player.clone()), 220000, game_id));
 	System::assert_last_event(Event::AnswerSubmitted { player, game_id, guess: 220_000 }.into());
-	assert_ok!(GameModule::check_result(
-		RuntimeOrigin::root(),
-		220_000,
-		game_id,
-		220_000,
-		"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-	));
+	reveal(game_id);
 }
 
 fn run_to_block(n: u64) {
@@ -28,6 +79,7 @@ fn run_to_block(n: u64) {
 		System::set_block_number(System::block_number() + 1);
 		System::on_initialize(System::block_number());
 		GameModule::on_initialize(System::block_number());
+		GameModule::on_idle(System::block_number(), Weight::MAX);
 	}
 }
 
@@ -37,6 +89,8 @@ fn setup_game_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
+		assert_eq!(GameModule::current_round(), 1);
+		assert!(GameModule::round_active());
 	});
 }
 
@@ -52,8 +106,12 @@ fn setup_game_fails() {
 fn add_to_admins_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [0; 32].into()));
-		assert_eq!(GameModule::admins().len(), 1);
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[0; 32].into()
+		));
+		assert_eq!(GameModule::admins(Role::Registrar).len(), 1);
 	});
 }
 
@@ -62,13 +120,21 @@ fn add_to_admins_fails() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		assert_noop!(
-			GameModule::add_to_admins(RuntimeOrigin::signed([0; 32].into()), [0; 32].into()),
+			GameModule::add_to_admins(
+				RuntimeOrigin::signed([0; 32].into()),
+				Role::Registrar,
+				[0; 32].into()
+			),
 			BadOrigin
 		);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [0; 32].into()));
-		assert_eq!(GameModule::admins().len(), 1);
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[0; 32].into()
+		));
+		assert_eq!(GameModule::admins(Role::Registrar).len(), 1);
 		assert_noop!(
-			GameModule::add_to_admins(RuntimeOrigin::root(), [0; 32].into()),
+			GameModule::add_to_admins(RuntimeOrigin::root(), Role::Registrar, [0; 32].into()),
 			Error::<Test>::AccountAlreadyAdmin
 		);
 	});
@@ -78,10 +144,18 @@ fn add_to_admins_fails() {
 fn remove_admins_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [0; 32].into()));
-		assert_eq!(GameModule::admins().len(), 1);
-		assert_ok!(GameModule::remove_from_admins(RuntimeOrigin::root(), [0; 32].into()));
-		assert_eq!(GameModule::admins().len(), 0);
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[0; 32].into()
+		));
+		assert_eq!(GameModule::admins(Role::Registrar).len(), 1);
+		assert_ok!(GameModule::remove_from_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[0; 32].into()
+		));
+		assert_eq!(GameModule::admins(Role::Registrar).len(), 0);
 	});
 }
 
@@ -90,15 +164,92 @@ fn remove_admins_fails() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
 		assert_noop!(
-			GameModule::remove_from_admins(RuntimeOrigin::root(), [0; 32].into()),
+			GameModule::remove_from_admins(RuntimeOrigin::root(), Role::Registrar, [0; 32].into()),
 			Error::<Test>::NotAdmin
 		);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [0; 32].into()));
-		assert_eq!(GameModule::admins().len(), 1);
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[0; 32].into()
+		));
+		assert_eq!(GameModule::admins(Role::Registrar).len(), 1);
+		assert_noop!(
+			GameModule::remove_from_admins(
+				RuntimeOrigin::signed([0; 32].into()),
+				Role::Registrar,
+				[0; 32].into()
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn roles_are_granted_separately() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game();
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::PropertyCurator,
+			[4; 32].into()
+		));
+		System::assert_last_event(
+			Event::NewAdminAdded { role: Role::PropertyCurator, new_admin: [4; 32].into() }.into(),
+		);
+		assert_ok!(GameModule::add_property(RuntimeOrigin::signed([4; 32].into()), property(1, 5)));
 		assert_noop!(
-			GameModule::remove_from_admins(RuntimeOrigin::signed([0; 32].into()), [0; 32].into()),
+			GameModule::add_property(RuntimeOrigin::signed([0; 32].into()), property(2, 5)),
 			BadOrigin
 		);
+		assert_noop!(
+			GameModule::register_user(RuntimeOrigin::signed([4; 32].into()), [0; 32].into()),
+			BadOrigin
+		);
+		assert_noop!(
+			GameModule::give_points(RuntimeOrigin::signed([4; 32].into()), [0; 32].into(), 10),
+			BadOrigin
+		);
+		assert_ok!(GameModule::remove_from_admins(
+			RuntimeOrigin::root(),
+			Role::PropertyCurator,
+			[4; 32].into()
+		));
+		assert_noop!(
+			GameModule::remove_property(RuntimeOrigin::signed([4; 32].into()), 1),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn register_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game();
+		Balances::make_free_balance_be(&[0; 32].into(), 20);
+		assert_ok!(GameModule::register(RuntimeOrigin::signed([0; 32].into())));
+		System::assert_last_event(Event::NewPlayerRegistered { player: [0; 32].into() }.into());
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 50);
+		assert_eq!(Balances::reserved_balance(&AccountId::from([0; 32])), 5);
+		assert_eq!(GameModule::registration_deposits::<AccountId>([0; 32].into()), Some(5));
+		assert_noop!(
+			GameModule::register(RuntimeOrigin::signed([0; 32].into())),
+			Error::<Test>::PlayerAlreadyRegistered
+		);
+	});
+}
+
+#[test]
+fn register_fails_without_deposit() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game();
+		assert_noop!(
+			GameModule::register(RuntimeOrigin::signed([0; 32].into())),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+		assert!(GameModule::users::<AccountId>([0; 32].into()).is_none());
 	});
 }
 
@@ -106,15 +257,23 @@ fn remove_admins_fails() {
 fn play_game_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
+		setup_game();
 		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[4; 32].into()
+		));
 		assert_ok!(GameModule::register_user(
 			RuntimeOrigin::signed([4; 32].into()),
 			[0; 32].into()
 		));
 		assert_eq!(Balances::free_balance(&([0; 32].into())), 10);
 		assert_ok!(GameModule::give_points(RuntimeOrigin::root(), [0; 32].into(), 100));
+		System::assert_last_event(
+			Event::PointsReceived { receiver: [0; 32].into(), amount: 100, total_points: 150 }
+				.into(),
+		);
 		practise_round([0; 32].into(), 0);
 		assert_ok!(GameModule::play_game(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -128,7 +287,7 @@ fn play_game_works() {
 fn play_game_fails() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
+		setup_game();
 		assert_eq!(GameModule::game_properties().len(), 4);
 		assert_noop!(
 			GameModule::play_game(
@@ -144,7 +303,11 @@ fn play_game_fails() {
 fn play_game_fails_no_active_round() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[4; 32].into()
+		));
 		assert_ok!(GameModule::register_user(
 			RuntimeOrigin::signed([4; 32].into()),
 			[0; 32].into()
@@ -163,9 +326,13 @@ fn play_game_fails_no_active_round() {
 fn play_game_fails_not_enough_points() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
+		setup_game();
 		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[4; 32].into()
+		));
 		assert_ok!(GameModule::register_user(
 			RuntimeOrigin::signed([4; 32].into()),
 			[0; 32].into()
@@ -187,9 +354,13 @@ fn play_game_fails_not_enough_points() {
 fn submit_answer_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
+		setup_game();
 		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[4; 32].into()
+		));
 		assert_ok!(GameModule::register_user(
 			RuntimeOrigin::signed([4; 32].into()),
 			[0; 32].into()
@@ -203,14 +374,21 @@ fn submit_answer_works() {
 		System::assert_last_event(
 			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 223_000 }.into(),
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			223_000,
-			1,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
-		System::assert_last_event(Event::ResultChecked { game_id: 1, secret: "nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(), points: 25, won: true, nft_received: false }.into());
+		reveal(1);
+		System::assert_last_event(
+			Event::ResultChecked {
+				game_id: 1,
+				player: [0; 32].into(),
+				property_id: PROPERTY_IDS[0],
+				difficulty: crate::DifficultyLevel::Player,
+				price: 220_000,
+				band: Some(30),
+				points: PointsDelta::Gained(25),
+				total_points: 80,
+				nft: None,
+			}
+			.into(),
+		);
 		assert_eq!(GameModule::game_info(0).is_none(), true);
 		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 80);
 		assert_ok!(GameModule::play_game(
@@ -221,14 +399,21 @@ fn submit_answer_works() {
 		System::assert_last_event(
 			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 2, guess: 220_000 }.into(),
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			220_000,
-			2,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
-		System::assert_last_event(Event::ResultChecked { game_id: 2, secret: "nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(), points: 100, won: true, nft_received: true }.into());
+		reveal(2);
+		System::assert_last_event(
+			Event::ResultChecked {
+				game_id: 2,
+				player: [0; 32].into(),
+				property_id: PROPERTY_IDS[0],
+				difficulty: crate::DifficultyLevel::Player,
+				price: 220_000,
+				band: Some(10),
+				points: PointsDelta::Gained(100),
+				total_points: 180,
+				nft: Some((0, 0)),
+			}
+			.into(),
+		);
 		assert_eq!(GameModule::game_info(1).is_none(), true);
 		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 180);
 		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
@@ -241,13 +426,7 @@ fn submit_answer_works() {
 		System::assert_last_event(
 			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 3, guess: 0 }.into(),
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			0,
-			3,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
+		reveal(3);
 		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 155);
 	});
 }
@@ -256,9 +435,13 @@ fn submit_answer_works() {
 fn game_expires_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
+		setup_game();
 		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[4; 32].into()
+		));
 		assert_ok!(GameModule::register_user(
 			RuntimeOrigin::signed([4; 32].into()),
 			[0; 32].into()
@@ -269,30 +452,30 @@ fn game_expires_works() {
 			crate::DifficultyLevel::Player,
 		));
 		run_to_block(20);
-		System::assert_last_event(Event::NoAnswer { game_id: 1, points: 25 }.into());
+		System::assert_last_event(
+			Event::NoAnswer { game_id: 1, player: [0; 32].into(), penalty: 25, total_points: 30 }
+				.into(),
+		);
 		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 30);
 		assert_ok!(GameModule::play_game(
 			RuntimeOrigin::signed([0; 32].into()),
 			crate::DifficultyLevel::Player,
 		));
-		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 2));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 223_000, 2));
 		System::assert_last_event(
-			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 2, guess: 220_000 }.into(),
+			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 2, guess: 223_000 }.into(),
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			220_000,
-			2,
-			223_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
+		reveal(2);
 		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 55);
 		assert_ok!(GameModule::play_game(
 			RuntimeOrigin::signed([0; 32].into()),
 			crate::DifficultyLevel::Pro,
 		));
 		run_to_block(30);
-		System::assert_last_event(Event::NoAnswer { game_id: 3, points: 50 }.into());
+		System::assert_last_event(
+			Event::NoAnswer { game_id: 3, player: [0; 32].into(), penalty: 50, total_points: 5 }
+				.into(),
+		);
 		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 5);
 	});
 }
@@ -301,9 +484,13 @@ fn game_expires_works() {
 fn leaderboard_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
+		setup_game();
 		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[4; 32].into()
+		));
 		assert_ok!(GameModule::register_user(
 			RuntimeOrigin::signed([4; 32].into()),
 			[0; 32].into()
@@ -327,14 +514,21 @@ fn leaderboard_works() {
 		System::assert_last_event(
 			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 3, guess: 230_000 }.into(),
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			230_000,
-			3,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
-		System::assert_last_event(Event::ResultChecked { game_id: 3, secret: "nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(), points: 15, won: true, nft_received: false }.into());
+		reveal(3);
+		System::assert_last_event(
+			Event::ResultChecked {
+				game_id: 3,
+				player: [0; 32].into(),
+				property_id: PROPERTY_IDS[0],
+				difficulty: crate::DifficultyLevel::Player,
+				price: 220_000,
+				band: Some(50),
+				points: PointsDelta::Gained(15),
+				total_points: 70,
+				nft: None,
+			}
+			.into(),
+		);
 		assert_ok!(GameModule::play_game(
 			RuntimeOrigin::signed([1; 32].into()),
 			crate::DifficultyLevel::Player,
@@ -343,13 +537,7 @@ fn leaderboard_works() {
 		System::assert_last_event(
 			Event::AnswerSubmitted { player: [1; 32].into(), game_id: 4, guess: 225_000 }.into(),
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			225_000,
-			4,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
+		reveal(4);
 		assert_ok!(GameModule::play_game(
 			RuntimeOrigin::signed([2; 32].into()),
 			crate::DifficultyLevel::Player,
@@ -358,13 +546,7 @@ fn leaderboard_works() {
 		System::assert_last_event(
 			Event::AnswerSubmitted { player: [2; 32].into(), game_id: 5, guess: 220_000 }.into(),
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			220_000,
-			5,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
+		reveal(5);
 		assert_eq!(GameModule::game_info(0).is_none(), true);
 		assert_eq!(GameModule::users::<AccountId>([2; 32].into()).unwrap().points, 155);
 		assert_eq!(GameModule::users::<AccountId>([1; 32].into()).unwrap().points, 80);
@@ -382,7 +564,7 @@ fn leaderboard_works() {
 fn submit_answer_fails() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
+		setup_game();
 		assert_eq!(GameModule::game_properties().len(), 4);
 		assert_noop!(
 			GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 223_000, 0),
@@ -395,9 +577,13 @@ fn submit_answer_fails() {
 fn transfer_of_nft_does_not_work() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
+		setup_game();
 		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[4; 32].into()
+		));
 		assert_ok!(GameModule::register_user(
 			RuntimeOrigin::signed([4; 32].into()),
 			[0; 32].into()
@@ -412,13 +598,7 @@ fn transfer_of_nft_does_not_work() {
 		System::assert_last_event(
 			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 220_000 }.into(),
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			220_000,
-			1,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
+		reveal(1);
 		assert_eq!(GameModule::game_info(1).is_none(), true);
 		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
 		assert_noop!(
@@ -441,9 +621,13 @@ fn transfer_of_nft_does_not_work() {
 fn list_nft_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
+		setup_game();
 		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[4; 32].into()
+		));
 		assert_ok!(GameModule::register_user(
 			RuntimeOrigin::signed([4; 32].into()),
 			[0; 32].into()
@@ -457,17 +641,16 @@ fn list_nft_works() {
 		System::assert_last_event(
 			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 220_000 }.into(),
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			220_000,
-			1,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
+		reveal(1);
 		assert_eq!(GameModule::game_info(1).is_none(), true);
 		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 155);
 		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
-		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0,));
+		assert_ok!(GameModule::list_nft(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			0,
+			ListingPrice::MinimumBid(0),
+		));
 		assert_eq!(Nfts::owner(0, 0).unwrap(), GameModule::account_id());
 		assert_eq!(GameModule::listings(0).unwrap().owner, [0; 32].into());
 	});
@@ -477,9 +660,13 @@ fn list_nft_works() {
 fn list_nft_doesnt_work() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
+		setup_game();
 		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[4; 32].into()
+		));
 		assert_ok!(GameModule::register_user(
 			RuntimeOrigin::signed([4; 32].into()),
 			[0; 32].into()
@@ -493,18 +680,17 @@ fn list_nft_doesnt_work() {
 		System::assert_last_event(
 			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 220_000 }.into(),
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			220_000,
-			1,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
+		reveal(1);
 		assert_eq!(GameModule::game_info(1).is_none(), true);
 		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 155);
 		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
 		assert_noop!(
-			GameModule::list_nft(RuntimeOrigin::signed([1; 32].into()), 0, 0,),
+			GameModule::list_nft(
+				RuntimeOrigin::signed([1; 32].into()),
+				0,
+				0,
+				ListingPrice::MinimumBid(0),
+			),
 			Error::<Test>::NoPermission
 		);
 	});
@@ -514,9 +700,13 @@ fn list_nft_doesnt_work() {
 fn delist_nft_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
+		setup_game();
 		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[4; 32].into()
+		));
 		assert_ok!(GameModule::register_user(
 			RuntimeOrigin::signed([4; 32].into()),
 			[0; 32].into()
@@ -530,17 +720,16 @@ fn delist_nft_works() {
 		System::assert_last_event(
 			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 220_000 }.into(),
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			220_000,
-			1,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
+		reveal(1);
 		assert_eq!(GameModule::game_info(0).is_none(), true);
 		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 155);
 		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
-		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0,));
+		assert_ok!(GameModule::list_nft(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			0,
+			ListingPrice::MinimumBid(0),
+		));
 		assert_eq!(Nfts::owner(0, 0).unwrap(), GameModule::account_id());
 		assert_eq!(GameModule::listings(0).unwrap().owner, [0; 32].into());
 		assert_ok!(GameModule::delist_nft(RuntimeOrigin::signed([0; 32].into()), 0,));
@@ -564,9 +753,13 @@ fn delist_nft_works() {
 fn delist_nft_doesnt_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
+		setup_game();
 		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[4; 32].into()
+		));
 		assert_ok!(GameModule::register_user(
 			RuntimeOrigin::signed([4; 32].into()),
 			[0; 32].into()
@@ -580,17 +773,16 @@ fn delist_nft_doesnt_works() {
 		System::assert_last_event(
 			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 220_000 }.into(),
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			220_000,
-			1,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
+		reveal(1);
 		assert_eq!(GameModule::game_info(0).is_none(), true);
 		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 155);
 		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
-		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0,));
+		assert_ok!(GameModule::list_nft(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			0,
+			ListingPrice::MinimumBid(0),
+		));
 		assert_eq!(Nfts::owner(0, 0).unwrap(), GameModule::account_id());
 		assert_eq!(GameModule::listings(0).unwrap().owner, [0; 32].into());
 		assert_noop!(
@@ -604,9 +796,13 @@ fn delist_nft_doesnt_works() {
 fn make_offer_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
+		setup_game();
 		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[4; 32].into()
+		));
 		assert_ok!(GameModule::register_user(
 			RuntimeOrigin::signed([4; 32].into()),
 			[0; 32].into()
@@ -624,13 +820,7 @@ fn make_offer_works() {
 		System::assert_last_event(
 			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 220_000 }.into(),
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			220_000,
-			1,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
+		reveal(1);
 		assert_eq!(GameModule::game_info(0).is_none(), true);
 		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 155);
 		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
@@ -643,18 +833,22 @@ fn make_offer_works() {
 		System::assert_last_event(
 			Event::AnswerSubmitted { player: [1; 32].into(), game_id: 3, guess: 220_000 }.into(),
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			220_000,
-			3,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
+		reveal(3);
 		assert_eq!(Nfts::owner(0, 1).unwrap(), [1; 32].into());
-		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0,));
+		assert_ok!(GameModule::list_nft(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			0,
+			ListingPrice::MinimumBid(0),
+		));
 		assert_eq!(Nfts::owner(0, 0).unwrap(), GameModule::account_id());
 		assert_eq!(GameModule::listings(0).unwrap().owner, [0; 32].into());
-		assert_ok!(GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 0, 0, 1,));
+		assert_ok!(GameModule::make_offer(
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+			Some((0, 1)),
+			0,
+		));
 		assert_eq!(GameModule::offers(0).unwrap().owner, [1; 32].into());
 	});
 }
@@ -663,9 +857,13 @@ fn make_offer_works() {
 fn make_offer_doesnt_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
+		setup_game();
 		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[4; 32].into()
+		));
 		assert_ok!(GameModule::register_user(
 			RuntimeOrigin::signed([4; 32].into()),
 			[0; 32].into()
@@ -678,7 +876,7 @@ fn make_offer_doesnt_works() {
 		));
 		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
 		assert_noop!(
-			GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 0, 0, 0,),
+			GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 0, Some((0, 0)), 0,),
 			Error::<Test>::ListingDoesNotExist
 		);
 	});
@@ -688,9 +886,13 @@ fn make_offer_doesnt_works() {
 fn withdraw_offer_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
+		setup_game();
 		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[4; 32].into()
+		));
 		assert_ok!(GameModule::register_user(
 			RuntimeOrigin::signed([4; 32].into()),
 			[0; 32].into()
@@ -708,13 +910,7 @@ fn withdraw_offer_works() {
 		System::assert_last_event(
 			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 220_000 }.into(),
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			220_000,
-			1,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
+		reveal(1);
 		assert_eq!(GameModule::game_info(0).is_none(), true);
 		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 155);
 		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().wins, 1);
@@ -728,18 +924,22 @@ fn withdraw_offer_works() {
 		System::assert_last_event(
 			Event::AnswerSubmitted { player: [1; 32].into(), game_id: 3, guess: 220_000 }.into(),
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			220_000,
-			3,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
+		reveal(3);
 		assert_eq!(Nfts::owner(0, 1).unwrap(), [1; 32].into());
-		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0,));
+		assert_ok!(GameModule::list_nft(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			0,
+			ListingPrice::MinimumBid(0),
+		));
 		assert_eq!(Nfts::owner(0, 0).unwrap(), GameModule::account_id());
 		assert_eq!(GameModule::listings(0).unwrap().owner, [0; 32].into());
-		assert_ok!(GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 0, 0, 1,));
+		assert_ok!(GameModule::make_offer(
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+			Some((0, 1)),
+			0,
+		));
 		assert_eq!(GameModule::offers(0).unwrap().owner, [1; 32].into());
 		assert_ok!(GameModule::withdraw_offer(RuntimeOrigin::signed([1; 32].into()), 0));
 		assert_eq!(GameModule::offers(0).is_none(), true);
@@ -750,9 +950,13 @@ fn withdraw_offer_works() {
 fn withdraw_offer_fails() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
+		setup_game();
 		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[4; 32].into()
+		));
 		assert_ok!(GameModule::register_user(
 			RuntimeOrigin::signed([4; 32].into()),
 			[0; 32].into()
@@ -770,13 +974,7 @@ fn withdraw_offer_fails() {
 		System::assert_last_event(
 			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 220_000 }.into(),
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			220_000,
-			1,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
+		reveal(1);
 		assert_eq!(GameModule::game_info(0).is_none(), true);
 		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 155);
 		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
@@ -789,22 +987,26 @@ fn withdraw_offer_fails() {
 		System::assert_last_event(
 			Event::AnswerSubmitted { player: [1; 32].into(), game_id: 3, guess: 220_000 }.into(),
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			220_000,
-			3,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
+		reveal(3);
 		assert_eq!(Nfts::owner(0, 1).unwrap(), [1; 32].into());
-		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0,));
+		assert_ok!(GameModule::list_nft(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			0,
+			ListingPrice::MinimumBid(0),
+		));
 		assert_eq!(Nfts::owner(0, 0).unwrap(), GameModule::account_id());
 		assert_eq!(GameModule::listings(0).unwrap().owner, [0; 32].into());
 		assert_noop!(
 			GameModule::withdraw_offer(RuntimeOrigin::signed([0; 32].into()), 0),
 			Error::<Test>::OfferDoesNotExist
 		);
-		assert_ok!(GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 0, 0, 1,));
+		assert_ok!(GameModule::make_offer(
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+			Some((0, 1)),
+			0,
+		));
 		assert_eq!(GameModule::offers(0).unwrap().owner, [1; 32].into());
 		assert_noop!(
 			GameModule::withdraw_offer(RuntimeOrigin::signed([0; 32].into()), 0),
@@ -817,9 +1019,13 @@ fn withdraw_offer_fails() {
 fn handle_offer_accept_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
+		setup_game();
 		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[4; 32].into()
+		));
 		assert_ok!(GameModule::register_user(
 			RuntimeOrigin::signed([4; 32].into()),
 			[0; 32].into()
@@ -837,13 +1043,7 @@ fn handle_offer_accept_works() {
 		System::assert_last_event(
 			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 220_000 }.into(),
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			220_000,
-			1,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
+		reveal(1);
 		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 155);
 		assert_eq!(GameModule::game_info(0).is_none(), true);
 		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
@@ -856,13 +1056,7 @@ fn handle_offer_accept_works() {
 		System::assert_last_event(
 			Event::AnswerSubmitted { player: [1; 32].into(), game_id: 3, guess: 220_000 }.into(),
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			220_000,
-			3,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
+		reveal(3);
 		assert_ok!(GameModule::play_game(
 			RuntimeOrigin::signed([0; 32].into()),
 			crate::DifficultyLevel::Player,
@@ -871,13 +1065,7 @@ fn handle_offer_accept_works() {
 		System::assert_last_event(
 			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 4, guess: 220_000 }.into(),
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			220_000,
-			4,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
+		reveal(4);
 		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 275);
 		assert_ok!(GameModule::play_game(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -887,27 +1075,34 @@ fn handle_offer_accept_works() {
 		System::assert_last_event(
 			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 5, guess: 220_000 }.into(),
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			220_000,
-			5,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
+		reveal(5);
 		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().nfts.xorange, 3);
 		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 495);
 		assert_eq!(GameModule::users::<AccountId>([1; 32].into()).unwrap().nfts.xorange, 1);
 		assert_eq!(Nfts::owner(0, 1).unwrap(), [1; 32].into());
-		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0,));
+		assert_ok!(GameModule::list_nft(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			0,
+			ListingPrice::MinimumBid(0),
+		));
 		assert_eq!(Nfts::owner(0, 0).unwrap(), GameModule::account_id());
 		assert_eq!(GameModule::listings(0).unwrap().owner, [0; 32].into());
-		assert_ok!(GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 0, 0, 1,));
+		assert_ok!(GameModule::make_offer(
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+			Some((0, 1)),
+			0,
+		));
 		assert_eq!(GameModule::offers(0).unwrap().owner, [1; 32].into());
 		assert_ok!(GameModule::handle_offer(
 			RuntimeOrigin::signed([0; 32].into()),
 			0,
 			crate::Offer::Accept,
 		));
+		System::assert_last_event(
+			Event::OfferHandled { offer_id: 0, listing_id: 0, offer: crate::Offer::Accept }.into(),
+		);
 		assert_eq!(Nfts::owner(0, 0).unwrap(), [1; 32].into());
 		assert_eq!(Nfts::owner(0, 1).unwrap(), [0; 32].into());
 		assert_eq!(GameModule::offers(0).is_none(), true);
@@ -961,9 +1156,13 @@ fn handle_offer_accept_works() {
 fn handle_offer_reject_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
+		setup_game();
 		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[4; 32].into()
+		));
 		assert_ok!(GameModule::register_user(
 			RuntimeOrigin::signed([4; 32].into()),
 			[0; 32].into()
@@ -981,13 +1180,7 @@ fn handle_offer_reject_works() {
 		System::assert_last_event(
 			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 220_000 }.into(),
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			220_000,
-			1,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
+		reveal(1);
 		assert_eq!(GameModule::game_info(0).is_none(), true);
 		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 155);
 		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
@@ -1000,24 +1193,31 @@ fn handle_offer_reject_works() {
 		System::assert_last_event(
 			Event::AnswerSubmitted { player: [1; 32].into(), game_id: 3, guess: 220_000 }.into(),
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			220_000,
-			3,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
+		reveal(3);
 		assert_eq!(Nfts::owner(0, 1).unwrap(), [1; 32].into());
-		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0,));
+		assert_ok!(GameModule::list_nft(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			0,
+			ListingPrice::MinimumBid(0),
+		));
 		assert_eq!(Nfts::owner(0, 0).unwrap(), GameModule::account_id());
 		assert_eq!(GameModule::listings(0).unwrap().owner, [0; 32].into());
-		assert_ok!(GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 0, 0, 1,));
+		assert_ok!(GameModule::make_offer(
+			RuntimeOrigin::signed([1; 32].into()),
+			0,
+			Some((0, 1)),
+			0,
+		));
 		assert_eq!(GameModule::offers(0).unwrap().owner, [1; 32].into());
 		assert_ok!(GameModule::handle_offer(
 			RuntimeOrigin::signed([0; 32].into()),
 			0,
 			crate::Offer::Reject,
 		));
+		System::assert_last_event(
+			Event::OfferHandled { offer_id: 0, listing_id: 0, offer: crate::Offer::Reject }.into(),
+		);
 		assert_eq!(Nfts::owner(0, 1).unwrap(), [1; 32].into());
 		assert_eq!(GameModule::offers(0).is_none(), true);
 		assert_eq!(GameModule::listings(0).is_some(), true);
@@ -1041,9 +1241,13 @@ fn handle_offer_reject_works() {
 fn handle_offer_doesnt_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
+		setup_game();
 		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[4; 32].into()
+		));
 		assert_ok!(GameModule::register_user(
 			RuntimeOrigin::signed([4; 32].into()),
 			[0; 32].into()
@@ -1061,13 +1265,7 @@ fn handle_offer_doesnt_works() {
 		System::assert_last_event(
 			Event::AnswerSubmitted { player: [0; 32].into(), game_id: 1, guess: 220_000 }.into(),
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			220_000,
-			1,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
+		reveal(1);
 		assert_eq!(GameModule::game_info(0).is_none(), true);
 		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 155);
 		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
@@ -1080,15 +1278,14 @@ fn handle_offer_doesnt_works() {
 		System::assert_last_event(
 			Event::AnswerSubmitted { player: [1; 32].into(), game_id: 3, guess: 220_000 }.into(),
 		);
-		assert_ok!(GameModule::check_result(
-			RuntimeOrigin::root(),
-			220_000,
-			3,
-			220_000,
-			"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-		));
+		reveal(3);
 		assert_eq!(Nfts::owner(0, 1).unwrap(), [1; 32].into());
-		assert_ok!(GameModule::list_nft(RuntimeOrigin::signed([0; 32].into()), 0, 0,));
+		assert_ok!(GameModule::list_nft(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			0,
+			ListingPrice::MinimumBid(0),
+		));
 		assert_eq!(Nfts::owner(0, 0).unwrap(), GameModule::account_id());
 		assert_eq!(GameModule::listings(0).unwrap().owner, [0; 32].into());
 		assert_noop!(
@@ -1106,9 +1303,13 @@ fn handle_offer_doesnt_works() {
 fn play_multiple_rounds_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
+		setup_game();
 		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[4; 32].into()
+		));
 		assert_ok!(GameModule::register_user(
 			RuntimeOrigin::signed([4; 32].into()),
 			[0; 32].into()
@@ -1128,13 +1329,7 @@ fn play_multiple_rounds_works() {
 				Event::AnswerSubmitted { player: [0; 32].into(), game_id: x, guess: 217_000 }
 					.into(),
 			);
-			assert_ok!(GameModule::check_result(
-				RuntimeOrigin::root(),
-				217_000,
-				x,
-				220_000,
-				"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
-			));
+			reveal(x);
 		}
 		assert_ok!(GameModule::play_game(
 			RuntimeOrigin::signed([0; 32].into()),
@@ -1149,21 +1344,106 @@ fn play_multiple_rounds_works() {
 fn add_property_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
-		let new_property = PropertyInfoData {
-			id: 147031382,
-			data: "nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(),
-		};
+		setup_game();
+		let new_property = property(147031384, GameModule::game_properties()[0].round);
 		assert_ok!(GameModule::add_property(RuntimeOrigin::root(), new_property));
 		assert_eq!(GameModule::game_properties().len(), 5);
 	});
 }
 
+#[test]
+fn add_property_fails_for_taken_id() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game();
+		let taken = property(147031382, GameModule::game_properties()[0].round);
+		assert_noop!(
+			GameModule::add_property(RuntimeOrigin::root(), taken),
+			Error::<Test>::PropertyIdTaken
+		);
+	});
+}
+
+#[test]
+fn import_properties_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game();
+		let round = GameModule::game_properties()[0].round;
+		let properties = vec![property_v1(1, round), property_v1(2, round + 1)];
+		assert_noop!(
+			GameModule::import_properties(
+				RuntimeOrigin::signed([0; 32].into()),
+				properties.clone().try_into().unwrap()
+			),
+			BadOrigin
+		);
+		assert_ok!(GameModule::import_properties(
+			RuntimeOrigin::root(),
+			properties.try_into().unwrap()
+		));
+		System::assert_last_event(Event::PropertiesImported { count: 2 }.into());
+		assert_eq!(GameModule::game_properties().len(), 6);
+		assert_eq!(GameModule::game_properties()[5], property(2, round + 1));
+	});
+}
+
+#[test]
+fn import_properties_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game();
+		let round = GameModule::game_properties()[0].round;
+		assert_noop!(
+			GameModule::import_properties(
+				RuntimeOrigin::root(),
+				vec![property_v1(146480642, round)].try_into().unwrap()
+			),
+			Error::<Test>::PropertyIdTaken
+		);
+		assert_noop!(
+			GameModule::import_properties(
+				RuntimeOrigin::root(),
+				vec![property_v1(1, round), property_v1(1, round)].try_into().unwrap()
+			),
+			Error::<Test>::PropertyIdTaken
+		);
+		let properties = (1..=<MaxProperties as Get<u32>>::get())
+			.map(|id| property_v1(id, round))
+			.collect::<Vec<_>>();
+		assert_noop!(
+			GameModule::import_properties(RuntimeOrigin::root(), properties.try_into().unwrap()),
+			Error::<Test>::TooManyTest
+		);
+	});
+}
+
+#[test]
+fn genesis_imports_properties() {
+	let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	crate::GenesisConfig::<Test> { properties: vec![property_v1(1, 20), property_v1(2, 30)] }
+		.assimilate_storage(&mut storage)
+		.unwrap();
+	sp_io::TestExternalities::from(storage).execute_with(|| {
+		assert_eq!(
+			GameModule::game_properties().into_inner(),
+			vec![property(1, 20), property(2, 30)]
+		);
+	});
+}
+
+#[test]
+fn versioned_property_json_round_trips() {
+	let properties = vec![VersionedProperty::<Test>::V1(property(1, 20))];
+	let json = serde_json::to_string(&properties).unwrap();
+	assert_eq!(serde_json::from_str::<Vec<VersionedProperty<Test>>>(&json).unwrap(), properties);
+}
+
 #[test]
 fn remove_property_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
+		setup_game();
 		assert_ok!(GameModule::remove_property(RuntimeOrigin::root(), 146480642));
 		assert_eq!(GameModule::game_properties().len(), 3);
 	});
@@ -1173,8 +1453,12 @@ fn remove_property_works() {
 fn request_token_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
+		setup_game();
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[4; 32].into()
+		));
 		assert_ok!(GameModule::register_user(
 			RuntimeOrigin::signed([4; 32].into()),
 			[0; 32].into()
@@ -1185,16 +1469,37 @@ fn request_token_works() {
 	});
 }
 
+#[test]
+fn request_token_only_tops_up_balance() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game();
+		assert_ok!(GameModule::register_user(RuntimeOrigin::root(), [0; 32].into()));
+		Balances::make_free_balance_be(&[0; 32].into(), 4);
+		System::set_block_number(200);
+		assert_ok!(GameModule::request_token(RuntimeOrigin::signed([0; 32].into())));
+		assert_eq!(Balances::free_balance(&([0; 32].into())), 10);
+		Balances::make_free_balance_be(&[0; 32].into(), 50);
+		System::set_block_number(400);
+		assert_ok!(GameModule::request_token(RuntimeOrigin::signed([0; 32].into())));
+		assert_eq!(Balances::free_balance(&([0; 32].into())), 50);
+	});
+}
+
 #[test]
 fn request_token_doesnt_works() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
+		setup_game();
 		assert_noop!(
 			GameModule::request_token(RuntimeOrigin::signed([0; 32].into())),
 			Error::<Test>::UserNotRegistered
 		);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[4; 32].into()
+		));
 		assert_ok!(GameModule::register_user(
 			RuntimeOrigin::signed([4; 32].into()),
 			[0; 32].into()
@@ -1207,50 +1512,1632 @@ fn request_token_doesnt_works() {
 }
 
 #[test]
-fn check_result_fails() {
+fn result_is_checked_once_the_round_is_revealed() {
 	new_test_ext().execute_with(|| {
 		System::set_block_number(1);
-		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
-		assert_eq!(GameModule::game_properties().len(), 4);
-		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), [4; 32].into()));
+		setup_game();
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[4; 32].into()
+		));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		practise_round([0; 32].into(), 0);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 223_000, 1));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 225_000, 1));
+		let round = GameModule::game_info(1).unwrap().property.round;
+		assert_eq!(GameModule::pending_reveals().into_inner(), vec![(round, 1)]);
+		MockTimelock::set_latest_round(round - 1);
+		run_to_block(20);
+		assert_eq!(GameModule::game_info(1).unwrap().guess, Some(225_000));
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 55);
+		reveal(1);
+		System::assert_last_event(
+			Event::ResultChecked {
+				game_id: 1,
+				player: [0; 32].into(),
+				property_id: PROPERTY_IDS[0],
+				difficulty: crate::DifficultyLevel::Player,
+				price: 220_000,
+				band: Some(30),
+				points: PointsDelta::Gained(25),
+				total_points: 80,
+				nft: None,
+			}
+			.into(),
+		);
+		assert!(GameModule::game_info(1).is_none());
+		assert!(GameModule::pending_reveals().is_empty());
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 80);
+	});
+}
+
+#[test]
+fn submit_answer_fails_after_reveal() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game();
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[4; 32].into()
+		));
 		assert_ok!(GameModule::register_user(
 			RuntimeOrigin::signed([4; 32].into()),
 			[0; 32].into()
 		));
 		practise_round([0; 32].into(), 0);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		MockTimelock::set_latest_round(GameModule::game_info(1).unwrap().property.round);
 		assert_noop!(
-			GameModule::check_result(
-				RuntimeOrigin::root(),
-				223_000,
-				0,
-				220_000,
-				"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+			GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1),
+			Error::<Test>::PriceRevealed
+		);
+	});
+}
+
+#[test]
+fn play_game_skips_revealed_properties() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game();
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[4; 32].into()
+		));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		let round = GameModule::game_properties()[0].round;
+		assert_ok!(GameModule::add_property(RuntimeOrigin::root(), property(1, round + 1)));
+		MockTimelock::set_latest_round(round - <RevealDelay as Get<RoundNumber>>::get());
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Practice,
+		));
+		assert_eq!(GameModule::game_info(0).unwrap().property.id, 1);
+		assert_eq!(GameModule::game_properties().len(), 1);
+		MockTimelock::set_latest_round(round);
+		assert_noop!(
+			GameModule::play_game(
+				RuntimeOrigin::signed([0; 32].into()),
+				crate::DifficultyLevel::Practice,
 			),
-			Error::<Test>::NoActiveGame
+			Error::<Test>::NoProperty
+		);
+	});
+}
+
+#[test]
+fn undecryptable_price_voids_game() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game();
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[4; 32].into()
+		));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		practise_round([0; 32].into(), 0);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		crate::GameInfo::<Test>::mutate(1, |game_info| {
+			game_info.as_mut().unwrap().property.price = vec![1].try_into().unwrap();
+		});
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
+		reveal(1);
+		System::assert_last_event(Event::RevealFailed { game_id: 1 }.into());
+		assert!(GameModule::game_info(1).is_none());
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 55);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().wins, 0);
+	});
+}
+
+fn scoring_with_player(player: DifficultyScoring) -> ScoringTable {
+	ScoringTable { player, ..Default::default() }
+}
+
+fn band(max_difference: u16, points: u32, won: bool) -> ScoreBand {
+	ScoreBand { max_difference, points, won, nft_chance: Percent::zero() }
+}
+
+#[test]
+fn set_scoring_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game();
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[4; 32].into()
+		));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		practise_round([0; 32].into(), 0);
+		let scoring = scoring_with_player(DifficultyScoring {
+			entry_points: 10,
+			expiry_blocks: 3,
+			bands: vec![band(5, 100, true), band(30, 40, true), band(60, 20, false)]
+				.try_into()
+				.unwrap(),
+			miss_penalty: 30,
+		});
+		assert_ok!(GameModule::set_scoring(RuntimeOrigin::root(), scoring.clone()));
+		System::assert_last_event(Event::ScoringUpdated.into());
+		assert_eq!(GameModule::scoring(), scoring);
+		let block = System::block_number();
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		System::assert_last_event(
+			Event::GameStarted { player: [0; 32].into(), game_id: 1, ending_block: block + 3 }
+				.into(),
+		);
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 223_000, 1));
+		reveal(1);
+		System::assert_last_event(
+			Event::ResultChecked {
+				game_id: 1,
+				player: [0; 32].into(),
+				property_id: PROPERTY_IDS[0],
+				difficulty: crate::DifficultyLevel::Player,
+				price: 220_000,
+				band: Some(30),
+				points: PointsDelta::Gained(40),
+				total_points: 95,
+				nft: None,
+			}
+			.into(),
+		);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 95);
+		assert_ok!(GameModule::play_game(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player,
+		));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 300_000, 2));
+		reveal(2);
+		System::assert_last_event(
+			Event::ResultChecked {
+				game_id: 2,
+				player: [0; 32].into(),
+				property_id: PROPERTY_IDS[0],
+				difficulty: crate::DifficultyLevel::Player,
+				price: 220_000,
+				band: None,
+				points: PointsDelta::Lost(30),
+				total_points: 65,
+				nft: None,
+			}
+			.into(),
 		);
+		let user = GameModule::users::<AccountId>([0; 32].into()).unwrap();
+		assert_eq!(user.points, 65);
+		assert_eq!(user.wins, 1);
+		assert_eq!(user.losses, 1);
+	});
+}
+
+#[test]
+fn set_scoring_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
 		assert_noop!(
-			GameModule::check_result(
-				RuntimeOrigin::root(),
-				223_000,
-				1,
-				220_000,
-				"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+			GameModule::set_scoring(RuntimeOrigin::signed([0; 32].into()), Default::default()),
+			BadOrigin
+		);
+		let unordered = DifficultyScoring {
+			entry_points: 10,
+			expiry_blocks: 3,
+			bands: vec![band(30, 40, true), band(30, 20, false)].try_into().unwrap(),
+			miss_penalty: 30,
+		};
+		assert_noop!(
+			GameModule::set_scoring(RuntimeOrigin::root(), scoring_with_player(unordered.clone())),
+			Error::<Test>::InvalidScoring
+		);
+		let empty = DifficultyScoring { bands: Default::default(), ..unordered };
+		assert_noop!(
+			GameModule::set_scoring(RuntimeOrigin::root(), scoring_with_player(empty)),
+			Error::<Test>::InvalidScoring
+		);
+	});
+}
+
+#[test]
+fn scoring_limits_entry() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game();
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[4; 32].into()
+		));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		let mut scoring = ScoringTable { practise_round_limit: 1, ..Default::default() };
+		scoring.pro.entry_points = 60;
+		assert_ok!(GameModule::set_scoring(RuntimeOrigin::root(), scoring));
+		practise_round([0; 32].into(), 0);
+		assert_noop!(
+			GameModule::play_game(
+				RuntimeOrigin::signed([0; 32].into()),
+				crate::DifficultyLevel::Practice,
 			),
-			Error::<Test>::NoActiveGame
+			Error::<Test>::TooManyPractise
+		);
+		assert_noop!(
+			GameModule::play_game(
+				RuntimeOrigin::signed([0; 32].into()),
+				crate::DifficultyLevel::Pro,
+			),
+			Error::<Test>::NotEnoughPoints
 		);
 		assert_ok!(GameModule::play_game(
 			RuntimeOrigin::signed([0; 32].into()),
 			crate::DifficultyLevel::Player,
 		));
+	});
+}
+
+#[test]
+fn scheduled_round_starts_and_ends() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(GameModule::schedule_round(
+			RuntimeOrigin::root(),
+			5,
+			30,
+			vec![1_000, 500].try_into().unwrap()
+		));
+		System::assert_last_event(Event::RoundScheduled { start: 5, end: 30 }.into());
+		run_to_block(5);
+		System::assert_has_event(Event::RoundStarted { round: 1, end: Some(30) }.into());
+		assert!(GameModule::next_round().is_none());
+		assert!(GameModule::round_active());
+		assert_eq!(GameModule::round_collections(1).into_inner(), (0..8).collect::<Vec<_>>());
+		lock_properties();
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[4; 32].into()
+		));
+		for player in [[0; 32], [1; 32], [2; 32]] {
+			assert_ok!(GameModule::register_user(
+				RuntimeOrigin::signed([4; 32].into()),
+				player.into()
+			));
+		}
+		practise_round([0; 32].into(), 0);
+		practise_round([1; 32].into(), 1);
+		practise_round([1; 32].into(), 2);
+		practise_round([2; 32].into(), 3);
+		let pallet_balance = Balances::free_balance(GameModule::account_id());
+		let first_balance = Balances::free_balance(AccountId::from([1; 32]));
+		let second_balance = Balances::free_balance(AccountId::from([0; 32]));
+		run_to_block(30);
+		System::assert_has_event(
+			Event::PrizePaid { round: 1, winner: [1; 32].into(), amount: 1_000 }.into(),
+		);
+		System::assert_has_event(
+			Event::PrizePaid { round: 1, winner: [0; 32].into(), amount: 500 }.into(),
+		);
+		System::assert_last_event(
+			Event::RoundEnded { round: 1, champion: Some([1; 32].into()) }.into(),
+		);
+		assert_eq!(Balances::free_balance(GameModule::account_id()), pallet_balance - 1_500);
+		assert_eq!(Balances::free_balance(AccountId::from([1; 32])), first_balance + 1_000);
+		assert_eq!(Balances::free_balance(AccountId::from([0; 32])), second_balance + 500);
+		assert!(!GameModule::round_active());
+		assert_eq!(GameModule::round_champion(1), Some([1; 32].into()));
+		assert_eq!(
+			GameModule::round_archive(1).into_inner(),
+			vec![([1; 32].into(), 60), ([0; 32].into(), 55), ([2; 32].into(), 55)]
+		);
+		assert!(GameModule::leaderboard().is_empty());
+	});
+}
+
+#[test]
+fn schedule_round_fails() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(10);
 		assert_noop!(
-			GameModule::check_result(
-				RuntimeOrigin::root(),
-				223_000,
-				1,
-				220_000,
-				"nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap()
+			GameModule::schedule_round(
+				RuntimeOrigin::signed([0; 32].into()),
+				20,
+				30,
+				Default::default()
 			),
-			Error::<Test>::NoGuess
+			BadOrigin
+		);
+		assert_noop!(
+			GameModule::schedule_round(RuntimeOrigin::root(), 10, 30, Default::default()),
+			Error::<Test>::InvalidSchedule
+		);
+		assert_noop!(
+			GameModule::schedule_round(RuntimeOrigin::root(), 20, 20, Default::default()),
+			Error::<Test>::InvalidSchedule
+		);
+		assert_ok!(GameModule::schedule_round(RuntimeOrigin::root(), 20, 30, Default::default()));
+		assert_noop!(
+			GameModule::schedule_round(RuntimeOrigin::root(), 40, 50, Default::default()),
+			Error::<Test>::RoundAlreadyScheduled
+		);
+	});
+}
+
+#[test]
+fn cancel_scheduled_round_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_noop!(
+			GameModule::cancel_scheduled_round(RuntimeOrigin::root()),
+			Error::<Test>::NoRoundScheduled
+		);
+		assert_ok!(GameModule::schedule_round(RuntimeOrigin::root(), 5, 30, Default::default()));
+		assert_ok!(GameModule::cancel_scheduled_round(RuntimeOrigin::root()));
+		System::assert_last_event(Event::ScheduledRoundCancelled.into());
+		run_to_block(5);
+		assert!(!GameModule::round_active());
+		assert_eq!(GameModule::current_round(), 0);
+	});
+}
+
+#[test]
+fn next_round_resets_points_and_archives_them() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game();
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[4; 32].into()
+		));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		practise_round([0; 32].into(), 0);
+		assert_ok!(GameModule::give_points(RuntimeOrigin::root(), [0; 32].into(), 45));
+		assert_ok!(GameModule::end_round(RuntimeOrigin::root()));
+		System::assert_last_event(
+			Event::RoundEnded { round: 1, champion: Some([0; 32].into()) }.into(),
+		);
+		assert_noop!(GameModule::end_round(RuntimeOrigin::root()), Error::<Test>::NoActiveRound);
+		assert_ok!(GameModule::schedule_round(RuntimeOrigin::root(), 5, 30, Default::default()));
+		run_to_block(5);
+		assert_eq!(GameModule::current_round(), 2);
+		assert_eq!(GameModule::round_collections(2).into_inner(), (8..16).collect::<Vec<_>>());
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 100);
+		lock_properties();
+		practise_round([0; 32].into(), 1);
+		assert_eq!(GameModule::round_points(1, AccountId::from([0; 32])), Some(100));
+		let user = GameModule::users::<AccountId>([0; 32].into()).unwrap();
+		assert_eq!(user.points, 55);
+		assert_eq!(user.last_played_round, 2);
+		assert_eq!(GameModule::leaderboard().into_inner(), vec![([0; 32].into(), 55)]);
+	});
+}
+
+/// Registers two players and lets the first one win the nft `(0, 0)`.
+fn setup_marketplace() {
+	System::set_block_number(1);
+	setup_game();
+	assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+	assert_ok!(GameModule::register_user(RuntimeOrigin::signed([4; 32].into()), [0; 32].into()));
+	assert_ok!(GameModule::register_user(RuntimeOrigin::signed([4; 32].into()), [1; 32].into()));
+	practise_round([0; 32].into(), 0);
+	assert_ok!(GameModule::play_game(
+		RuntimeOrigin::signed([0; 32].into()),
+		crate::DifficultyLevel::Player,
+	));
+	assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
+	reveal(1);
+	assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
+	Balances::make_free_balance_be(&[0; 32].into(), 100);
+	Balances::make_free_balance_be(&[1; 32].into(), 1_000);
+}
+
+#[test]
+fn buy_nft_works() {
+	new_test_ext().execute_with(|| {
+		setup_marketplace();
+		let seller_points = GameModule::users::<AccountId>([0; 32].into()).unwrap().points;
+		let buyer_points = GameModule::users::<AccountId>([1; 32].into()).unwrap().points;
+		let pallet_balance = Balances::free_balance(GameModule::account_id());
+		assert_ok!(GameModule::list_nft(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			0,
+			ListingPrice::Fixed(500),
+		));
+		System::assert_last_event(
+			Event::NftListed {
+				owner: [0; 32].into(),
+				listing_id: 0,
+				collection_id: 0,
+				item_id: 0,
+				price: ListingPrice::Fixed(500),
+				expiry: 103,
+			}
+			.into(),
+		);
+		assert_ok!(GameModule::buy_nft(RuntimeOrigin::signed([1; 32].into()), 0));
+		System::assert_last_event(
+			Event::NftSold {
+				listing_id: 0,
+				seller: [0; 32].into(),
+				buyer: [1; 32].into(),
+				price: 500,
+				fee: 50,
+			}
+			.into(),
+		);
+		assert_eq!(Nfts::owner(0, 0).unwrap(), [1; 32].into());
+		assert!(GameModule::listings(0).is_none());
+		assert_eq!(Balances::free_balance(AccountId::from([1; 32])), 500);
+		assert_eq!(Balances::free_balance(AccountId::from([0; 32])), 550);
+		assert_eq!(Balances::free_balance(GameModule::account_id()), pallet_balance + 50);
+		let seller = GameModule::users::<AccountId>([0; 32].into()).unwrap();
+		let buyer = GameModule::users::<AccountId>([1; 32].into()).unwrap();
+		assert_eq!(seller.nfts.xorange, 0);
+		assert_eq!(seller.points, seller_points - 100);
+		assert_eq!(buyer.nfts.xorange, 1);
+		assert_eq!(buyer.points, buyer_points + 100);
+		assert_noop!(
+			Nfts::transfer(
+				RuntimeOrigin::signed([1; 32].into()),
+				0,
+				0,
+				sp_runtime::MultiAddress::Id([0; 32].into())
+			),
+			DispatchError::Module(ModuleError {
+				index: 3,
+				error: [12, 0, 0, 0],
+				message: Some("ItemLocked")
+			})
+		);
+	});
+}
+
+#[test]
+fn buy_nft_fails() {
+	new_test_ext().execute_with(|| {
+		setup_marketplace();
+		assert_ok!(GameModule::list_nft(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			0,
+			ListingPrice::MinimumBid(500),
+		));
+		assert_noop!(
+			GameModule::buy_nft(RuntimeOrigin::signed([1; 32].into()), 0),
+			Error::<Test>::NotForSale
+		);
+		assert_ok!(GameModule::delist_nft(RuntimeOrigin::signed([0; 32].into()), 0));
+		assert_ok!(GameModule::list_nft(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			0,
+			ListingPrice::Fixed(2_000),
+		));
+		assert_noop!(
+			GameModule::buy_nft(RuntimeOrigin::signed([0; 32].into()), 1),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			GameModule::buy_nft(RuntimeOrigin::signed([1; 32].into()), 1),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+		run_to_block(104);
+		assert_noop!(
+			GameModule::buy_nft(RuntimeOrigin::signed([1; 32].into()), 1),
+			Error::<Test>::ListingExpired
+		);
+		assert_noop!(
+			GameModule::buy_nft(RuntimeOrigin::signed([1; 32].into()), 2),
+			Error::<Test>::ListingDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn expired_listing_can_be_delisted_by_anyone() {
+	new_test_ext().execute_with(|| {
+		setup_marketplace();
+		assert_ok!(GameModule::list_nft(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			0,
+			ListingPrice::Fixed(500),
+		));
+		assert_noop!(
+			GameModule::delist_nft(RuntimeOrigin::signed([1; 32].into()), 0),
+			Error::<Test>::NoPermission
+		);
+		run_to_block(104);
+		assert_ok!(GameModule::delist_nft(RuntimeOrigin::signed([1; 32].into()), 0));
+		System::assert_last_event(
+			Event::NftDelisted { owner: [0; 32].into(), collection_id: 0, item_id: 0 }.into(),
+		);
+		assert_eq!(Nfts::owner(0, 0).unwrap(), [0; 32].into());
+		assert!(GameModule::listings(0).is_none());
+	});
+}
+
+#[test]
+fn currency_offer_works() {
+	new_test_ext().execute_with(|| {
+		setup_marketplace();
+		let seller_points = GameModule::users::<AccountId>([0; 32].into()).unwrap().points;
+		let buyer_points = GameModule::users::<AccountId>([1; 32].into()).unwrap().points;
+		let pallet_balance = Balances::free_balance(GameModule::account_id());
+		assert_ok!(GameModule::list_nft(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			0,
+			ListingPrice::MinimumBid(300),
+		));
+		assert_ok!(GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 0, None, 400));
+		System::assert_last_event(
+			Event::OfferMade {
+				owner: [1; 32].into(),
+				listing_id: 0,
+				offer_id: 0,
+				nft: None,
+				amount: 400,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::reserved_balance(AccountId::from([1; 32])), 400);
+		assert_ok!(GameModule::handle_offer(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			crate::Offer::Accept,
+		));
+		assert_eq!(Nfts::owner(0, 0).unwrap(), [1; 32].into());
+		assert!(GameModule::offers(0).is_none());
+		assert!(GameModule::listings(0).is_none());
+		assert_eq!(Balances::reserved_balance(AccountId::from([1; 32])), 0);
+		assert_eq!(Balances::free_balance(AccountId::from([1; 32])), 600);
+		assert_eq!(Balances::free_balance(AccountId::from([0; 32])), 460);
+		assert_eq!(Balances::free_balance(GameModule::account_id()), pallet_balance + 40);
+		assert_eq!(
+			GameModule::users::<AccountId>([0; 32].into()).unwrap().points,
+			seller_points - 100
+		);
+		assert_eq!(
+			GameModule::users::<AccountId>([1; 32].into()).unwrap().points,
+			buyer_points + 100
+		);
+	});
+}
+
+#[test]
+fn rejected_offer_is_refunded() {
+	new_test_ext().execute_with(|| {
+		setup_marketplace();
+		assert_ok!(GameModule::list_nft(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			0,
+			ListingPrice::MinimumBid(300),
+		));
+		assert_ok!(GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 0, None, 300));
+		assert_ok!(GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 0, None, 350));
+		assert_eq!(Balances::reserved_balance(AccountId::from([1; 32])), 650);
+		assert_ok!(GameModule::handle_offer(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			crate::Offer::Reject,
+		));
+		assert_ok!(GameModule::withdraw_offer(RuntimeOrigin::signed([1; 32].into()), 1));
+		assert_eq!(Balances::reserved_balance(AccountId::from([1; 32])), 0);
+		assert_eq!(Balances::free_balance(AccountId::from([1; 32])), 1_000);
+		assert!(GameModule::listings(0).is_some());
+	});
+}
+
+#[test]
+fn make_offer_fails_for_invalid_bids() {
+	new_test_ext().execute_with(|| {
+		setup_marketplace();
+		assert_ok!(GameModule::list_nft(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			0,
+			ListingPrice::MinimumBid(300),
+		));
+		assert_noop!(
+			GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 0, None, 299),
+			Error::<Test>::BidTooLow
+		);
+		assert_noop!(
+			GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 0, None, 2_000),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+		assert_ok!(GameModule::delist_nft(RuntimeOrigin::signed([0; 32].into()), 0));
+		assert_ok!(GameModule::list_nft(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			0,
+			ListingPrice::MinimumBid(0),
+		));
+		assert_noop!(
+			GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 1, None, 0),
+			Error::<Test>::EmptyOffer
+		);
+		run_to_block(104);
+		assert_noop!(
+			GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 1, None, 300),
+			Error::<Test>::ListingExpired
+		);
+		assert_ok!(GameModule::delist_nft(RuntimeOrigin::signed([0; 32].into()), 1));
+		assert_ok!(GameModule::list_nft(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			0,
+			ListingPrice::Fixed(300),
+		));
+		assert_noop!(
+			GameModule::make_offer(RuntimeOrigin::signed([1; 32].into()), 2, None, 300),
+			Error::<Test>::NoOffersAccepted
+		);
+	});
+}
+
+const INVITE_CODE: [u8; 32] = [7; 32];
+
+fn invite() -> <Test as frame_system::Config>::Hash {
+	<Test as frame_system::Config>::Hashing::hash(&INVITE_CODE)
+}
+
+/// Registers the players and lets them skip the practise round.
+fn setup_lobby_players(players: u8) {
+	System::set_block_number(1);
+	setup_game();
+	assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+	for player in 0..players {
+		let player = AccountId::from([player; 32]);
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			player.clone()
+		));
+		crate::Users::<Test>::mutate(player, |user| user.as_mut().unwrap().practise_rounds = 1);
+	}
+}
+
+fn open_lobby(capacity: u32) {
+	assert_ok!(GameModule::create_lobby(
+		RuntimeOrigin::signed([0; 32].into()),
+		crate::DifficultyLevel::Player,
+		capacity,
+		invite(),
+	));
+}
+
+fn join_lobby(player: u8) {
+	assert_ok!(GameModule::join_lobby(RuntimeOrigin::signed([player; 32].into()), 0, INVITE_CODE));
+}
+
+fn seal_guess(player: u8, lobby_id: u32, guess: u32) {
+	assert_ok!(GameModule::submit_sealed_guess(
+		RuntimeOrigin::signed([player; 32].into()),
+		lobby_id,
+		MockTimelock::lock_price(guess).try_into().unwrap(),
+	));
+}
+
+fn lobby_round(lobby_id: u32) -> RoundNumber {
+	match GameModule::lobbies(lobby_id).unwrap().state {
+		crate::LobbyState::Guessing { property, .. } => property.round,
+		crate::LobbyState::Open { .. } => panic!("lobby has not started"),
+	}
+}
+
+fn reveal_lobby(lobby_id: u32) {
+	MockTimelock::set_latest_round(lobby_round(lobby_id));
+	run_to_block(System::block_number() + 1);
+}
+
+fn points(player: u8) -> u32 {
+	GameModule::users(AccountId::from([player; 32])).unwrap().points
+}
+
+#[test]
+fn lobby_with_invite_code_works() {
+	new_test_ext().execute_with(|| {
+		setup_lobby_players(3);
+		open_lobby(3);
+		System::assert_last_event(
+			Event::LobbyCreated {
+				lobby_id: 0,
+				host: [0; 32].into(),
+				capacity: 3,
+				entry_points: 25,
+			}
+			.into(),
+		);
+		assert_noop!(
+			GameModule::join_lobby(RuntimeOrigin::signed([1; 32].into()), 0, [8; 32]),
+			Error::<Test>::WrongInviteCode
+		);
+		join_lobby(1);
+		System::assert_last_event(
+			Event::LobbyJoined { lobby_id: 0, player: [1; 32].into() }.into(),
+		);
+		assert_noop!(
+			GameModule::join_lobby(RuntimeOrigin::signed([1; 32].into()), 0, INVITE_CODE),
+			Error::<Test>::AlreadyInLobby
+		);
+		join_lobby(2);
+		System::assert_last_event(
+			Event::LobbyStarted { lobby_id: 0, players: 3, ending_block: 9 }.into(),
+		);
+		assert_eq!(points(0), 25);
+		assert_eq!(points(2), 25);
+		seal_guess(0, 0, PRICE);
+		seal_guess(1, 0, 1_000);
+		System::assert_last_event(
+			Event::SealedGuessSubmitted { lobby_id: 0, player: [1; 32].into() }.into(),
+		);
+		assert_noop!(
+			GameModule::submit_sealed_guess(
+				RuntimeOrigin::signed([3; 32].into()),
+				0,
+				MockTimelock::lock_price(PRICE).try_into().unwrap(),
+			),
+			Error::<Test>::NotInLobby
+		);
+		reveal_lobby(0);
+		System::assert_last_event(
+			Event::LobbySettled { lobby_id: 0, winner: [0; 32].into(), price: PRICE, pot: 75 }
+				.into(),
+		);
+		assert_eq!(points(0), 100);
+		assert_eq!(points(1), 25);
+		assert_eq!(points(2), 25);
+		assert_eq!(GameModule::users(AccountId::from([0; 32])).unwrap().wins, 1);
+		assert_eq!(GameModule::users(AccountId::from([1; 32])).unwrap().losses, 1);
+		assert_eq!(GameModule::users(AccountId::from([2; 32])).unwrap().losses, 1);
+		assert!(GameModule::lobbies(0).is_none());
+		assert!(GameModule::player_lobby(AccountId::from([0; 32])).is_none());
+		assert!(GameModule::lobby_guesses(0, AccountId::from([0; 32])).is_none());
+	});
+}
+
+#[test]
+fn create_lobby_fails() {
+	new_test_ext().execute_with(|| {
+		setup_lobby_players(1);
+		for capacity in [1, 9] {
+			assert_noop!(
+				GameModule::create_lobby(
+					RuntimeOrigin::signed([0; 32].into()),
+					crate::DifficultyLevel::Player,
+					capacity,
+					invite(),
+				),
+				Error::<Test>::InvalidLobbySize
+			);
+		}
+		assert_noop!(
+			GameModule::create_lobby(
+				RuntimeOrigin::signed([0; 32].into()),
+				crate::DifficultyLevel::Practice,
+				2,
+				invite(),
+			),
+			Error::<Test>::InvalidLobbyDifficulty
+		);
+		crate::Users::<Test>::mutate(AccountId::from([0; 32]), |user| {
+			user.as_mut().unwrap().points = 20
+		});
+		assert_noop!(
+			GameModule::create_lobby(
+				RuntimeOrigin::signed([0; 32].into()),
+				crate::DifficultyLevel::Player,
+				2,
+				invite(),
+			),
+			Error::<Test>::NotEnoughPoints
+		);
+		assert_ok!(GameModule::give_points(RuntimeOrigin::root(), [0; 32].into(), 30));
+		open_lobby(2);
+		assert_noop!(
+			GameModule::create_lobby(
+				RuntimeOrigin::signed([0; 32].into()),
+				crate::DifficultyLevel::Player,
+				2,
+				invite(),
+			),
+			Error::<Test>::AlreadyInLobby
+		);
+	});
+}
+
+#[test]
+fn leave_lobby_refunds_entry_points() {
+	new_test_ext().execute_with(|| {
+		setup_lobby_players(3);
+		open_lobby(4);
+		join_lobby(1);
+		assert_eq!(points(1), 25);
+		assert_noop!(
+			GameModule::leave_lobby(RuntimeOrigin::signed([2; 32].into()), 0),
+			Error::<Test>::NotInLobby
+		);
+		assert_ok!(GameModule::leave_lobby(RuntimeOrigin::signed([1; 32].into()), 0));
+		System::assert_last_event(Event::LobbyLeft { lobby_id: 0, player: [1; 32].into() }.into());
+		assert_eq!(points(1), 50);
+		assert!(GameModule::player_lobby(AccountId::from([1; 32])).is_none());
+		join_lobby(1);
+		join_lobby(2);
+		assert_ok!(GameModule::leave_lobby(RuntimeOrigin::signed([0; 32].into()), 0));
+		System::assert_last_event(Event::LobbyCancelled { lobby_id: 0 }.into());
+		assert_eq!((points(0), points(1), points(2)), (50, 50, 50));
+		assert!(GameModule::lobbies(0).is_none());
+		assert!(GameModule::player_lobby(AccountId::from([2; 32])).is_none());
+		assert_noop!(
+			GameModule::leave_lobby(RuntimeOrigin::signed([0; 32].into()), 0),
+			Error::<Test>::LobbyDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn host_can_start_lobby_early() {
+	new_test_ext().execute_with(|| {
+		setup_lobby_players(3);
+		open_lobby(4);
+		assert_noop!(
+			GameModule::start_lobby(RuntimeOrigin::signed([0; 32].into()), 0),
+			Error::<Test>::InvalidLobbySize
+		);
+		assert_noop!(
+			GameModule::submit_sealed_guess(
+				RuntimeOrigin::signed([0; 32].into()),
+				0,
+				MockTimelock::lock_price(PRICE).try_into().unwrap(),
+			),
+			Error::<Test>::LobbyNotStarted
+		);
+		join_lobby(1);
+		assert_noop!(
+			GameModule::start_lobby(RuntimeOrigin::signed([1; 32].into()), 0),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(GameModule::start_lobby(RuntimeOrigin::signed([0; 32].into()), 0));
+		System::assert_last_event(
+			Event::LobbyStarted { lobby_id: 0, players: 2, ending_block: 9 }.into(),
+		);
+		assert_noop!(
+			GameModule::join_lobby(RuntimeOrigin::signed([2; 32].into()), 0, INVITE_CODE),
+			Error::<Test>::LobbyNotOpen
+		);
+		assert_noop!(
+			GameModule::leave_lobby(RuntimeOrigin::signed([1; 32].into()), 0),
+			Error::<Test>::LobbyNotOpen
+		);
+	});
+}
+
+#[test]
+fn queue_for_lobby_matches_players() {
+	new_test_ext().execute_with(|| {
+		setup_lobby_players(2);
+		assert_ok!(GameModule::queue_for_lobby(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player
+		));
+		System::assert_last_event(
+			Event::QueuedForLobby { player: [0; 32].into(), entry_points: 25 }.into(),
+		);
+		assert_eq!(points(0), 25);
+		assert_noop!(
+			GameModule::queue_for_lobby(
+				RuntimeOrigin::signed([0; 32].into()),
+				crate::DifficultyLevel::Player
+			),
+			Error::<Test>::AlreadyInLobby
+		);
+		assert_ok!(GameModule::queue_for_lobby(
+			RuntimeOrigin::signed([1; 32].into()),
+			crate::DifficultyLevel::Player
+		));
+		System::assert_last_event(
+			Event::LobbyStarted { lobby_id: 0, players: 2, ending_block: 9 }.into(),
+		);
+		assert_eq!(GameModule::player_lobby(AccountId::from([0; 32])), Some(0));
+		assert_eq!(GameModule::player_lobby(AccountId::from([1; 32])), Some(0));
+		assert!(GameModule::matchmaking_entries(AccountId::from([0; 32])).is_none());
+		assert_eq!(GameModule::lobbies(0).unwrap().pot(), 50);
+	});
+}
+
+#[test]
+fn queue_for_lobby_only_matches_the_same_difficulty() {
+	new_test_ext().execute_with(|| {
+		setup_lobby_players(3);
+		assert_noop!(
+			GameModule::queue_for_lobby(
+				RuntimeOrigin::signed([0; 32].into()),
+				crate::DifficultyLevel::Practice
+			),
+			Error::<Test>::InvalidLobbyDifficulty
+		);
+		assert_ok!(GameModule::queue_for_lobby(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player
+		));
+		assert_ok!(GameModule::queue_for_lobby(
+			RuntimeOrigin::signed([1; 32].into()),
+			crate::DifficultyLevel::Pro
+		));
+		assert_eq!(points(1), 0);
+		assert!(GameModule::lobbies(0).is_none());
+		assert_ok!(GameModule::queue_for_lobby(
+			RuntimeOrigin::signed([2; 32].into()),
+			crate::DifficultyLevel::Player
+		));
+		let lobby = GameModule::lobbies(0).unwrap();
+		assert_eq!(lobby.difficulty, crate::DifficultyLevel::Player);
+		assert_eq!(lobby.pot(), 50);
+		assert!(GameModule::matchmaking_entries(AccountId::from([1; 32])).is_some());
+	});
+}
+
+#[test]
+fn leave_lobby_queue_refunds_entry_points() {
+	new_test_ext().execute_with(|| {
+		setup_lobby_players(2);
+		assert_noop!(
+			GameModule::leave_lobby_queue(RuntimeOrigin::signed([0; 32].into())),
+			Error::<Test>::NotQueuedForLobby
+		);
+		assert_ok!(GameModule::queue_for_lobby(
+			RuntimeOrigin::signed([0; 32].into()),
+			crate::DifficultyLevel::Player
+		));
+		assert_eq!(points(0), 25);
+		assert_ok!(GameModule::leave_lobby_queue(RuntimeOrigin::signed([0; 32].into())));
+		System::assert_last_event(
+			Event::LeftLobbyQueue { player: [0; 32].into(), entry_points: 25 }.into(),
+		);
+		assert_eq!(points(0), 50);
+		assert!(GameModule::matchmaking_entries(AccountId::from([0; 32])).is_none());
+		// The player is no longer matched with the next one in the queue.
+		assert_ok!(GameModule::queue_for_lobby(
+			RuntimeOrigin::signed([1; 32].into()),
+			crate::DifficultyLevel::Player
+		));
+		assert!(GameModule::lobbies(0).is_none());
+	});
+}
+
+#[test]
+fn tied_lobby_guesses_are_broken_by_randomness() {
+	new_test_ext().execute_with(|| {
+		setup_lobby_players(2);
+		open_lobby(2);
+		join_lobby(1);
+		seal_guess(0, 0, PRICE + 1_000);
+		seal_guess(1, 0, PRICE - 1_000);
+		reveal_lobby(0);
+		let winner = System::events()
+			.into_iter()
+			.find_map(|record| match record.event {
+				RuntimeEvent::GameModule(Event::LobbySettled { winner, pot, .. }) => {
+					assert_eq!(pot, 50);
+					Some(winner)
+				},
+				_ => None,
+			})
+			.unwrap();
+		assert!([AccountId::from([0; 32]), AccountId::from([1; 32])].contains(&winner));
+		assert_eq!(GameModule::users(winner).unwrap().points, 75);
+		assert_eq!(points(0) + points(1), 100);
+	});
+}
+
+#[test]
+fn lobby_without_guesses_is_voided() {
+	new_test_ext().execute_with(|| {
+		setup_lobby_players(2);
+		open_lobby(2);
+		join_lobby(1);
+		reveal_lobby(0);
+		System::assert_last_event(Event::LobbyVoided { lobby_id: 0 }.into());
+		assert_eq!((points(0), points(1)), (50, 50));
+		assert!(GameModule::lobbies(0).is_none());
+		assert!(GameModule::player_lobby(AccountId::from([1; 32])).is_none());
+	});
+}
+
+#[test]
+fn submit_sealed_guess_fails_after_guessing_closed() {
+	new_test_ext().execute_with(|| {
+		setup_lobby_players(2);
+		open_lobby(2);
+		join_lobby(1);
+		MockTimelock::set_latest_round(lobby_round(0));
+		assert_noop!(
+			GameModule::submit_sealed_guess(
+				RuntimeOrigin::signed([0; 32].into()),
+				0,
+				MockTimelock::lock_price(PRICE).try_into().unwrap(),
+			),
+			Error::<Test>::PriceRevealed
+		);
+		MockTimelock::set_latest_round(0);
+		run_to_block(10);
+		assert_noop!(
+			GameModule::submit_sealed_guess(
+				RuntimeOrigin::signed([0; 32].into()),
+				0,
+				MockTimelock::lock_price(PRICE).try_into().unwrap(),
+			),
+			Error::<Test>::GuessingClosed
+		);
+	});
+}
+
+/// Registers a player who finished the practise round and starts game 1 for the player.
+fn start_player_game() {
+	System::set_block_number(1);
+	setup_game();
+	assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), Role::Registrar, [4; 32].into()));
+	assert_ok!(GameModule::register_user(RuntimeOrigin::signed([4; 32].into()), [0; 32].into()));
+	practise_round([0; 32].into(), 0);
+	assert_ok!(GameModule::play_game(
+		RuntimeOrigin::signed([0; 32].into()),
+		crate::DifficultyLevel::Player,
+	));
+}
+
+/// Runs the block hooks without cleaning up the finished games.
+fn initialize_block(n: u64) {
+	System::set_block_number(n);
+	GameModule::on_initialize(n);
+}
+
+#[test]
+fn finished_games_are_removed_on_idle() {
+	new_test_ext().execute_with(|| {
+		start_player_game();
+		assert_eq!(GameModule::game_info(1).unwrap().state, GameState::Pending);
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
+		assert_eq!(GameModule::game_info(1).unwrap().state, GameState::Answered);
+		MockTimelock::set_latest_round(GameModule::game_info(1).unwrap().property.round);
+		initialize_block(System::block_number() + 1);
+		assert_eq!(
+			GameModule::game_info(1).unwrap().state,
+			GameState::Scored { points: 25, won: true }
+		);
+		assert_eq!(GameModule::finished_games().into_inner(), vec![1]);
+		assert_noop!(
+			GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1),
+			Error::<Test>::NoActiveGame
+		);
+		assert_eq!(GameModule::on_idle(System::block_number(), Weight::zero()), Weight::zero());
+		assert!(GameModule::game_info(1).is_some());
+		GameModule::on_idle(System::block_number(), Weight::MAX);
+		assert!(GameModule::game_info(1).is_none());
+		assert!(GameModule::finished_games().is_empty());
+	});
+}
+
+#[test]
+fn expired_game_is_scored_as_a_miss() {
+	new_test_ext().execute_with(|| {
+		start_player_game();
+		let expiry = System::block_number() + 8;
+		initialize_block(expiry);
+		System::assert_last_event(
+			Event::NoAnswer { game_id: 1, player: [0; 32].into(), penalty: 25, total_points: 30 }
+				.into(),
+		);
+		assert_eq!(
+			GameModule::game_info(1).unwrap().state,
+			GameState::Scored { points: 25, won: false }
+		);
+		assert_noop!(
+			GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1),
+			Error::<Test>::NoActiveGame
+		);
+	});
+}
+
+#[test]
+fn answered_game_is_refunded_after_settlement_deadline() {
+	new_test_ext().execute_with(|| {
+		start_player_game();
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
+		let settlement_block = GameModule::game_info(1).unwrap().settlement_block;
+		assert_eq!(settlement_block, System::block_number() + 8 + 20);
+		run_to_block(settlement_block - 1);
+		assert_eq!(GameModule::game_info(1).unwrap().state, GameState::Answered);
+		assert_eq!(GameModule::pending_reveals().len(), 1);
+		initialize_block(settlement_block);
+		System::assert_last_event(Event::GameRefunded { game_id: 1 }.into());
+		assert_eq!(GameModule::game_info(1).unwrap().state, GameState::Voided);
+		assert!(GameModule::pending_reveals().is_empty());
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 55);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().losses, 0);
+		// A late reveal doesn't score the voided game anymore.
+		MockTimelock::set_latest_round(GameModule::game_info(1).unwrap().property.round);
+		run_to_block(settlement_block + 1);
+		assert!(GameModule::game_info(1).is_none());
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 55);
+	});
+}
+
+#[test]
+fn answered_game_waits_for_the_signature_of_its_round() {
+	new_test_ext().execute_with(|| {
+		start_player_game();
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
+		let round = GameModule::game_info(1).unwrap().property.round;
+		// The beacon moves past the round without its signature being brought on chain.
+		MockTimelock::skip_round(round);
+		MockTimelock::set_latest_round(round + 1);
+		run_to_block(System::block_number() + 1);
+		assert_eq!(GameModule::game_info(1).unwrap().state, GameState::Answered);
+		assert_eq!(GameModule::pending_reveals().len(), 1);
+		let settlement_block = GameModule::game_info(1).unwrap().settlement_block;
+		run_to_block(settlement_block);
+		System::assert_has_event(Event::GameRefunded { game_id: 1 }.into());
+		assert_eq!(GameModule::users(AccountId::from([0; 32])).unwrap().points, 55);
+	});
+}
+
+#[test]
+fn lobby_waits_for_the_signature_of_its_round() {
+	new_test_ext().execute_with(|| {
+		setup_lobby_players(2);
+		open_lobby(2);
+		join_lobby(1);
+		seal_guess(0, 0, PRICE);
+		let round = lobby_round(0);
+		MockTimelock::skip_round(round);
+		MockTimelock::set_latest_round(round + 1);
+		run_to_block(System::block_number() + 1);
+		assert!(GameModule::lobbies(0).is_some());
+		assert_eq!(GameModule::pending_lobbies().len(), 1);
+		MockTimelock::set_latest_round(round + <RevealDelay as Get<RoundNumber>>::get() + 1);
+		run_to_block(System::block_number() + 1);
+		System::assert_has_event(Event::LobbyVoided { lobby_id: 0 }.into());
+		assert_eq!(points(0), 50);
+		assert_eq!(points(1), 50);
+	});
+}
+
+#[test]
+fn answered_game_is_scored_at_settlement_deadline_once_revealed() {
+	new_test_ext().execute_with(|| {
+		start_player_game();
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
+		let settlement_block = GameModule::game_info(1).unwrap().settlement_block;
+		run_to_block(settlement_block - 1);
+		crate::PendingReveals::<Test>::kill();
+		MockTimelock::set_latest_round(GameModule::game_info(1).unwrap().property.round);
+		initialize_block(settlement_block);
+		assert_eq!(
+			GameModule::game_info(1).unwrap().state,
+			GameState::Scored { points: 25, won: true }
+		);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 80);
+	});
+}
+
+fn play_practice_game() -> sp_runtime::DispatchResult {
+	GameModule::play_game(RuntimeOrigin::signed([0; 32].into()), crate::DifficultyLevel::Practice)
+}
+
+#[test]
+fn properties_are_drawn_without_replacement() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game();
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[4; 32].into()
+		));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		let mut drawn = Vec::new();
+		for game_id in 0..4 {
+			assert_ok!(play_practice_game());
+			drawn.push(GameModule::game_info(game_id).unwrap().property.id);
+		}
+		drawn.sort();
+		let mut property_ids = PROPERTY_IDS.to_vec();
+		property_ids.sort();
+		assert_eq!(drawn, property_ids);
+		assert_eq!(GameModule::game_properties().len(), 4);
+		System::assert_has_event(Event::PropertyPoolLow { remaining: 1 }.into());
+		System::assert_has_event(Event::PropertyPoolLow { remaining: 0 }.into());
+		assert_noop!(play_practice_game(), Error::<Test>::NoProperty);
+	});
+}
+
+#[test]
+fn property_pool_is_refilled() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game();
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[4; 32].into()
+		));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		for _ in 0..4 {
+			assert_ok!(play_practice_game());
+		}
+		assert_noop!(play_practice_game(), Error::<Test>::NoProperty);
+		let round = GameModule::game_properties()[0].round;
+		assert_ok!(GameModule::add_property(RuntimeOrigin::root(), property(1, round)));
+		assert_ok!(play_practice_game());
+		assert_eq!(GameModule::game_info(4).unwrap().property.id, 1);
+		assert_eq!(GameModule::drawn_properties().len(), 5);
+		// A new round puts all properties back into the pool.
+		assert_ok!(GameModule::setup_game(RuntimeOrigin::root()));
+		assert!(GameModule::drawn_properties().is_empty());
+		assert_ok!(play_practice_game());
+	});
+}
+
+/// Test externalities with an offchain worker, a transaction pool and an oracle key.
+///
+/// Also returns a function that takes the last submitted transaction, one that lets the price
+/// source answer with a price for a property, and the account of the oracle key.
+fn oracle_ext() -> (
+	sp_io::TestExternalities,
+	impl Fn() -> Option<Extrinsic>,
+	impl Fn(u32, u32),
+	AccountId,
+) {
+	let (offchain, offchain_state) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	let keystore = MemoryKeystore::new();
+	let public = keystore.sr25519_generate_new(crate::KEY_TYPE, None).unwrap();
+	let mut ext = new_test_ext();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+	ext.register_extension(KeystoreExt::new(keystore));
+	let take_transaction = move || {
+		let tx = pool_state.write().transactions.pop()?;
+		Some(Extrinsic::decode(&mut &*tx).unwrap())
+	};
+	let answer_price = move |property_id: u32, price: u32| {
+		offchain_state.write().expect_request(PendingRequest {
+			method: "GET".into(),
+			uri: format!("http://localhost:8080/prices/{}", property_id),
+			response: Some(format!("{{\"price\":{}}}", price).into_bytes()),
+			sent: true,
+			..Default::default()
+		});
+	};
+	(ext, take_transaction, answer_price, MultiSigner::from(public).into_account())
+}
+
+#[test]
+fn oracle_submits_result_from_price_source() {
+	let (mut ext, take_transaction, answer_price, oracle) = oracle_ext();
+	ext.execute_with(|| {
+		start_player_game();
+		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), Role::ResultsOracle, oracle));
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
+		answer_price(GameModule::game_info(1).unwrap().property.id, PRICE);
+		GameModule::offchain_worker(System::block_number());
+
+		let tx = take_transaction().unwrap();
+		assert!(take_transaction().is_none());
+		assert_eq!(tx.signature, None);
+		assert!(GameModule::validate_unsigned(TransactionSource::External, &tx.call).is_ok());
+		let RuntimeCall::GameModule(crate::Call::submit_result_unsigned { payload, signature }) =
+			tx.call
+		else {
+			panic!("unexpected call");
+		};
+		assert_eq!((payload.game_id, payload.price), (1, PRICE));
+		assert_ok!(GameModule::submit_result_unsigned(RuntimeOrigin::none(), payload, signature));
+		let final_block = GameModule::proposed_results(1).unwrap().final_block;
+		System::assert_last_event(
+			Event::ResultProposed { game_id: 1, price: PRICE, final_block }.into(),
+		);
+		// The price of a game is only looked up once.
+		GameModule::offchain_worker(System::block_number() + 1);
+		assert!(take_transaction().is_none());
+		assert_noop!(
+			GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 200_000, 1),
+			Error::<Test>::PriceRevealed
+		);
+		initialize_block(final_block - 1);
+		assert_eq!(GameModule::game_info(1).unwrap().state, GameState::Answered);
+		initialize_block(final_block);
+		assert_eq!(
+			GameModule::game_info(1).unwrap().state,
+			GameState::Scored { points: 25, won: true }
+		);
+		assert!(GameModule::pending_reveals().is_empty());
+		assert!(GameModule::proposed_results(1).is_none());
+	});
+}
+
+#[test]
+fn unsigned_result_needs_an_oracle_key() {
+	let (mut ext, _, _, oracle) = oracle_ext();
+	ext.execute_with(|| {
+		start_player_game();
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
+		let public = MultiSigner::from(
+			sp_io::crypto::sr25519_public_keys(crate::KEY_TYPE).pop().unwrap(),
+		);
+		let payload = ResultPayload { game_id: 1, price: PRICE, public };
+		let signature =
+			SignedPayload::<Test>::sign::<crate::crypto::OracleAuthId>(&payload).unwrap();
+		let call = crate::Call::submit_result_unsigned {
+			payload: payload.clone(),
+			signature: signature.clone(),
+		};
+		assert_eq!(
+			GameModule::validate_unsigned(TransactionSource::External, &call),
+			InvalidTransaction::BadSigner.into()
+		);
+		assert_ok!(GameModule::add_to_admins(RuntimeOrigin::root(), Role::ResultsOracle, oracle));
+		assert!(GameModule::validate_unsigned(TransactionSource::External, &call).is_ok());
+		let forged = ResultPayload { price: 1, ..payload };
+		let call = crate::Call::submit_result_unsigned { payload: forged, signature };
+		assert_eq!(
+			GameModule::validate_unsigned(TransactionSource::External, &call),
+			InvalidTransaction::BadProof.into()
+		);
+	});
+}
+
+#[test]
+fn disputed_result_waits_for_reveal() {
+	new_test_ext().execute_with(|| {
+		start_player_game();
+		assert_noop!(
+			GameModule::submit_result(RuntimeOrigin::signed([5; 32].into()), 1, PRICE),
+			BadOrigin
+		);
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::ResultsOracle,
+			[5; 32].into()
+		));
+		assert_noop!(
+			GameModule::submit_result(RuntimeOrigin::signed([5; 32].into()), 1, PRICE),
+			Error::<Test>::NoActiveGame
+		);
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
+		assert_ok!(GameModule::submit_result(RuntimeOrigin::signed([5; 32].into()), 1, 100_000));
+		assert_noop!(
+			GameModule::submit_result(RuntimeOrigin::signed([5; 32].into()), 1, PRICE),
+			Error::<Test>::ResultAlreadyProposed
+		);
+		assert_noop!(
+			GameModule::dispute_result(RuntimeOrigin::signed([5; 32].into()), 1),
+			BadOrigin
+		);
+		assert_ok!(GameModule::dispute_result(RuntimeOrigin::root(), 1));
+		System::assert_last_event(Event::ResultDisputed { game_id: 1 }.into());
+		assert_noop!(
+			GameModule::dispute_result(RuntimeOrigin::root(), 1),
+			Error::<Test>::NoProposedResult
+		);
+		let points = GameModule::users::<AccountId>([0; 32].into()).unwrap().points;
+		initialize_block(GameModule::proposed_results(1).unwrap().final_block);
+		assert_eq!(GameModule::game_info(1).unwrap().state, GameState::Answered);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, points);
+		assert_noop!(
+			GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 200_000, 1),
+			Error::<Test>::PriceRevealed
+		);
+		MockTimelock::set_latest_round(GameModule::game_info(1).unwrap().property.round);
+		initialize_block(System::block_number() + 1);
+		assert_eq!(
+			GameModule::game_info(1).unwrap().state,
+			GameState::Scored { points: 25, won: true }
+		);
+		GameModule::on_idle(System::block_number(), Weight::MAX);
+		assert!(GameModule::proposed_results(1).is_none());
+	});
+}
+
+#[test]
+fn result_must_be_final_before_settlement() {
+	new_test_ext().execute_with(|| {
+		start_player_game();
+		assert_ok!(GameModule::submit_answer(RuntimeOrigin::signed([0; 32].into()), 220_000, 1));
+		let settlement_block = GameModule::game_info(1).unwrap().settlement_block;
+		System::set_block_number(settlement_block - DisputeWindow::get());
+		assert_noop!(
+			GameModule::submit_result(RuntimeOrigin::root(), 1, PRICE),
+			Error::<Test>::ResultTooLate
+		);
+		System::set_block_number(settlement_block - DisputeWindow::get() - 1);
+		assert_ok!(GameModule::submit_result(RuntimeOrigin::root(), 1, PRICE));
+	});
+}
+
+/// Mints nfts of the `index`th collection of the round to a player.
+fn collect_nfts(player: AccountId, index: usize, amount: u32) -> Vec<u32> {
+	let collection_id = GameModule::round_collections(GameModule::current_round())[index];
+	(0..amount)
+		.map(|_| {
+			let item_id = GameModule::mint_nft(&player, collection_id).unwrap();
+			assert_ok!(GameModule::add_user_color(&player, collection_id));
+			item_id
+		})
+		.collect()
+}
+
+#[test]
+fn craft_nft_works() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game();
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[4; 32].into()
+		));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		let items = collect_nfts([0; 32].into(), 0, 3);
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 490);
+		assert_ok!(GameModule::craft_nft(
+			RuntimeOrigin::signed([0; 32].into()),
+			0,
+			items.try_into().unwrap()
+		));
+		assert_eq!(Nfts::owner(0, 0), None);
+		assert_eq!(Nfts::owner(1, 0).unwrap(), [0; 32].into());
+		let user = GameModule::users::<AccountId>([0; 32].into()).unwrap();
+		assert_eq!(user.nfts.xorange, 0);
+		assert_eq!(user.nfts.xpink, 1);
+		assert_eq!(user.points, 150);
+		assert_eq!(GameModule::leaderboard()[0], ([0; 32].into(), 150));
+		System::assert_last_event(
+			Event::NftCrafted {
+				player: [0; 32].into(),
+				burned_collection: 0,
+				collection_id: 1,
+				item_id: 0,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn craft_nft_fails_with_wrong_recipe() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game();
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[4; 32].into()
+		));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[1; 32].into()
+		));
+		let items = collect_nfts([0; 32].into(), 0, 3);
+		assert_noop!(
+			GameModule::craft_nft(
+				RuntimeOrigin::signed([0; 32].into()),
+				0,
+				items[..2].to_vec().try_into().unwrap()
+			),
+			Error::<Test>::InvalidRecipe
+		);
+		assert_noop!(
+			GameModule::craft_nft(
+				RuntimeOrigin::signed([0; 32].into()),
+				8,
+				items.clone().try_into().unwrap()
+			),
+			Error::<Test>::CollectionUnknown
+		);
+		assert_noop!(
+			GameModule::craft_nft(
+				RuntimeOrigin::signed([1; 32].into()),
+				0,
+				items.try_into().unwrap()
+			),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn craft_trophy_gives_bonus_points() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		setup_game();
+		assert_ok!(GameModule::add_to_admins(
+			RuntimeOrigin::root(),
+			Role::Registrar,
+			[4; 32].into()
+		));
+		assert_ok!(GameModule::register_user(
+			RuntimeOrigin::signed([4; 32].into()),
+			[0; 32].into()
+		));
+		let items = (0..8)
+			.flat_map(|index| collect_nfts([0; 32].into(), index, 1))
+			.collect::<Vec<_>>();
+		assert_eq!(GameModule::users::<AccountId>([0; 32].into()).unwrap().points, 850);
+		assert_noop!(
+			GameModule::craft_trophy(
+				RuntimeOrigin::signed([0; 32].into()),
+				items[..7].to_vec().try_into().unwrap()
+			),
+			Error::<Test>::InvalidRecipe
+		);
+		assert_ok!(GameModule::craft_trophy(
+			RuntimeOrigin::signed([0; 32].into()),
+			items.try_into().unwrap()
+		));
+		assert_eq!(GameModule::trophy_collection(), Some(8));
+		assert_eq!(Nfts::owner(8, 0).unwrap(), [0; 32].into());
+		assert!((0..8).all(|collection_id| Nfts::owner(collection_id, 0).is_none()));
+		let user = GameModule::users::<AccountId>([0; 32].into()).unwrap();
+		assert_eq!(user.nfts, Default::default());
+		assert_eq!(user.points, 1050);
+		assert_eq!(GameModule::leaderboard()[0], ([0; 32].into(), 1050));
+		System::assert_last_event(
+			Event::TrophyCrafted {
+				player: [0; 32].into(),
+				collection_id: 8,
+				item_id: 0,
+				points: 1000,
+			}
+			.into(),
 		);
 	});
 }
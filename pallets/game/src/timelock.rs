@@ -0,0 +1,35 @@
+use sp_std::prelude::*;
+
+/// A drand round number.
+pub type RoundNumber = u64;
+
+/// Access to the drand beacon for decrypting timelocked property prices.
+///
+/// Prices are encrypted with identity based encryption against a future drand round, so they
+/// can only be decrypted with the signature the beacon publishes for exactly that round.
+pub trait TimelockDecrypter {
+	/// The latest round whose signature has been brought on chain.
+	fn latest_round() -> RoundNumber;
+
+	/// Whether the signature of exactly `round` has been brought on chain.
+	///
+	/// Beacon rounds and blocks don't line up, so earlier rounds than [`Self::latest_round`] may
+	/// not be on chain.
+	fn is_revealed(round: RoundNumber) -> bool;
+
+	/// Decrypts a ciphertext that was locked to `round`.
+	///
+	/// Returns `None` if the signature of `round` is not available on chain or the ciphertext
+	/// can't be decrypted with it.
+	fn decrypt(round: RoundNumber, ciphertext: &[u8]) -> Option<Vec<u8>>;
+}
+
+/// Helper to lock and reveal prices in the benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper {
+	/// Encrypts the SCALE encoded `price` against `round`.
+	fn lock_price(round: RoundNumber, price: u32) -> Vec<u8>;
+
+	/// Makes the signature of `round` available on chain.
+	fn reveal_round(round: RoundNumber);
+}
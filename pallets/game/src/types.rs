@@ -1,6 +1,7 @@
 use crate::*;
-use frame_support::pallet_prelude::*;
+use frame_support::{pallet_prelude::*, sp_runtime::Percent};
 use frame_system::pallet_prelude::*;
+use sp_std::prelude::*;
 
 /// Difficulty level of game enum.
 #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
@@ -11,6 +12,13 @@ pub enum DifficultyLevel {
 	Pro,
 }
 
+/// The change of the points of a player by a game.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub enum PointsDelta {
+	Gained(u32),
+	Lost(u32),
+}
+
 /// Offer enum.
 #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
@@ -19,6 +27,20 @@ pub enum Offer {
 	Reject,
 }
 
+/// Permissions that can be granted to the admins of the game.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub enum Role {
+	/// Registers players.
+	Registrar,
+	/// Adds and removes properties.
+	PropertyCurator,
+	/// Awards points for results.
+	ResultsOracle,
+	/// Starts, schedules and ends rounds and sets their scoring.
+	SeasonManager,
+}
+
 /// Nft color enum.
 #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
@@ -56,6 +78,36 @@ pub struct PalletIdStorage<T: Config> {
 	pallet_id: AccountIdOf<T>,
 }
 
+/// State of a game.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub enum GameState {
+	/// The game waits for the answer of the player.
+	Pending,
+	/// The answer waits for the price of the property to be revealed.
+	Answered,
+	/// The answer has been checked, or the game expired without one.
+	Scored { points: u32, won: bool },
+	/// The game ended without a result and without changing the points of the player.
+	Voided,
+}
+
+impl GameState {
+	/// Whether the game still waits for a result.
+	pub fn is_open(&self) -> bool {
+		matches!(self, GameState::Pending | GameState::Answered)
+	}
+}
+
+/// A price proposed by the oracle for an answered game.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct ProposedResult<T: Config> {
+	pub price: u32,
+	pub final_block: BlockNumberFor<T>,
+	pub disputed: bool,
+}
+
 /// Game Data.
 #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
@@ -65,6 +117,30 @@ pub struct GameData<T: Config> {
 	pub player: AccountIdOf<T>,
 	pub property: PropertyInfoData<T>,
 	pub guess: Option<u32>,
+	pub state: GameState,
+	/// Answered games that haven't been scored by this block are settled without waiting
+	/// any longer for the price.
+	pub settlement_block: BlockNumberFor<T>,
+}
+
+/// Schedule of a round.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct RoundSchedule<T: Config> {
+	pub start: BlockNumberFor<T>,
+	pub end: BlockNumberFor<T>,
+	/// The prizes of the leaderboard ranks, starting with the first rank.
+	pub prizes: BoundedVec<CurrencyBalanceOf<T>, <T as Config>::MaxPrizes>,
+}
+
+/// Price of a listing.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub enum ListingPrice<Balance> {
+	/// The nft can be bought right away for this price.
+	Fixed(Balance),
+	/// The nft is sold to an accepted offer of at least this amount.
+	MinimumBid(Balance),
 }
 
 /// Listing infos of a NFT.
@@ -75,6 +151,16 @@ pub struct ListingInfo<CollectionId, ItemId, T: Config> {
 	pub owner: AccountIdOf<T>,
 	pub collection_id: CollectionId,
 	pub item_id: ItemId,
+	pub price: ListingPrice<CurrencyBalanceOf<T>>,
+	/// The last block in which the nft can be bought or offers can be made and accepted.
+	pub expiry: BlockNumberFor<T>,
+}
+
+impl<CollectionId, ItemId, T: Config> ListingInfo<CollectionId, ItemId, T> {
+	/// Whether the listing has expired at the given block.
+	pub fn is_expired(&self, now: BlockNumberFor<T>) -> bool {
+		now > self.expiry
+	}
 }
 
 /// Offer infos of a listing.
@@ -84,12 +170,47 @@ pub struct ListingInfo<CollectionId, ItemId, T: Config> {
 pub struct OfferInfo<CollectionId, ItemId, T: Config> {
 	pub owner: AccountIdOf<T>,
 	pub listing_id: u32,
-	pub collection_id: CollectionId,
-	pub item_id: ItemId,
+	/// The nft that is offered in exchange, held by the pallet.
+	pub nft: Option<(CollectionId, ItemId)>,
+	/// The amount that is offered, reserved from the owner.
+	pub amount: CurrencyBalanceOf<T>,
+}
+
+/// A guess that is timelock encrypted against the drand round of the property.
+pub type SealedGuess<T> = BoundedVec<u8, <T as Config>::StringLimit>;
+
+/// The phase of a lobby.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub enum LobbyState<T: Config> {
+	/// Players can join with the invite code until the lobby is full or started by the host.
+	Open { host: AccountIdOf<T>, capacity: u32, invite: <T as frame_system::Config>::Hash },
+	/// The players submit their sealed guesses until the ending block.
+	Guessing { property: PropertyInfoData<T>, ending_block: BlockNumberFor<T> },
+}
+
+/// A lobby of players who guess the price of the same property.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct LobbyData<T: Config> {
+	pub difficulty: DifficultyLevel,
+	pub state: LobbyState<T>,
+	/// The players and the entry points they paid into the pot.
+	pub players: BoundedVec<(AccountIdOf<T>, u32), <T as Config>::MaxLobbyPlayers>,
+}
+
+impl<T: Config> LobbyData<T> {
+	/// The points the winner of the lobby receives.
+	pub fn pot(&self) -> u32 {
+		self.players.iter().fold(0, |pot, (_, entry)| pot.saturating_add(*entry))
+	}
+
+	pub fn has_player(&self, player: &AccountIdOf<T>) -> bool {
+		self.players.iter().any(|(account, _)| account == player)
+	}
 }
 
 /// Struct to store the property data for a game.
-#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 #[derive(
 	Encode,
 	Decode,
@@ -99,11 +220,206 @@ pub struct OfferInfo<CollectionId, ItemId, T: Config> {
 	MaxEncodedLen,
 	frame_support::pallet_prelude::RuntimeDebugNoBound,
 	TypeInfo,
+	serde::Serialize,
+	serde::Deserialize,
 )]
+#[serde(bound = "")]
 #[scale_info(skip_type_params(T))]
 pub struct PropertyInfoData<T: Config> {
 	pub id: u32,
 	pub data: BoundedVec<u8, <T as Config>::StringLimit>,
+	pub region: BoundedVec<u8, <T as Config>::StringLimit>,
+	pub features: BoundedVec<u8, <T as Config>::StringLimit>,
+	/// The drand round the price is locked to.
+	pub round: RoundNumber,
+	/// The SCALE encoded price, timelock encrypted against `round`.
+	pub price: BoundedVec<u8, <T as Config>::StringLimit>,
+}
+
+/// Version 1 of the property import schema.
+///
+/// Kept as is so encoded catalogues stay decodable when `PropertyInfoData` changes.
+#[derive(
+	Encode,
+	Decode,
+	Clone,
+	PartialEq,
+	Eq,
+	frame_support::pallet_prelude::RuntimeDebugNoBound,
+	TypeInfo,
+	serde::Serialize,
+	serde::Deserialize,
+)]
+#[serde(bound = "")]
+#[scale_info(skip_type_params(T))]
+pub struct PropertyInfoDataV1<T: Config> {
+	pub id: u32,
+	pub data: BoundedVec<u8, <T as Config>::StringLimit>,
+	pub region: BoundedVec<u8, <T as Config>::StringLimit>,
+	pub features: BoundedVec<u8, <T as Config>::StringLimit>,
+	/// The drand round the price is locked to.
+	pub round: RoundNumber,
+	/// The SCALE encoded price, timelock encrypted against `round`.
+	pub price: BoundedVec<u8, <T as Config>::StringLimit>,
+}
+
+impl<T: Config> From<PropertyInfoDataV1<T>> for PropertyInfoData<T> {
+	fn from(property: PropertyInfoDataV1<T>) -> Self {
+		let PropertyInfoDataV1 { id, data, region, features, round, price } = property;
+		Self { id, data, region, features, round, price }
+	}
+}
+
+/// A property in one of the supported versions of the import schema.
+#[derive(
+	Encode,
+	Decode,
+	Clone,
+	PartialEq,
+	Eq,
+	frame_support::pallet_prelude::RuntimeDebugNoBound,
+	TypeInfo,
+	serde::Serialize,
+	serde::Deserialize,
+)]
+#[serde(bound = "")]
+#[scale_info(skip_type_params(T))]
+pub enum VersionedProperty<T: Config> {
+	V1(PropertyInfoDataV1<T>),
+}
+
+impl<T: Config> From<VersionedProperty<T>> for PropertyInfoData<T> {
+	fn from(property: VersionedProperty<T>) -> Self {
+		match property {
+			VersionedProperty::V1(property) => property.into(),
+		}
+	}
+}
+
+/// The maximum amount of bands in the scoring of a difficulty level.
+pub type MaxScoreBands = ConstU32<16>;
+
+/// Points for answers up to a maximum price difference.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct ScoreBand {
+	/// The largest difference between guess and price in per mille of the price.
+	pub max_difference: u16,
+	/// The points the player receives, or loses if the band is not a win.
+	pub points: u32,
+	/// Whether answers in this band count as a win.
+	pub won: bool,
+	/// The chance that a won game drops a nft instead of giving `points`.
+	pub nft_chance: Percent,
+}
+
+/// Scoring of a difficulty level.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct DifficultyScoring {
+	/// The points a player needs to start a game.
+	pub entry_points: u32,
+	/// The amount of blocks the player has to submit an answer.
+	pub expiry_blocks: u32,
+	/// The bands ordered by their maximum difference.
+	pub bands: BoundedVec<ScoreBand, MaxScoreBands>,
+	/// The points a player loses for a guess beyond the last band or for not answering.
+	pub miss_penalty: u32,
+}
+
+impl DifficultyScoring {
+	/// The band a difference falls into, `None` if it is beyond the last band.
+	pub fn band(&self, difference: u16) -> Option<&ScoreBand> {
+		self.bands.iter().find(|band| difference <= band.max_difference)
+	}
+
+	/// Checks that there is at least one band and that the bands are strictly ordered.
+	pub fn is_valid(&self) -> bool {
+		!self.bands.is_empty() &&
+			self.bands
+				.windows(2)
+				.all(|bands| bands[0].max_difference < bands[1].max_difference)
+	}
+
+	fn from_bands(
+		entry_points: u32,
+		expiry_blocks: u32,
+		bands: &[(u16, u32, bool, u8)],
+		miss_penalty: u32,
+	) -> Self {
+		let bands = bands
+			.iter()
+			.map(|&(max_difference, points, won, nft_chance)| ScoreBand {
+				max_difference,
+				points,
+				won,
+				nft_chance: Percent::from_percent(nft_chance),
+			})
+			.collect::<Vec<_>>();
+		Self { entry_points, expiry_blocks, bands: BoundedVec::truncate_from(bands), miss_penalty }
+	}
+}
+
+/// The scoring of all difficulty levels.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct ScoringTable {
+	pub practice: DifficultyScoring,
+	pub player: DifficultyScoring,
+	pub pro: DifficultyScoring,
+	/// The amount of practise rounds a user can play.
+	pub practise_round_limit: u8,
+}
+
+impl ScoringTable {
+	/// The scoring of a difficulty level.
+	pub fn difficulty(&self, difficulty: &DifficultyLevel) -> &DifficultyScoring {
+		match difficulty {
+			DifficultyLevel::Practice => &self.practice,
+			DifficultyLevel::Player => &self.player,
+			DifficultyLevel::Pro => &self.pro,
+		}
+	}
+
+	pub fn is_valid(&self) -> bool {
+		self.practice.is_valid() && self.player.is_valid() && self.pro.is_valid()
+	}
+}
+
+impl Default for ScoringTable {
+	fn default() -> Self {
+		Self {
+			practice: DifficultyScoring::from_bands(0, 10, &[(u16::MAX, 5, true, 0)], 0),
+			player: DifficultyScoring::from_bands(
+				25,
+				8,
+				&[
+					(10, 25, true, 100),
+					(30, 25, true, 0),
+					(50, 15, true, 0),
+					(100, 5, true, 0),
+					(150, 5, false, 0),
+					(200, 10, false, 0),
+					(250, 15, false, 0),
+					(300, 20, false, 0),
+				],
+				25,
+			),
+			pro: DifficultyScoring::from_bands(
+				50,
+				5,
+				&[
+					(10, 50, true, 100),
+					(30, 50, true, 0),
+					(50, 30, true, 0),
+					(100, 10, true, 0),
+					(150, 10, false, 0),
+					(200, 20, false, 0),
+					(250, 30, false, 0),
+					(300, 40, false, 0),
+				],
+				50,
+			),
+			practise_round_limit: 5,
+		}
+	}
 }
 
 /// Struct for the user datas.
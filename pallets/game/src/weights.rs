@@ -39,16 +39,39 @@ pub trait WeightInfo {
 	fn give_points() -> Weight;
 	fn play_game() -> Weight;
 	fn submit_answer() -> Weight;
-	fn check_result() -> Weight;
+	fn reveal_result() -> Weight;
 	fn list_nft() -> Weight;
 	fn delist_nft() -> Weight;
 	fn make_offer() -> Weight;
 	fn handle_offer() -> Weight;
 	fn add_property() -> Weight;
 	fn remove_property() -> Weight;
+	fn import_properties(n: u32, ) -> Weight;
 	fn add_to_admins() -> Weight;
 	fn remove_from_admins() -> Weight;
 	fn request_token() -> Weight;
+	fn set_scoring() -> Weight;
+	fn schedule_round() -> Weight;
+	fn cancel_scheduled_round() -> Weight;
+	fn end_round() -> Weight;
+	fn buy_nft() -> Weight;
+	fn create_lobby() -> Weight;
+	fn join_lobby() -> Weight;
+	fn leave_lobby(n: u32, ) -> Weight;
+	fn start_lobby() -> Weight;
+	fn queue_for_lobby() -> Weight;
+	fn leave_lobby_queue() -> Weight;
+	fn submit_sealed_guess() -> Weight;
+	fn settle_lobby(n: u32, ) -> Weight;
+	fn expire_game() -> Weight;
+	fn settle_game() -> Weight;
+	fn cleanup_game() -> Weight;
+	fn register() -> Weight;
+	fn submit_result() -> Weight;
+	fn dispute_result() -> Weight;
+	fn finalise_result() -> Weight;
+	fn craft_nft() -> Weight;
+	fn craft_trophy() -> Weight;
 }
 
 /// Weight functions for `pallet_game`.
@@ -64,8 +87,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `GameModule::GameProperties` (`max_values`: Some(1), `max_size`: Some(100802), added: 101297, mode: `MaxEncodedLen`)
 	/// Storage: `GameModule::CurrentRound` (r:1 w:1)
 	/// Proof: `GameModule::CurrentRound` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
-	/// Storage: `GameModule::RoundActive` (r:0 w:1)
+	/// Storage: `GameModule::RoundActive` (r:1 w:1)
 	/// Proof: `GameModule::RoundActive` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::RoundCollections` (r:0 w:1)
+	/// Proof: `GameModule::RoundCollections` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
 	/// Storage: `GameModule::CollectionColor` (r:0 w:8)
 	/// Proof: `GameModule::CollectionColor` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::CollectionRoleOf` (r:0 w:8)
@@ -78,26 +103,28 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Proof Size summary in bytes:
 		//  Measured:  `166`
 		//  Estimated: `102287`
-		// Minimum execution time: 203_338_000 picoseconds.
-		Weight::from_parts(207_008_000, 0)
+		// Minimum execution time: 205_912_000 picoseconds.
+		Weight::from_parts(209_476_000, 0)
 			.saturating_add(Weight::from_parts(0, 102287))
-			.saturating_add(T::DbWeight::get().reads(12))
-			.saturating_add(T::DbWeight::get().writes(45))
+			.saturating_add(T::DbWeight::get().reads(13))
+			.saturating_add(T::DbWeight::get().writes(46))
 	}
 	/// Storage: `GameModule::Admins` (r:1 w:0)
-	/// Proof: `GameModule::Admins` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
+	/// Proof: `GameModule::Admins` (`max_values`: None, `max_size`: Some(338), added: 2813, mode: `MaxEncodedLen`)
 	/// Storage: `GameModule::Users` (r:1 w:1)
 	/// Proof: `GameModule::Users` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::CurrentRound` (r:1 w:0)
+	/// Proof: `GameModule::CurrentRound` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn register_user() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `159`
-		//  Estimated: `3593`
-		// Minimum execution time: 23_973_000 picoseconds.
-		Weight::from_parts(25_287_000, 0)
-			.saturating_add(Weight::from_parts(0, 3593))
-			.saturating_add(T::DbWeight::get().reads(3))
+		//  Estimated: `3803`
+		// Minimum execution time: 24_418_000 picoseconds.
+		Weight::from_parts(25_790_000, 0)
+			.saturating_add(Weight::from_parts(0, 3803))
+			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `GameModule::Users` (r:1 w:1)
@@ -114,6 +141,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	/// Storage: `GameModule::Users` (r:1 w:0)
 	/// Proof: `GameModule::Users` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::Scoring` (r:1 w:0)
+	/// Proof: `GameModule::Scoring` (`max_values`: Some(1), `max_size`: Some(424), added: 919, mode: `MaxEncodedLen`)
 	/// Storage: `GameModule::RoundActive` (r:1 w:0)
 	/// Proof: `GameModule::RoundActive` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
 	/// Storage: `GameModule::CurrentRound` (r:1 w:0)
@@ -126,36 +155,48 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `RandomnessCollectiveFlip::RandomMaterial` (`max_values`: Some(1), `max_size`: Some(2594), added: 3089, mode: `MaxEncodedLen`)
 	/// Storage: `GameModule::GameProperties` (r:1 w:1)
 	/// Proof: `GameModule::GameProperties` (`max_values`: Some(1), `max_size`: Some(100802), added: 101297, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::DrawnProperties` (r:1 w:1)
+	/// Proof: `GameModule::DrawnProperties` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
 	/// Storage: `GameModule::GameInfo` (r:0 w:1)
-	/// Proof: `GameModule::GameInfo` (`max_values`: None, `max_size`: Some(1066), added: 3541, mode: `MaxEncodedLen`)
+	/// Proof: `GameModule::GameInfo` (`max_values`: None, `max_size`: Some(1076), added: 3551, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::RoundPoints` (r:0 w:1)
+	/// Proof: `GameModule::RoundPoints` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
 	fn play_game() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `616`
 		//  Estimated: `102287`
-		// Minimum execution time: 33_772_000 picoseconds.
-		Weight::from_parts(35_079_000, 0)
+		// Minimum execution time: 38_260_000 picoseconds.
+		Weight::from_parts(39_847_000, 0)
 			.saturating_add(Weight::from_parts(0, 102287))
-			.saturating_add(T::DbWeight::get().reads(7))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `GameModule::GameInfo` (r:1 w:1)
-	/// Proof: `GameModule::GameInfo` (`max_values`: None, `max_size`: Some(1066), added: 3541, mode: `MaxEncodedLen`)
+	/// Proof: `GameModule::GameInfo` (`max_values`: None, `max_size`: Some(1076), added: 3551, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::ProposedResults` (r:1 w:0)
+	/// Proof: `GameModule::ProposedResults` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::PendingReveals` (r:1 w:1)
+	/// Proof: `GameModule::PendingReveals` (`max_values`: Some(1), `max_size`: Some(2401), added: 2896, mode: `MaxEncodedLen`)
 	fn submit_answer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `359`
 		//  Estimated: `4531`
 		// Minimum execution time: 14_305_000 picoseconds.
-		Weight::from_parts(15_213_000, 0)
+		Weight::from_parts(17_842_000, 0)
 			.saturating_add(Weight::from_parts(0, 4531))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `GameModule::GameInfo` (r:1 w:1)
-	/// Proof: `GameModule::GameInfo` (`max_values`: None, `max_size`: Some(1066), added: 3541, mode: `MaxEncodedLen`)
+	/// Proof: `GameModule::GameInfo` (`max_values`: None, `max_size`: Some(1076), added: 3551, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::Scoring` (r:1 w:0)
+	/// Proof: `GameModule::Scoring` (`max_values`: Some(1), `max_size`: Some(424), added: 919, mode: `MaxEncodedLen`)
 	/// Storage: `RandomnessCollectiveFlip::RandomMaterial` (r:1 w:0)
 	/// Proof: `RandomnessCollectiveFlip::RandomMaterial` (`max_values`: Some(1), `max_size`: Some(2594), added: 3089, mode: `MaxEncodedLen`)
 	/// Storage: `GameModule::CurrentRound` (r:1 w:0)
 	/// Proof: `GameModule::CurrentRound` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::RoundCollections` (r:1 w:0)
+	/// Proof: `GameModule::RoundCollections` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
 	/// Storage: `GameModule::NextColorId` (r:1 w:1)
 	/// Proof: `GameModule::NextColorId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Item` (r:1 w:1)
@@ -176,14 +217,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `GameModule::Leaderboard` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Account` (r:0 w:1)
 	/// Proof: `Nfts::Account` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
-	fn check_result() -> Weight {
+	fn reveal_result() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1697`
 		//  Estimated: `4531`
-		// Minimum execution time: 83_106_000 picoseconds.
-		Weight::from_parts(85_634_000, 0)
+		// Minimum execution time: 86_045_000 picoseconds.
+		Weight::from_parts(88_370_000, 0)
 			.saturating_add(Weight::from_parts(0, 4531))
-			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().reads(14))
 			.saturating_add(T::DbWeight::get().writes(8))
 	}
 	/// Storage: `Nfts::Item` (r:1 w:1)
@@ -201,7 +242,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `GameModule::NextListingId` (r:1 w:1)
 	/// Proof: `GameModule::NextListingId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `GameModule::Listings` (r:0 w:1)
-	/// Proof: `GameModule::Listings` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Proof: `GameModule::Listings` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Account` (r:0 w:2)
 	/// Proof: `Nfts::Account` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::ItemPriceOf` (r:0 w:1)
@@ -219,7 +260,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(8))
 	}
 	/// Storage: `GameModule::Listings` (r:1 w:1)
-	/// Proof: `GameModule::Listings` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Proof: `GameModule::Listings` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Collection` (r:1 w:0)
 	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Attribute` (r:1 w:0)
@@ -249,7 +290,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `GameModule::Listings` (r:1 w:0)
-	/// Proof: `GameModule::Listings` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Proof: `GameModule::Listings` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::CollectionRoleOf` (r:1 w:0)
 	/// Proof: `Nfts::CollectionRoleOf` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::ItemConfigOf` (r:1 w:1)
@@ -262,10 +303,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Nfts::CollectionConfigOf` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Item` (r:1 w:1)
 	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(861), added: 3336, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `GameModule::NextOfferId` (r:1 w:1)
 	/// Proof: `GameModule::NextOfferId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `GameModule::Offers` (r:0 w:1)
-	/// Proof: `GameModule::Offers` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Proof: `GameModule::Offers` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Account` (r:0 w:2)
 	/// Proof: `Nfts::Account` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::ItemPriceOf` (r:0 w:1)
@@ -279,13 +322,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 77_751_000 picoseconds.
 		Weight::from_parts(82_016_000, 0)
 			.saturating_add(Weight::from_parts(0, 4326))
-			.saturating_add(T::DbWeight::get().reads(8))
-			.saturating_add(T::DbWeight::get().writes(8))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(9))
 	}
 	/// Storage: `GameModule::Offers` (r:1 w:1)
-	/// Proof: `GameModule::Offers` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Proof: `GameModule::Offers` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `GameModule::Listings` (r:1 w:1)
-	/// Proof: `GameModule::Listings` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Proof: `GameModule::Listings` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Collection` (r:1 w:0)
 	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::Attribute` (r:2 w:0)
@@ -298,6 +341,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(861), added: 3336, mode: `MaxEncodedLen`)
 	/// Storage: `Nfts::CollectionRoleOf` (r:1 w:0)
 	/// Proof: `Nfts::CollectionRoleOf` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::CurrentRound` (r:1 w:0)
+	/// Proof: `GameModule::CurrentRound` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::RoundCollections` (r:1 w:0)
+	/// Proof: `GameModule::RoundCollections` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
 	/// Storage: `GameModule::Users` (r:2 w:2)
 	/// Proof: `GameModule::Users` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
 	/// Storage: `GameModule::CollectionColor` (r:1 w:0)
@@ -317,8 +366,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 144_786_000 picoseconds.
 		Weight::from_parts(151_331_000, 0)
 			.saturating_add(Weight::from_parts(0, 7662))
-			.saturating_add(T::DbWeight::get().reads(15))
-			.saturating_add(T::DbWeight::get().writes(17))
+			.saturating_add(T::DbWeight::get().reads(20))
+			.saturating_add(T::DbWeight::get().writes(20))
 	}
 	/// Storage: `GameModule::GameProperties` (r:1 w:1)
 	/// Proof: `GameModule::GameProperties` (`max_values`: Some(1), `max_size`: Some(100802), added: 101297, mode: `MaxEncodedLen`)
@@ -344,27 +393,42 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `GameModule::GameProperties` (r:1 w:1)
+	/// Proof: `GameModule::GameProperties` (`max_values`: Some(1), `max_size`: Some(100802), added: 101297, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 96]`.
+	fn import_properties(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `318`
+		//  Estimated: `102287`
+		// Minimum execution time: 9_104_000 picoseconds.
+		Weight::from_parts(8_926_000, 0)
+			.saturating_add(Weight::from_parts(0, 102287))
+			// Standard Error: 1_215
+			.saturating_add(Weight::from_parts(1_402_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 	/// Storage: `GameModule::Admins` (r:1 w:1)
-	/// Proof: `GameModule::Admins` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
+	/// Proof: `GameModule::Admins` (`max_values`: None, `max_size`: Some(338), added: 2813, mode: `MaxEncodedLen`)
 	fn add_to_admins() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `118`
-		//  Estimated: `1806`
+		//  Estimated: `3803`
 		// Minimum execution time: 10_116_000 picoseconds.
 		Weight::from_parts(10_550_000, 0)
-			.saturating_add(Weight::from_parts(0, 1806))
+			.saturating_add(Weight::from_parts(0, 3803))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `GameModule::Admins` (r:1 w:1)
-	/// Proof: `GameModule::Admins` (`max_values`: Some(1), `max_size`: Some(321), added: 816, mode: `MaxEncodedLen`)
+	/// Proof: `GameModule::Admins` (`max_values`: None, `max_size`: Some(338), added: 2813, mode: `MaxEncodedLen`)
 	fn remove_from_admins() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `159`
-		//  Estimated: `1806`
+		//  Estimated: `3803`
 		// Minimum execution time: 11_207_000 picoseconds.
 		Weight::from_parts(11_767_000, 0)
-			.saturating_add(Weight::from_parts(0, 1806))
+			.saturating_add(Weight::from_parts(0, 3803))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
@@ -380,4 +444,554 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `GameModule::Scoring` (r:0 w:1)
+	/// Proof: `GameModule::Scoring` (`max_values`: Some(1), `max_size`: Some(424), added: 919, mode: `MaxEncodedLen`)
+	fn set_scoring() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_215_000 picoseconds.
+		Weight::from_parts(6_502_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `GameModule::NextRound` (r:1 w:1)
+	/// Proof: `GameModule::NextRound` (`max_values`: Some(1), `max_size`: Some(169), added: 664, mode: `MaxEncodedLen`)
+	fn schedule_round() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `1654`
+		// Minimum execution time: 8_730_000 picoseconds.
+		Weight::from_parts(9_114_000, 0)
+			.saturating_add(Weight::from_parts(0, 1654))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `GameModule::NextRound` (r:1 w:1)
+	/// Proof: `GameModule::NextRound` (`max_values`: Some(1), `max_size`: Some(169), added: 664, mode: `MaxEncodedLen`)
+	fn cancel_scheduled_round() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `179`
+		//  Estimated: `1654`
+		// Minimum execution time: 8_912_000 picoseconds.
+		Weight::from_parts(9_305_000, 0)
+			.saturating_add(Weight::from_parts(0, 1654))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `GameModule::RoundActive` (r:1 w:1)
+	/// Proof: `GameModule::RoundActive` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::CurrentRound` (r:1 w:0)
+	/// Proof: `GameModule::CurrentRound` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::Leaderboard` (r:1 w:1)
+	/// Proof: `GameModule::Leaderboard` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::RoundSchedules` (r:1 w:0)
+	/// Proof: `GameModule::RoundSchedules` (`max_values`: None, `max_size`: Some(189), added: 2664, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:11 w:11)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::RoundChampion` (r:0 w:1)
+	/// Proof: `GameModule::RoundChampion` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::RoundArchive` (r:0 w:1)
+	/// Proof: `GameModule::RoundArchive` (`max_values`: None, `max_size`: Some(381), added: 2856, mode: `MaxEncodedLen`)
+	fn end_round() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1216`
+		//  Estimated: `29623`
+		// Minimum execution time: 412_507_000 picoseconds.
+		Weight::from_parts(420_881_000, 0)
+			.saturating_add(Weight::from_parts(0, 29623))
+			.saturating_add(T::DbWeight::get().reads(15))
+			.saturating_add(T::DbWeight::get().writes(15))
+	}
+	/// Storage: `GameModule::Listings` (r:1 w:1)
+	/// Proof: `GameModule::Listings` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Collection` (r:1 w:0)
+	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Attribute` (r:1 w:0)
+	/// Proof: `Nfts::Attribute` (`max_values`: None, `max_size`: Some(446), added: 2921, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
+	/// Proof: `Nfts::CollectionConfigOf` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemConfigOf` (r:1 w:1)
+	/// Proof: `Nfts::ItemConfigOf` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Item` (r:1 w:1)
+	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(861), added: 3336, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::CollectionRoleOf` (r:1 w:0)
+	/// Proof: `Nfts::CollectionRoleOf` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::Users` (r:2 w:2)
+	/// Proof: `GameModule::Users` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::CurrentRound` (r:1 w:0)
+	/// Proof: `GameModule::CurrentRound` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::RoundCollections` (r:1 w:0)
+	/// Proof: `GameModule::RoundCollections` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::CollectionColor` (r:1 w:0)
+	/// Proof: `GameModule::CollectionColor` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::Leaderboard` (r:1 w:1)
+	/// Proof: `GameModule::Leaderboard` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Account` (r:0 w:2)
+	/// Proof: `Nfts::Account` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemPriceOf` (r:0 w:1)
+	/// Proof: `Nfts::ItemPriceOf` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::PendingSwapOf` (r:0 w:1)
+	/// Proof: `Nfts::PendingSwapOf` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
+	fn buy_nft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1954`
+		//  Estimated: `8799`
+		// Minimum execution time: 121_402_000 picoseconds.
+		Weight::from_parts(125_918_000, 0)
+			.saturating_add(Weight::from_parts(0, 8799))
+			.saturating_add(T::DbWeight::get().reads(16))
+			.saturating_add(T::DbWeight::get().writes(13))
+	}
+	/// Storage: `GameModule::RoundActive` (r:1 w:0)
+	/// Proof: `GameModule::RoundActive` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::PlayerLobby` (r:1 w:1)
+	/// Proof: `GameModule::PlayerLobby` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::MatchmakingEntries` (r:1 w:0)
+	/// Proof: `GameModule::MatchmakingEntries` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::Users` (r:1 w:1)
+	/// Proof: `GameModule::Users` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::CurrentRound` (r:1 w:0)
+	/// Proof: `GameModule::CurrentRound` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::Scoring` (r:1 w:0)
+	/// Proof: `GameModule::Scoring` (`max_values`: Some(1), `max_size`: Some(1040), added: 1535, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::Leaderboard` (r:1 w:1)
+	/// Proof: `GameModule::Leaderboard` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::NextLobbyId` (r:1 w:1)
+	/// Proof: `GameModule::NextLobbyId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::Lobbies` (r:0 w:1)
+	/// Proof: `GameModule::Lobbies` (`max_values`: None, `max_size`: Some(2335), added: 4810, mode: `MaxEncodedLen`)
+	fn create_lobby() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1391`
+		//  Estimated: `3566`
+		// Minimum execution time: 38_512_000 picoseconds.
+		Weight::from_parts(38_512_000, 0)
+			.saturating_add(Weight::from_parts(0, 3566))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `GameModule::Lobbies` (r:1 w:1)
+	/// Proof: `GameModule::Lobbies` (`max_values`: None, `max_size`: Some(2335), added: 4810, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::RoundActive` (r:1 w:0)
+	/// Proof: `GameModule::RoundActive` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::PlayerLobby` (r:1 w:1)
+	/// Proof: `GameModule::PlayerLobby` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::MatchmakingEntries` (r:1 w:0)
+	/// Proof: `GameModule::MatchmakingEntries` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::Users` (r:1 w:1)
+	/// Proof: `GameModule::Users` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::CurrentRound` (r:1 w:0)
+	/// Proof: `GameModule::CurrentRound` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::Scoring` (r:1 w:0)
+	/// Proof: `GameModule::Scoring` (`max_values`: Some(1), `max_size`: Some(1040), added: 1535, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::Leaderboard` (r:1 w:1)
+	/// Proof: `GameModule::Leaderboard` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	/// Storage: `RandomnessCollectiveFlip::RandomMaterial` (r:1 w:0)
+	/// Proof: `RandomnessCollectiveFlip::RandomMaterial` (`max_values`: Some(1), `max_size`: Some(2594), added: 3089, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::GameProperties` (r:1 w:1)
+	/// Proof: `GameModule::GameProperties` (`max_values`: Some(1), `max_size`: Some(100802), added: 101297, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::DrawnProperties` (r:1 w:1)
+	/// Proof: `GameModule::DrawnProperties` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::PendingLobbies` (r:1 w:1)
+	/// Proof: `GameModule::PendingLobbies` (`max_values`: Some(1), `max_size`: Some(2401), added: 2896, mode: `MaxEncodedLen`)
+	fn join_lobby() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `103547`
+		//  Estimated: `102287`
+		// Minimum execution time: 96_781_000 picoseconds.
+		Weight::from_parts(96_781_000, 0)
+			.saturating_add(Weight::from_parts(0, 102287))
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	/// Storage: `GameModule::Lobbies` (r:1 w:1)
+	/// Proof: `GameModule::Lobbies` (`max_values`: None, `max_size`: Some(2335), added: 4810, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::CurrentRound` (r:1 w:0)
+	/// Proof: `GameModule::CurrentRound` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[2, 7]`.
+	fn leave_lobby(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1844 + n * (101 ±0)`
+		//  Estimated: `5800 + n * (2576 ±0)`
+		// Minimum execution time: 24_901_000 picoseconds.
+		Weight::from_parts(18_204_000, 0)
+			.saturating_add(Weight::from_parts(0, 5800))
+			// Standard Error: 21_417
+			.saturating_add(Weight::from_parts(9_317_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2576).saturating_mul(n.into()))
+	}
+	/// Storage: `GameModule::Lobbies` (r:1 w:1)
+	/// Proof: `GameModule::Lobbies` (`max_values`: None, `max_size`: Some(2335), added: 4810, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::Scoring` (r:1 w:0)
+	/// Proof: `GameModule::Scoring` (`max_values`: Some(1), `max_size`: Some(1040), added: 1535, mode: `MaxEncodedLen`)
+	/// Storage: `RandomnessCollectiveFlip::RandomMaterial` (r:1 w:0)
+	/// Proof: `RandomnessCollectiveFlip::RandomMaterial` (`max_values`: Some(1), `max_size`: Some(2594), added: 3089, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::GameProperties` (r:1 w:1)
+	/// Proof: `GameModule::GameProperties` (`max_values`: Some(1), `max_size`: Some(100802), added: 101297, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::DrawnProperties` (r:1 w:1)
+	/// Proof: `GameModule::DrawnProperties` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::PendingLobbies` (r:1 w:1)
+	/// Proof: `GameModule::PendingLobbies` (`max_values`: Some(1), `max_size`: Some(2401), added: 2896, mode: `MaxEncodedLen`)
+	fn start_lobby() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `103311`
+		//  Estimated: `102287`
+		// Minimum execution time: 84_305_000 picoseconds.
+		Weight::from_parts(84_305_000, 0)
+			.saturating_add(Weight::from_parts(0, 102287))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `GameModule::RoundActive` (r:1 w:0)
+	/// Proof: `GameModule::RoundActive` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::PlayerLobby` (r:1 w:1)
+	/// Proof: `GameModule::PlayerLobby` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::MatchmakingEntries` (r:1 w:0)
+	/// Proof: `GameModule::MatchmakingEntries` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::Users` (r:1 w:1)
+	/// Proof: `GameModule::Users` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::CurrentRound` (r:1 w:0)
+	/// Proof: `GameModule::CurrentRound` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::Scoring` (r:1 w:0)
+	/// Proof: `GameModule::Scoring` (`max_values`: Some(1), `max_size`: Some(1040), added: 1535, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::Leaderboard` (r:1 w:1)
+	/// Proof: `GameModule::Leaderboard` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	/// Storage: `MatchmakerModule::BracketsCount` (r:1 w:0)
+	/// Proof: `MatchmakerModule::BracketsCount` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `MatchmakerModule::BracketIndices` (r:1 w:1)
+	/// Proof: `MatchmakerModule::BracketIndices` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `MatchmakerModule::BracketKeyValueMap` (r:1 w:1)
+	/// Proof: `MatchmakerModule::BracketKeyValueMap` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `MatchmakerModule::BracketIndexKeyMap` (r:1 w:1)
+	/// Proof: `MatchmakerModule::BracketIndexKeyMap` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::NextLobbyId` (r:1 w:1)
+	/// Proof: `GameModule::NextLobbyId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `RandomnessCollectiveFlip::RandomMaterial` (r:1 w:0)
+	/// Proof: `RandomnessCollectiveFlip::RandomMaterial` (`max_values`: Some(1), `max_size`: Some(2594), added: 3089, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::GameProperties` (r:1 w:1)
+	/// Proof: `GameModule::GameProperties` (`max_values`: Some(1), `max_size`: Some(100802), added: 101297, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::DrawnProperties` (r:1 w:1)
+	/// Proof: `GameModule::DrawnProperties` (`max_values`: Some(1), `max_size`: Some(401), added: 896, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::PendingLobbies` (r:1 w:1)
+	/// Proof: `GameModule::PendingLobbies` (`max_values`: Some(1), `max_size`: Some(2401), added: 2896, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::Lobbies` (r:0 w:1)
+	/// Proof: `GameModule::Lobbies` (`max_values`: None, `max_size`: Some(2335), added: 4810, mode: `MaxEncodedLen`)
+	fn queue_for_lobby() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `104207`
+		//  Estimated: `102287`
+		// Minimum execution time: 131_402_000 picoseconds.
+		Weight::from_parts(133_915_000, 0)
+			.saturating_add(Weight::from_parts(0, 102287))
+			.saturating_add(T::DbWeight::get().reads(21))
+			.saturating_add(T::DbWeight::get().writes(16))
+	}
+	/// Storage: `GameModule::MatchmakingEntries` (r:1 w:1)
+	/// Proof: `GameModule::MatchmakingEntries` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `MatchmakerModule::BracketsCount` (r:1 w:0)
+	/// Proof: `MatchmakerModule::BracketsCount` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `MatchmakerModule::BracketIndices` (r:2 w:2)
+	/// Proof: `MatchmakerModule::BracketIndices` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `MatchmakerModule::BracketKeyValueMap` (r:1 w:1)
+	/// Proof: `MatchmakerModule::BracketKeyValueMap` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `MatchmakerModule::BracketIndexKeyMap` (r:1 w:1)
+	/// Proof: `MatchmakerModule::BracketIndexKeyMap` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::PlayerLobby` (r:0 w:1)
+	/// Proof: `GameModule::PlayerLobby` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::CurrentRound` (r:1 w:0)
+	/// Proof: `GameModule::CurrentRound` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::Users` (r:1 w:1)
+	/// Proof: `GameModule::Users` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::Leaderboard` (r:1 w:1)
+	/// Proof: `GameModule::Leaderboard` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	fn leave_lobby_queue() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1409`
+		//  Estimated: `5052`
+		// Minimum execution time: 52_813_000 picoseconds.
+		Weight::from_parts(54_120_000, 0)
+			.saturating_add(Weight::from_parts(0, 5052))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	/// Storage: `GameModule::Lobbies` (r:1 w:0)
+	/// Proof: `GameModule::Lobbies` (`max_values`: None, `max_size`: Some(2335), added: 4810, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::LobbyGuesses` (r:0 w:1)
+	/// Proof: `GameModule::LobbyGuesses` (`max_values`: None, `max_size`: Some(570), added: 3045, mode: `MaxEncodedLen`)
+	fn submit_sealed_guess() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2432`
+		//  Estimated: `5800`
+		// Minimum execution time: 23_614_000 picoseconds.
+		Weight::from_parts(23_614_000, 0)
+			.saturating_add(Weight::from_parts(0, 5800))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `GameModule::Lobbies` (r:1 w:1)
+	/// Proof: `GameModule::Lobbies` (`max_values`: None, `max_size`: Some(2335), added: 4810, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::LobbyGuesses` (r:8 w:8)
+	/// Proof: `GameModule::LobbyGuesses` (`max_values`: None, `max_size`: Some(570), added: 3045, mode: `MaxEncodedLen`)
+	/// Storage: `RandomnessCollectiveFlip::RandomMaterial` (r:1 w:0)
+	/// Proof: `RandomnessCollectiveFlip::RandomMaterial` (`max_values`: Some(1), `max_size`: Some(2594), added: 3089, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::CurrentRound` (r:1 w:0)
+	/// Proof: `GameModule::CurrentRound` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::Leaderboard` (r:1 w:1)
+	/// Proof: `GameModule::Leaderboard` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[2, 8]`.
+	fn settle_lobby(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3911 + n * (101 ±0)`
+		//  Estimated: `27155 + n * (2576 ±0)`
+		// Minimum execution time: 61_209_000 picoseconds.
+		Weight::from_parts(41_880_000, 0)
+			.saturating_add(Weight::from_parts(0, 27155))
+			// Standard Error: 21_417
+			.saturating_add(Weight::from_parts(17_563_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(10))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2576).saturating_mul(n.into()))
+	}
+	/// Storage: `GameModule::GameInfo` (r:1 w:1)
+	/// Proof: `GameModule::GameInfo` (`max_values`: None, `max_size`: Some(1076), added: 3551, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::Scoring` (r:1 w:0)
+	/// Proof: `GameModule::Scoring` (`max_values`: Some(1), `max_size`: Some(1040), added: 1535, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::Users` (r:1 w:1)
+	/// Proof: `GameModule::Users` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::FinishedGames` (r:1 w:1)
+	/// Proof: `GameModule::FinishedGames` (`max_values`: Some(1), `max_size`: Some(802), added: 1297, mode: `MaxEncodedLen`)
+	fn expire_game() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1589`
+		//  Estimated: `4541`
+		// Minimum execution time: 27_305_000 picoseconds.
+		Weight::from_parts(27_305_000, 0)
+			.saturating_add(Weight::from_parts(0, 4541))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `GameModule::GameInfo` (r:1 w:1)
+	/// Proof: `GameModule::GameInfo` (`max_values`: None, `max_size`: Some(1076), added: 3551, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::PendingReveals` (r:1 w:1)
+	/// Proof: `GameModule::PendingReveals` (`max_values`: Some(1), `max_size`: Some(2401), added: 2896, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::FinishedGames` (r:1 w:1)
+	/// Proof: `GameModule::FinishedGames` (`max_values`: Some(1), `max_size`: Some(802), added: 1297, mode: `MaxEncodedLen`)
+	fn settle_game() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1721`
+		//  Estimated: `4541`
+		// Minimum execution time: 22_870_000 picoseconds.
+		Weight::from_parts(22_870_000, 0)
+			.saturating_add(Weight::from_parts(0, 4541))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `GameModule::GameInfo` (r:0 w:1)
+	/// Proof: `GameModule::GameInfo` (`max_values`: None, `max_size`: Some(1076), added: 3551, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::ProposedResults` (r:0 w:1)
+	/// Proof: `GameModule::ProposedResults` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	fn cleanup_game() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 4_108_000 picoseconds.
+		Weight::from_parts(4_108_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().reads(0))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `GameModule::Users` (r:1 w:1)
+	/// Proof: `GameModule::Users` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::CurrentRound` (r:1 w:0)
+	/// Proof: `GameModule::CurrentRound` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::RegistrationDeposits` (r:0 w:1)
+	/// Proof: `GameModule::RegistrationDeposits` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn register() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `173`
+		//  Estimated: `3593`
+		// Minimum execution time: 31_806_000 picoseconds.
+		Weight::from_parts(32_944_000, 0)
+			.saturating_add(Weight::from_parts(0, 3593))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `GameModule::GameInfo` (r:1 w:1)
+	/// Proof: `GameModule::GameInfo` (`max_values`: None, `max_size`: Some(1076), added: 3551, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::ProposedResults` (r:1 w:1)
+	/// Proof: `GameModule::ProposedResults` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::ResultsFinalising` (r:1 w:1)
+	/// Proof: `GameModule::ResultsFinalising` (`max_values`: None, `max_size`: Some(822), added: 3297, mode: `MaxEncodedLen`)
+	fn submit_result() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `4541`
+		// Minimum execution time: 17_934_000 picoseconds.
+		Weight::from_parts(18_652_000, 0)
+			.saturating_add(Weight::from_parts(0, 4541))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `GameModule::ProposedResults` (r:1 w:1)
+	/// Proof: `GameModule::ProposedResults` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	fn dispute_result() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `167`
+		//  Estimated: `3494`
+		// Minimum execution time: 9_215_000 picoseconds.
+		Weight::from_parts(9_688_000, 0)
+			.saturating_add(Weight::from_parts(0, 3494))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `GameModule::GameInfo` (r:1 w:1)
+	/// Proof: `GameModule::GameInfo` (`max_values`: None, `max_size`: Some(1076), added: 3551, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::ProposedResults` (r:1 w:1)
+	/// Proof: `GameModule::ProposedResults` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::PendingReveals` (r:1 w:1)
+	/// Proof: `GameModule::PendingReveals` (`max_values`: Some(1), `max_size`: Some(2401), added: 2896, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::Scoring` (r:1 w:0)
+	/// Proof: `GameModule::Scoring` (`max_values`: Some(1), `max_size`: Some(424), added: 919, mode: `MaxEncodedLen`)
+	/// Storage: `RandomnessCollectiveFlip::RandomMaterial` (r:1 w:0)
+	/// Proof: `RandomnessCollectiveFlip::RandomMaterial` (`max_values`: Some(1), `max_size`: Some(2594), added: 3089, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::CurrentRound` (r:1 w:0)
+	/// Proof: `GameModule::CurrentRound` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::RoundCollections` (r:1 w:0)
+	/// Proof: `GameModule::RoundCollections` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::NextColorId` (r:1 w:1)
+	/// Proof: `GameModule::NextColorId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Item` (r:1 w:1)
+	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(861), added: 3336, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Collection` (r:1 w:1)
+	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
+	/// Proof: `Nfts::CollectionConfigOf` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemConfigOf` (r:1 w:1)
+	/// Proof: `Nfts::ItemConfigOf` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::CollectionRoleOf` (r:1 w:0)
+	/// Proof: `Nfts::CollectionRoleOf` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::Users` (r:1 w:1)
+	/// Proof: `GameModule::Users` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::CollectionColor` (r:1 w:0)
+	/// Proof: `GameModule::CollectionColor` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::Leaderboard` (r:1 w:1)
+	/// Proof: `GameModule::Leaderboard` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Account` (r:0 w:1)
+	/// Proof: `Nfts::Account` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	fn finalise_result() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1784`
+		//  Estimated: `4531`
+		// Minimum execution time: 92_318_000 picoseconds.
+		Weight::from_parts(94_806_000, 0)
+			.saturating_add(Weight::from_parts(0, 4531))
+			.saturating_add(T::DbWeight::get().reads(16))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
+	/// Storage: `GameModule::Users` (r:1 w:1)
+	/// Proof: `GameModule::Users` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::RoundActive` (r:1 w:0)
+	/// Proof: `GameModule::RoundActive` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::CurrentRound` (r:1 w:0)
+	/// Proof: `GameModule::CurrentRound` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::RoundCollections` (r:1 w:0)
+	/// Proof: `GameModule::RoundCollections` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Item` (r:3 w:3)
+	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(861), added: 3336, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Collection` (r:4 w:4)
+	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemMetadataOf` (r:3 w:0)
+	/// Proof: `Nfts::ItemMetadataOf` (`max_values`: None, `max_size`: Some(187), added: 2662, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Account` (r:0 w:4)
+	/// Proof: `Nfts::Account` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemPriceOf` (r:0 w:3)
+	/// Proof: `Nfts::ItemPriceOf` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemAttributesApprovalsOf` (r:0 w:3)
+	/// Proof: `Nfts::ItemAttributesApprovalsOf` (`max_values`: None, `max_size`: Some(681), added: 3156, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::PendingSwapOf` (r:0 w:3)
+	/// Proof: `Nfts::PendingSwapOf` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemConfigOf` (r:1 w:4)
+	/// Proof: `Nfts::ItemConfigOf` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::CollectionColor` (r:2 w:0)
+	/// Proof: `GameModule::CollectionColor` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::Leaderboard` (r:1 w:1)
+	/// Proof: `GameModule::Leaderboard` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	/// Storage: `RandomnessCollectiveFlip::RandomMaterial` (r:1 w:0)
+	/// Proof: `RandomnessCollectiveFlip::RandomMaterial` (`max_values`: Some(1), `max_size`: Some(2594), added: 3089, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::NextColorId` (r:1 w:1)
+	/// Proof: `GameModule::NextColorId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:0)
+	/// Proof: `Nfts::CollectionConfigOf` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::CollectionRoleOf` (r:1 w:0)
+	/// Proof: `Nfts::CollectionRoleOf` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	fn craft_nft() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2291`
+		//  Estimated: `11529`
+		// Minimum execution time: 163_417_000 picoseconds.
+		Weight::from_parts(168_205_000, 0)
+			.saturating_add(Weight::from_parts(0, 11529))
+			.saturating_add(T::DbWeight::get().reads(20))
+			.saturating_add(T::DbWeight::get().writes(26))
+	}
+	/// Storage: `GameModule::Users` (r:1 w:1)
+	/// Proof: `GameModule::Users` (`max_values`: None, `max_size`: Some(101), added: 2576, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::RoundActive` (r:1 w:0)
+	/// Proof: `GameModule::RoundActive` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::CurrentRound` (r:1 w:0)
+	/// Proof: `GameModule::CurrentRound` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::RoundCollections` (r:1 w:0)
+	/// Proof: `GameModule::RoundCollections` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Item` (r:8 w:8)
+	/// Proof: `Nfts::Item` (`max_values`: None, `max_size`: Some(861), added: 3336, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Collection` (r:9 w:9)
+	/// Proof: `Nfts::Collection` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemMetadataOf` (r:8 w:0)
+	/// Proof: `Nfts::ItemMetadataOf` (`max_values`: None, `max_size`: Some(187), added: 2662, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::Account` (r:0 w:9)
+	/// Proof: `Nfts::Account` (`max_values`: None, `max_size`: Some(88), added: 2563, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemPriceOf` (r:0 w:8)
+	/// Proof: `Nfts::ItemPriceOf` (`max_values`: None, `max_size`: Some(89), added: 2564, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemAttributesApprovalsOf` (r:0 w:8)
+	/// Proof: `Nfts::ItemAttributesApprovalsOf` (`max_values`: None, `max_size`: Some(681), added: 3156, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::PendingSwapOf` (r:0 w:8)
+	/// Proof: `Nfts::PendingSwapOf` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::ItemConfigOf` (r:1 w:9)
+	/// Proof: `Nfts::ItemConfigOf` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::CollectionColor` (r:8 w:0)
+	/// Proof: `GameModule::CollectionColor` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::Leaderboard` (r:1 w:1)
+	/// Proof: `GameModule::Leaderboard` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::TrophyCollection` (r:1 w:1)
+	/// Proof: `GameModule::TrophyCollection` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::NextCollectionId` (r:1 w:1)
+	/// Proof: `Nfts::NextCollectionId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `GameModule::NextColorId` (r:1 w:1)
+	/// Proof: `GameModule::NextColorId` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::CollectionConfigOf` (r:1 w:1)
+	/// Proof: `Nfts::CollectionConfigOf` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Nfts::CollectionRoleOf` (r:1 w:1)
+	/// Proof: `Nfts::CollectionRoleOf` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
+	fn craft_trophy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4176`
+		//  Estimated: `27216`
+		// Minimum execution time: 387_662_000 picoseconds.
+		Weight::from_parts(396_081_000, 0)
+			.saturating_add(Weight::from_parts(0, 27216))
+			.saturating_add(T::DbWeight::get().reads(45))
+			.saturating_add(T::DbWeight::get().writes(64))
+	}
 }
@@ -25,9 +25,20 @@ pub mod weights;
 pub use crate::{types::*, weights::*};
 
 use frame_support::{
-	ensure, sp_runtime, sp_runtime::SaturatedConversion, traits::Get, StorageHasher,
+	ensure, sp_runtime,
+	sp_runtime::{
+		traits::{AccountIdConversion, Saturating, Zero},
+		Perbill, SaturatedConversion,
+	},
+	traits::{
+		BalanceStatus, Currency, ExistenceRequirement, Get, OnUnbalanced, ReservableCurrency,
+		WithdrawReasons,
+	},
+	Blake2_256, PalletId, StorageHasher,
 };
+use parity_scale_codec::Encode;
 use scale_info::prelude::vec;
+use sp_std::collections::btree_set::BTreeSet;
 
 use pallet_elo::EloFunc;
 use pallet_matchmaker::MatchFunc;
@@ -40,6 +51,11 @@ pub mod pallet {
 
 	pub type TileSelectionOf<T> = TileSelection<<T as Config>::MaxTileSelection>;
 	pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::NegativeImbalance;
 
 	pub type HexGridOf<T> = HexGrid<<T as Config>::Tile, <T as Config>::MaxHexGridSize>;
 	pub type HexBoardOf<T> = HexBoard<<T as Config>::Tile, <T as Config>::MaxHexGridSize>;
@@ -126,6 +142,24 @@ pub mod pallet {
 
 		/// Something that provides randomness in the runtime.
 		type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
+
+		/// The currency in which the entry fees of staked games are paid.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The account that holds the prize pools of finished staked games.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// The fee that every player reserves to join a staked game.
+		#[pallet::constant]
+		type EntryFee: Get<BalanceOf<Self>>;
+
+		/// The part of a prize pool that is kept by the house.
+		#[pallet::constant]
+		type HouseFee: Get<Perbill>;
+
+		/// Where the house fee of settled prize pools ends up.
+		type HouseFeeDestination: OnUnbalanced<NegativeImbalanceOf<Self>>;
 	}
 
 	#[pallet::storage]
@@ -146,6 +180,35 @@ pub mod pallet {
 	pub type TargetGoalStorage<T: Config> =
 		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, TargetGoalHash>;
 
+	#[pallet::storage]
+	// Stores the entry fee a player has reserved for a staked game that has not finished yet.
+	pub type StakeStorage<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, BalanceOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	// Stores the prize a player can claim with receive_rewards.
+	pub type PrizeStorage<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, BalanceOf<T>, ValueQuery>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		#[serde(skip)]
+		pub _config: PhantomData<T>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			// Keep the prize pool account alive, so paying out a prize never reaps it.
+			let account_id = Pallet::<T>::account_id();
+			let min = T::Currency::minimum_balance();
+			if T::Currency::free_balance(&account_id) < min {
+				let _ = T::Currency::make_free_balance_be(&account_id, min);
+			}
+		}
+	}
+
 	// Pallets use events to inform users when important changes are made.
 	// https://docs.substrate.io/main-docs/build/events-errors/
 	#[pallet::event]
@@ -175,6 +238,27 @@ pub mod pallet {
 
 		HexBoardDeleted { player: AccountIdOf<T> },
 
+		// Player reserved the entry fee of a staked game
+		StakeReserved { player: AccountIdOf<T>, amount: BalanceOf<T> },
+
+		// Player joined a staked game
+		PlayerJoined { game_id: GameId, player: AccountIdOf<T> },
+
+		// All players joined the staked game, so it has started
+		GameStarted { game_id: GameId },
+
+		// Staked game has been cancelled before all players joined
+		GameCancelled { game_id: GameId },
+
+		// The stakes of a finished game have been collected into a prize pool
+		PrizePoolSettled { game_id: GameId, pot: BalanceOf<T>, house_fee: BalanceOf<T> },
+
+		// Player received their prize
+		PrizePaid { player: AccountIdOf<T>, amount: BalanceOf<T> },
+
+		// Player got their stake back, because the game has been deleted
+		StakeRefunded { player: AccountIdOf<T>, amount: BalanceOf<T> },
+
 		// Event that is never used. It serves the purpose to expose hidden rust enums
 		ExposeEnums { tile_type: TileType, tile_pattern: TilePattern },
 	}
@@ -259,6 +343,12 @@ pub mod pallet {
 
 		// Not enough blocks have passed to force finish turn
 		BlocksToPlayLimitNotPassed,
+
+		// Game is not waiting for its players to join.
+		GameNotWaitingForPlayers,
+
+		// Player is not waiting in the matchmaking queue.
+		PlayerNotQueued,
 	}
 
 	#[pallet::call]
@@ -272,116 +362,148 @@ pub mod pallet {
 		) -> DispatchResult {
 			let who: AccountIdOf<T> = ensure_signed(origin)?;
 
-			// If you want to play, you need to specify yourself in the Vec as well
-			let number_of_players = players.len();
-
-			ensure!(
-				number_of_players >= T::MinPlayers::get() as usize,
-				Error::<T>::NumberOfPlayersIsTooSmall
-			);
-
-			ensure!(
-				number_of_players <= T::MaxPlayers::get() as usize,
-				Error::<T>::NumberOfPlayersIsTooLarge
-			);
-
-			ensure!(Self::is_valid_grid_size(grid_size), Error::<T>::BadGridSize);
-
-			// Random GameId
-			// I used `who` to ensure that even if 2 independent players wanted to create game in
-			// the same block, they would be able to.
-			let current_block_number = <frame_system::Pallet<T>>::block_number();
-			let seed: &[u8] = &Blake2_256::hash(&(&who, &current_block_number).encode());
-			let game_id: GameId = Blake2_256::hash(&T::Randomness::random(seed).0.encode());
-
-			ensure!(players[0] == who, Error::<T>::CreatorNotInPlayersAtIndexZero);
-
-			// Ensure that the game has not already been created
-			ensure!(!GameStorage::<T>::contains_key(game_id), Error::<T>::GameAlreadyCreated);
-
-			// Initialise HexBoards for all players
-			for player in &players {
-				ensure!(!HexBoardStorage::<T>::contains_key(player), Error::<T>::AlreadyPlaying);
+			Self::do_create_game(who, players, grid_size, false)
+		}
 
-				HexBoardStorage::<T>::set(
-					player,
-					Some(
-						HexBoardOf::<T>::try_new::<T::DefaultPlayerResources>(
-							grid_size as usize,
-							MatchmakingState::Joined(game_id),
-						)
-						.ok_or(Error::<T>::InternalError)?,
-					),
-				);
-			}
+		/// Creates a game in which every player reserves the entry fee. The winner takes the
+		/// prize pool, a draw splits it between all players.
+		///
+		/// Only the creator's entry fee is reserved here. The game starts once all other players
+		/// have joined it with `join_staked_game`.
+		#[pallet::call_index(9)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1).ref_time())]
+		pub fn create_staked_game(
+			origin: OriginFor<T>,
+			players: Vec<AccountIdOf<T>>,
+			grid_size: u8,
+		) -> DispatchResult {
+			let who: AccountIdOf<T> = ensure_signed(origin)?;
 
-			// Default Game Config
-			Self::do_create_new_game(game_id, current_block_number, players, grid_size)
+			Self::do_create_game(who, players, grid_size, true)
 		}
 
-		#[pallet::call_index(100)]
+		/// Joins a staked game the player has been listed in and reserves the entry fee.
+		#[pallet::call_index(10)]
 		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1).ref_time())]
-		pub fn queue(origin: OriginFor<T>) -> DispatchResult {
+		pub fn join_staked_game(origin: OriginFor<T>, game_id: GameId) -> DispatchResult {
 			let who: AccountIdOf<T> = ensure_signed(origin)?;
 
-			// Make sure player has no board open.
+			// Ensures that the Game exists
+			let mut game = match GameStorage::<T>::get(game_id) {
+				Some(value) => value,
+				None => return Err(Error::<T>::GameNotInitialized.into()),
+			};
+
+			ensure!(game.state == GameState::Matchmaking, Error::<T>::GameNotWaitingForPlayers);
+			ensure!(game.borrow_players().contains(&who), Error::<T>::PlayerNotInGame);
 			ensure!(!HexBoardStorage::<T>::contains_key(&who), Error::<T>::AlreadyPlaying);
 
-			// Perhaps in the future, we might want to allow players to play on other grid sizes
-			let grid_size: u8 = 25;
+			// The creator has joined when creating the game, so their board has the grid size.
+			let grid_size = match HexBoardStorage::<T>::get(&game.borrow_players()[0]) {
+				Some(value) => value.hex_grid.len(),
+				None => return Err(Error::<T>::HexBoardNotInitialized.into()),
+			};
 
 			HexBoardStorage::<T>::set(
 				&who,
 				Some(
 					HexBoardOf::<T>::try_new::<T::DefaultPlayerResources>(
-						grid_size as usize,
-						MatchmakingState::Matchmaking,
+						grid_size,
+						MatchmakingState::Joined(game_id),
 					)
 					.ok_or(Error::<T>::InternalError)?,
 				),
 			);
 
-			// This might change with the introduction of ELO
-			let bracket: u8 = 0;
+			Self::reserve_stake(&who)?;
 
-			// Add player to queue, duplicate check is done in matchmaker.
-			T::Matchmaker::add_queue(who, bracket)?;
+			Self::deposit_event(Event::PlayerJoined { game_id, player: who });
 
-			let potential_players = T::Matchmaker::try_match();
+			if game.borrow_players().iter().all(|player| Self::has_joined(player, game_id)) {
+				game.set_state(GameState::Playing);
+				game.last_played_block = <frame_system::Pallet<T>>::block_number();
 
-			// if result is not empty we have a valid match
-			if !potential_players.is_empty() {
-				// Random GameId
-				// I used `potential_players` to ensure that even if 2 independent players wanted to
-				// create game in the same block, they would be able to.
-				let current_block_number = <frame_system::Pallet<T>>::block_number();
-				let seed: &[u8] = &Blake2_256::hash(&(&potential_players[0], &current_block_number).encode());
-				// with drand, we get 32-bytes of randomness
-				let game_id: GameId = Blake2_256::hash(&T::Randomness::random(seed).0.encode());
+				GameStorage::<T>::set(game_id, Some(game));
 
-				for player in &potential_players {
-					// Ensures that the HexBoard exists
-					let mut hex_board = match HexBoardStorage::<T>::get(player) {
-						Some(value) => value,
-						None => return Err(Error::<T>::HexBoardNotInitialized.into()),
-					};
+				Self::deposit_event(Event::GameStarted { game_id });
+			}
 
-					hex_board.matchmaking_state = MatchmakingState::Joined(game_id);
+			Ok(())
+		}
 
-					HexBoardStorage::<T>::set(player, Some(hex_board));
-				}
+		/// Cancels a staked game that is still waiting for its players. Every listed player can
+		/// cancel it, the players who already joined get their entry fee back.
+		#[pallet::call_index(11)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1).ref_time())]
+		pub fn cancel_staked_game(origin: OriginFor<T>, game_id: GameId) -> DispatchResult {
+			let who: AccountIdOf<T> = ensure_signed(origin)?;
 
-				// Create new game
-				Self::do_create_new_game(
-					game_id,
-					current_block_number,
-					potential_players,
-					grid_size,
-				)?;
+			// Ensures that the Game exists
+			let game = match GameStorage::<T>::get(game_id) {
+				Some(value) => value,
+				None => return Err(Error::<T>::GameNotInitialized.into()),
+			};
+
+			ensure!(game.state == GameState::Matchmaking, Error::<T>::GameNotWaitingForPlayers);
+			ensure!(game.borrow_players().contains(&who), Error::<T>::PlayerNotInGame);
 
-				// Maybe adjust the weight
+			for player in game.borrow_players() {
+				if Self::has_joined(player, game_id) {
+					Self::refund_stake(player);
+
+					HexBoardStorage::<T>::remove(player);
+					Self::deposit_event(Event::HexBoardDeleted { player: player.clone() });
+				}
 			}
 
+			GameStorage::<T>::remove(game_id);
+
+			Self::deposit_event(Event::GameCancelled { game_id });
+
+			Ok(())
+		}
+
+		#[pallet::call_index(100)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1).ref_time())]
+		pub fn queue(origin: OriginFor<T>) -> DispatchResult {
+			let who: AccountIdOf<T> = ensure_signed(origin)?;
+
+			Self::do_queue(who, false)
+		}
+
+		/// Queues for a game in which every player reserves the entry fee. Staked players are
+		/// only matched with each other.
+		#[pallet::call_index(101)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1).ref_time())]
+		pub fn queue_staked(origin: OriginFor<T>) -> DispatchResult {
+			let who: AccountIdOf<T> = ensure_signed(origin)?;
+
+			Self::do_queue(who, true)
+		}
+
+		/// Leaves the matchmaking queue and gives back the entry fee of a staked queue.
+		#[pallet::call_index(102)]
+		#[pallet::weight(10_000 + T::DbWeight::get().reads_writes(1,1).ref_time())]
+		pub fn dequeue(origin: OriginFor<T>) -> DispatchResult {
+			let who: AccountIdOf<T> = ensure_signed(origin)?;
+
+			// Ensures that the HexBoard exists
+			let hex_board = match HexBoardStorage::<T>::get(&who) {
+				Some(value) => value,
+				None => return Err(Error::<T>::HexBoardNotInitialized.into()),
+			};
+
+			ensure!(
+				hex_board.matchmaking_state == MatchmakingState::Matchmaking,
+				Error::<T>::PlayerNotQueued
+			);
+			ensure!(T::Matchmaker::remove_queue(who.clone()), Error::<T>::PlayerNotQueued);
+
+			Self::refund_stake(&who);
+
+			HexBoardStorage::<T>::remove(&who);
+			Self::deposit_event(Event::HexBoardDeleted { player: who });
+
 			Ok(())
 		}
 
@@ -595,6 +717,8 @@ pub mod pallet {
 					}
 				}
 
+				Self::settle_prize_pool(game_id, game.borrow_players(), Some(&who))?;
+
 				Self::deposit_event(Event::GameFinished { game_id });
 			} else {
 				// Handle next turn counting
@@ -629,6 +753,8 @@ pub mod pallet {
 						}
 					}
 
+					Self::settle_prize_pool(game_id, game.borrow_players(), None)?;
+
 					Self::deposit_event(Event::GameFinished { game_id });
 				} else {
 					let next_player = game.borrow_players()[next_player_turn as usize].clone();
@@ -654,6 +780,8 @@ pub mod pallet {
 				None => return Err(Error::<T>::GameNotInitialized.into()),
 			};
 
+			ensure!(game.state == GameState::Playing, Error::<T>::GameNotPlaying);
+
 			ensure!(game.borrow_players().contains(&who), Error::<T>::PlayerNotInGame);
 
 			let current_player = game.borrow_players()[game.get_player_turn() as usize].clone();
@@ -700,6 +828,8 @@ pub mod pallet {
 					HexBoardStorage::<T>::set(player, Some(other_hex_board));
 				}
 
+				Self::settle_prize_pool(game_id, game.borrow_players(), None)?;
+
 				Self::deposit_event(Event::GameFinished { game_id });
 			} else {
 				let next_player = game.borrow_players()[game.get_player_turn() as usize].clone();
@@ -723,18 +853,14 @@ pub mod pallet {
 				None => return Err(Error::<T>::HexBoardNotInitialized.into()),
 			};
 
-			match hex_board.matchmaking_state {
-				MatchmakingState::Finished(Rewards::Winner) => {
-					// Handle giving rewards
-				},
-				MatchmakingState::Finished(Rewards::Draw) => {
-					// Handle giving rewards
-				},
-				MatchmakingState::Finished(Rewards::Loser) => {
-					// Handle giving rewards
-				},
-				_ => return Err(Error::<T>::HexBoardNotInFinishedState.into()),
-			};
+			ensure!(
+				matches!(hex_board.matchmaking_state, MatchmakingState::Finished(_)),
+				Error::<T>::HexBoardNotInFinishedState
+			);
+
+			// The prizes of staked games have been assigned when the game finished, so losers and
+			// players of free games have nothing to claim.
+			Self::pay_prize(&who)?;
 
 			// Clean the HexBoardStorage
 			HexBoardStorage::<T>::remove(&who);
@@ -755,6 +881,19 @@ pub mod pallet {
 			};
 
 			for player in game.borrow_players() {
+				// Players who have not joined a staked game yet may be playing another one.
+				if game.state == GameState::Matchmaking && !Self::has_joined(player, game_id) {
+					continue;
+				}
+
+				// The stakes of a finished game have already been moved into its prize pool.
+				if !matches!(game.state, GameState::Finished { .. }) {
+					Self::refund_stake(player);
+				}
+
+				// The board is removed, so unclaimed prizes can not be claimed anymore.
+				Self::pay_prize(player)?;
+
 				HexBoardStorage::<T>::remove(player);
 			}
 
@@ -787,16 +926,277 @@ pub mod pallet {
 
 // Other helper methods
 impl<T: Config> Pallet<T> {
+	/// Creates a game for the given players. A staked game only reserves the entry fee of the
+	/// creator and waits for the other players to join it.
+	fn do_create_game(
+		who: AccountIdOf<T>,
+		players: Vec<AccountIdOf<T>>,
+		grid_size: u8,
+		staked: bool,
+	) -> Result<(), sp_runtime::DispatchError> {
+		// If you want to play, you need to specify yourself in the Vec as well
+		let number_of_players = players.len();
+
+		ensure!(
+			number_of_players >= T::MinPlayers::get() as usize,
+			Error::<T>::NumberOfPlayersIsTooSmall
+		);
+
+		ensure!(
+			number_of_players <= T::MaxPlayers::get() as usize,
+			Error::<T>::NumberOfPlayersIsTooLarge
+		);
+
+		ensure!(Self::is_valid_grid_size(grid_size), Error::<T>::BadGridSize);
+
+		// Random GameId
+		// I used `who` to ensure that even if 2 independent players wanted to create game in
+		// the same block, they would be able to.
+		let current_block_number = <frame_system::Pallet<T>>::block_number();
+		let seed: &[u8] = &Blake2_256::hash(&(&who, &current_block_number).encode());
+		let game_id: GameId = Blake2_256::hash(&T::Randomness::random(seed).0.encode());
+
+		ensure!(players[0] == who, Error::<T>::CreatorNotInPlayersAtIndexZero);
+
+		// Ensure that the game has not already been created
+		ensure!(!GameStorage::<T>::contains_key(game_id), Error::<T>::GameAlreadyCreated);
+
+		// Players of a staked game join later, so their HexBoards cannot catch duplicates
+		let mut listed = BTreeSet::new();
+		ensure!(players.iter().all(|player| listed.insert(player)), Error::<T>::AlreadyPlaying);
+
+		// The other players of a staked game create their HexBoards when joining it
+		let joined_players = if staked { &players[..1] } else { &players[..] };
+
+		// Initialise HexBoards for all players
+		for player in joined_players {
+			ensure!(!HexBoardStorage::<T>::contains_key(player), Error::<T>::AlreadyPlaying);
+
+			HexBoardStorage::<T>::set(
+				player,
+				Some(
+					HexBoardOf::<T>::try_new::<T::DefaultPlayerResources>(
+						grid_size as usize,
+						MatchmakingState::Joined(game_id),
+					)
+					.ok_or(Error::<T>::InternalError)?,
+				),
+			);
+
+			if staked {
+				Self::reserve_stake(player)?;
+			}
+		}
+
+		let state = if joined_players.len() == number_of_players {
+			GameState::Playing
+		} else {
+			GameState::Matchmaking
+		};
+
+		// Default Game Config
+		Self::do_create_new_game(game_id, current_block_number, players, grid_size, state)
+	}
+
+	/// Queues the player and creates a game once enough players have been matched.
+	fn do_queue(who: AccountIdOf<T>, staked: bool) -> Result<(), sp_runtime::DispatchError> {
+		// Make sure player has no board open.
+		ensure!(!HexBoardStorage::<T>::contains_key(&who), Error::<T>::AlreadyPlaying);
+
+		// Perhaps in the future, we might want to allow players to play on other grid sizes
+		let grid_size: u8 = 25;
+
+		HexBoardStorage::<T>::set(
+			&who,
+			Some(
+				HexBoardOf::<T>::try_new::<T::DefaultPlayerResources>(
+					grid_size as usize,
+					MatchmakingState::Matchmaking,
+				)
+				.ok_or(Error::<T>::InternalError)?,
+			),
+		);
+
+		if staked {
+			Self::reserve_stake(&who)?;
+		}
+
+		// Staked players get their own bracket. This might change with the introduction of ELO
+		let bracket: u8 = staked.into();
+
+		// Add player to queue, duplicate check is done in matchmaker.
+		T::Matchmaker::add_queue(who, bracket)?;
+
+		// Only match players of the same bracket, so staked and free players never meet.
+		let potential_players = T::Matchmaker::try_match_bracket(bracket);
+
+		// if result is not empty we have a valid match
+		if !potential_players.is_empty() {
+			// Random GameId
+			// I used `potential_players` to ensure that even if 2 independent players wanted to
+			// create game in the same block, they would be able to.
+			let current_block_number = <frame_system::Pallet<T>>::block_number();
+			let seed: &[u8] =
+				&Blake2_256::hash(&(&potential_players[0], &current_block_number).encode());
+			// with drand, we get 32-bytes of randomness
+			let game_id: GameId = Blake2_256::hash(&T::Randomness::random(seed).0.encode());
+
+			for player in &potential_players {
+				// Ensures that the HexBoard exists
+				let mut hex_board = match HexBoardStorage::<T>::get(player) {
+					Some(value) => value,
+					None => return Err(Error::<T>::HexBoardNotInitialized.into()),
+				};
+
+				hex_board.matchmaking_state = MatchmakingState::Joined(game_id);
+
+				HexBoardStorage::<T>::set(player, Some(hex_board));
+			}
+
+			// Create new game
+			Self::do_create_new_game(
+				game_id,
+				current_block_number,
+				potential_players,
+				grid_size,
+				GameState::Playing,
+			)?;
+
+			// Maybe adjust the weight
+		}
+
+		Ok(())
+	}
+
+	/// The account that holds the prize pools of finished staked games.
+	pub fn account_id() -> AccountIdOf<T> {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	/// Reserves the entry fee of a staked game from the player.
+	fn reserve_stake(player: &AccountIdOf<T>) -> Result<(), sp_runtime::DispatchError> {
+		let amount = T::EntryFee::get();
+		T::Currency::reserve(player, amount)?;
+		StakeStorage::<T>::mutate(player, |stake| *stake = stake.saturating_add(amount));
+
+		Self::deposit_event(Event::StakeReserved { player: player.clone(), amount });
+
+		Ok(())
+	}
+
+	/// Whether the player has a HexBoard in the given game.
+	fn has_joined(player: &AccountIdOf<T>, game_id: GameId) -> bool {
+		HexBoardStorage::<T>::get(player)
+			.is_some_and(|hex_board| hex_board.get_game_id() == Some(game_id))
+	}
+
+	/// Gives the player back the stake of a game that did not finish.
+	fn refund_stake(player: &AccountIdOf<T>) {
+		let stake = StakeStorage::<T>::take(player);
+
+		if !stake.is_zero() {
+			let amount = stake.saturating_sub(T::Currency::unreserve(player, stake));
+
+			Self::deposit_event(Event::StakeRefunded { player: player.clone(), amount });
+		}
+	}
+
+	/// Collects the stakes of a finished game into the pallet account and assigns the prizes.
+	/// The winner takes the pool, a draw splits it between all players. The house fee, and the
+	/// remainder of a split, are sent to the `HouseFeeDestination`.
+	fn settle_prize_pool(
+		game_id: GameId,
+		players: &[AccountIdOf<T>],
+		winner: Option<&AccountIdOf<T>>,
+	) -> Result<(), sp_runtime::DispatchError> {
+		let pallet_account = Self::account_id();
+		let mut pot = BalanceOf::<T>::zero();
+
+		for player in players {
+			let stake = StakeStorage::<T>::take(player);
+
+			if !stake.is_zero() {
+				let missing = T::Currency::repatriate_reserved(
+					player,
+					&pallet_account,
+					stake,
+					BalanceStatus::Free,
+				)?;
+				pot = pot.saturating_add(stake.saturating_sub(missing));
+			}
+		}
+
+		// Free game
+		if pot.is_zero() {
+			return Ok(());
+		}
+
+		let prize_pool = pot.saturating_sub(T::HouseFee::get() * pot);
+
+		let awarded = match winner {
+			Some(winner) => {
+				Self::add_prize(winner, prize_pool);
+				prize_pool
+			},
+			None => {
+				let number_of_players: BalanceOf<T> = players.len().saturated_into::<u32>().into();
+				let share = prize_pool / number_of_players;
+				for player in players {
+					Self::add_prize(player, share);
+				}
+				share.saturating_mul(number_of_players)
+			},
+		};
+
+		let house_fee = pot.saturating_sub(awarded);
+		if !house_fee.is_zero() {
+			let fee = T::Currency::withdraw(
+				&pallet_account,
+				house_fee,
+				WithdrawReasons::FEE,
+				ExistenceRequirement::KeepAlive,
+			)?;
+			T::HouseFeeDestination::on_unbalanced(fee);
+		}
+
+		Self::deposit_event(Event::PrizePoolSettled { game_id, pot, house_fee });
+
+		Ok(())
+	}
+
+	fn add_prize(player: &AccountIdOf<T>, amount: BalanceOf<T>) {
+		PrizeStorage::<T>::mutate(player, |prize| *prize = prize.saturating_add(amount));
+	}
+
+	/// Pays out the prize of the player. The prize is removed, so it is paid only once.
+	fn pay_prize(player: &AccountIdOf<T>) -> Result<(), sp_runtime::DispatchError> {
+		let amount = PrizeStorage::<T>::take(player);
+
+		if !amount.is_zero() {
+			T::Currency::transfer(
+				&Self::account_id(),
+				player,
+				amount,
+				ExistenceRequirement::KeepAlive,
+			)?;
+
+			Self::deposit_event(Event::PrizePaid { player: player.clone(), amount });
+		}
+
+		Ok(())
+	}
+
 	/// Instancializes a new Game
 	fn do_create_new_game(
 		game_id: GameId,
 		current_block_number: BlockNumberFor<T>,
 		players: Vec<AccountIdOf<T>>,
 		grid_size: u8,
+		state: GameState,
 	) -> Result<(), sp_runtime::DispatchError> {
 		// Default Game Config
 		let mut game = Game {
-			state: GameState::Playing,
+			state,
 			selection_size: 2,
 			round: 0,
 			max_rounds: T::MaxRounds::get(),
@@ -815,40 +1215,31 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
-	/// Helper method that generates a completely new selection from the selection_base
+	/// Helper method that generates a completely new selection from fresh randomness
 	fn new_selection(
 		game: &mut GameOf<T>,
-		selection_base: GameId,
+		game_id: GameId,
 	) -> Result<(), sp_runtime::DispatchError> {
-		// Current random source
-		let current_block_number = <frame_system::Pallet<T>>::block_number();
+		let random_bytes = Self::selection_randomness(game, game_id);
 
 		let mut new_selection: Vec<TileCostIndex> = Default::default();
 
-		let offset = (current_block_number.saturated_into::<u128>() % 32).saturated_into::<u8>();
-
-		for i in 0..game.get_selection_size() {
-			new_selection.push(
-				selection_base[((i + offset) % 32) as usize] %
-					T::TileCosts::get().len().saturated_into::<u8>(),
-			);
+		for i in 0..game.get_selection_size() as usize {
+			new_selection.push(Self::tile_cost_index(&random_bytes, i));
 		}
 
 		// Casting
 		game.selection = new_selection.try_into().map_err(|_| Error::<T>::InternalError)?;
 
-		Self::deposit_event(Event::NewTileSelection {
-			game_id: selection_base,
-			selection: game.selection.clone(),
-		});
+		Self::deposit_event(Event::NewTileSelection { game_id, selection: game.selection.clone() });
 
 		Ok(())
 	}
 
-	/// Helper method that refills the selection
+	/// Helper method that refills the selection from fresh randomness
 	fn refill_selection(
 		game: &mut GameOf<T>,
-		selection_base: GameId,
+		game_id: GameId,
 	) -> Result<(), sp_runtime::DispatchError> {
 		let selection_len = game.selection.len();
 
@@ -857,24 +1248,18 @@ impl<T: Config> Pallet<T> {
 				game.set_selection_size(game.get_selection_size().saturating_add(2));
 			}
 
-			let current_block_number = <frame_system::Pallet<T>>::block_number();
-
-			let offset =
-				(current_block_number.saturated_into::<u128>() % 32).saturated_into::<usize>();
+			let random_bytes = Self::selection_randomness(game, game_id);
 
 			let mut new_selection = game.selection.to_vec();
 
 			for i in selection_len..game.get_selection_size() as usize {
-				new_selection.push(
-					selection_base[(i + offset) % 32] %
-						T::TileCosts::get().len().saturated_into::<u8>(),
-				);
+				new_selection.push(Self::tile_cost_index(&random_bytes, i));
 			}
 
 			game.selection = new_selection.try_into().map_err(|_| Error::<T>::InternalError)?;
 
 			Self::deposit_event(Event::SelectionRefilled {
-				game_id: selection_base,
+				game_id,
 				selection: game.selection.clone(),
 			});
 		}
@@ -882,6 +1267,19 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Draws 32 bytes from the randomness source for the current round and turn of the game.
+	/// The remaining selection is part of the subject, so that several refills within one turn
+	/// draw different tiles.
+	fn selection_randomness(game: &GameOf<T>, game_id: GameId) -> [u8; 32] {
+		let subject = (game_id, game.get_round(), game.get_player_turn(), &game.selection).encode();
+		Blake2_256::hash(&T::Randomness::random(&subject).0.encode())
+	}
+
+	/// Maps the `i`th random byte to a tile cost index.
+	fn tile_cost_index(random_bytes: &[u8; 32], i: usize) -> TileCostIndex {
+		random_bytes[i % 32] % T::TileCosts::get().len().saturated_into::<u8>()
+	}
+
 	/// Helper method that determines if the user can buy a piece from the active selection
 	fn buy_from_selection(
 		selection: &mut TileSelectionOf<T>,
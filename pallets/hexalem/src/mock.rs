@@ -1,7 +1,8 @@
 use crate as pallet_hexalem;
 use frame_support::{
 	parameter_types,
-	traits::{ConstU16, ConstU64, Get},
+	traits::{ConstU16, ConstU32, ConstU64, Currency, Get, OnUnbalanced, Randomness},
+	PalletId,
 };
 use pallet_hexalem::{
 	GetTileInfo, ResourceAmount, ResourceProductions, ResourceType, ResourceUnit, TileCost,
@@ -11,8 +12,8 @@ use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_core::H256;
 use sp_runtime::{
-	traits::{BlakeTwo256, IdentityLookup},
-	BuildStorage,
+	traits::{BlakeTwo256, Hash, IdentityLookup},
+	BuildStorage, Perbill,
 };
 
 type Block = frame_system::mocking::MockBlock<TestRuntime>;
@@ -195,6 +196,10 @@ parameter_types! {
 
 	pub const HexalemTargetGoalGold: u8 = 10u8;
 	pub const HexalemTargetGoalHuman: u8 = 7u8;
+
+	pub const HexalemPalletId: PalletId = PalletId(*b"py/hxlem");
+	pub const HexalemEntryFee: u64 = 100;
+	pub const HexalemHouseFee: Perbill = Perbill::from_percent(10);
 }
 
 // Configure a mock runtime to test the pallet.
@@ -202,6 +207,7 @@ frame_support::construct_runtime!(
 	pub enum TestRuntime
 	{
 		System: frame_system,
+		Balances: pallet_balances,
 		HexalemModule: pallet_hexalem,
 		MatchmakerModule: pallet_matchmaker,
 		EloModule: pallet_elo,
@@ -243,13 +249,45 @@ impl frame_system::Config for TestRuntime {
 	type BlockHashCount = ConstU64<250>;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u64>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
 	type SS58Prefix = ConstU16<42>;
 	type OnSetCode = ();
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type RuntimeTask = ();
+}
+
+impl pallet_balances::Config for TestRuntime {
+	type Balance = u64;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = [u8; 8];
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+}
+
+parameter_types! {
+	// The latest pulse of the mock beacon
+	pub storage BeaconPulse: u64 = 0;
+}
+
+/// Stand-in for the drand beacon. The output only depends on the latest pulse and the subject,
+/// not on the block number.
+pub struct MockBeacon;
+
+impl Randomness<H256, u64> for MockBeacon {
+	fn random(subject: &[u8]) -> (H256, u64) {
+		(BlakeTwo256::hash_of(&(BeaconPulse::get(), subject)), System::block_number())
+	}
 }
 
 impl pallet_hexalem::Config for TestRuntime {
@@ -273,12 +311,36 @@ impl pallet_hexalem::Config for TestRuntime {
 	type TargetGoalHuman = HexalemTargetGoalHuman;
 	type Matchmaker = MatchmakerModule;
 	type Elo = EloModule;
+	type Randomness = MockBeacon;
+	type Currency = Balances;
+	type PalletId = HexalemPalletId;
+	type EntryFee = HexalemEntryFee;
+	type HouseFee = HexalemHouseFee;
+	type HouseFeeDestination = DepositToHouse;
+}
+
+pub const HOUSE: u64 = 100;
+
+/// Deposits the house fees into the `HOUSE` account.
+pub struct DepositToHouse;
+
+impl OnUnbalanced<pallet_hexalem::NegativeImbalanceOf<TestRuntime>> for DepositToHouse {
+	fn on_nonzero_unbalanced(amount: pallet_hexalem::NegativeImbalanceOf<TestRuntime>) {
+		Balances::resolve_creating(&HOUSE, amount);
+	}
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	frame_system::GenesisConfig::<TestRuntime>::default()
-		.build_storage()
-		.unwrap()
-		.into()
+	let mut storage =
+		frame_system::GenesisConfig::<TestRuntime>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<TestRuntime> {
+		balances: (1..=4).map(|player| (player, 1_000)).collect(),
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+	pallet_hexalem::GenesisConfig::<TestRuntime>::default()
+		.assimilate_storage(&mut storage)
+		.unwrap();
+	storage.into()
 }
@@ -1,5 +1,5 @@
 use crate::{mock::*, types::*, Event, *};
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_err, assert_noop, assert_ok};
 use pallet_elo::Event as EloEvent;
 
 #[test]
@@ -654,6 +654,12 @@ fn play_pattern() {
 
 		let game_id: GameId = hex_board.get_game_id().unwrap();
 
+		// Offer two caves, followed by water tiles for the later moves
+		let mut game = GameStorage::<TestRuntime>::get(game_id).unwrap();
+		game.selection = vec![13, 13, 3, 3, 3].try_into().unwrap();
+		game.set_selection_size(6);
+		HexalemModule::set_game(game_id, game);
+
 		// Set player resources to 0
 		HexalemModule::set_hex_board(
 			1,
@@ -1316,3 +1322,357 @@ fn clean_hex_board_storage() {
 		assert_ok!(HexalemModule::receive_rewards(RuntimeOrigin::signed(3)));
 	});
 }
+
+/// Gives the player enough humans and gold to win on their next finished turn.
+fn prepare_win(player: u64) {
+	let mut hex_board = HexBoardStorage::<TestRuntime>::get(player).unwrap();
+	hex_board.resources = [99; NUMBER_OF_RESOURCE_TYPES];
+	hex_board.hex_grid[12] = HexalemTile::new(TileType::Home, 3, TilePattern::Normal);
+	HexalemModule::set_hex_board(player, hex_board);
+}
+
+#[test]
+fn staked_game_winner_takes_prize_pool() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(HexalemModule::create_staked_game(RuntimeOrigin::signed(1), vec![1, 2], 25));
+
+		let game_id: GameId =
+			HexBoardStorage::<TestRuntime>::get(1).unwrap().get_game_id().unwrap();
+		assert_ok!(HexalemModule::join_staked_game(RuntimeOrigin::signed(2), game_id));
+
+		System::assert_has_event(Event::StakeReserved { player: 2, amount: 100 }.into());
+		System::assert_has_event(Event::GameStarted { game_id }.into());
+		assert_eq!(Balances::reserved_balance(1), 100);
+		assert_eq!(Balances::reserved_balance(2), 100);
+
+		prepare_win(1);
+		assert_ok!(HexalemModule::finish_turn(RuntimeOrigin::signed(1)));
+
+		System::assert_has_event(
+			Event::PrizePoolSettled { game_id, pot: 200, house_fee: 20 }.into(),
+		);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(PrizeStorage::<TestRuntime>::get(1), 180);
+
+		assert_ok!(HexalemModule::receive_rewards(RuntimeOrigin::signed(1)));
+		System::assert_has_event(Event::PrizePaid { player: 1, amount: 180 }.into());
+		assert_eq!(Balances::free_balance(1), 1_080);
+
+		assert_ok!(HexalemModule::receive_rewards(RuntimeOrigin::signed(2)));
+		assert_eq!(Balances::free_balance(2), 900);
+
+		// The prize is only paid once.
+		assert_noop!(
+			HexalemModule::receive_rewards(RuntimeOrigin::signed(1)),
+			Error::<TestRuntime>::HexBoardNotInitialized
+		);
+		// The pallet account keeps the existential deposit it got at genesis.
+		assert_eq!(Balances::free_balance(HexalemModule::account_id()), 1);
+		assert_eq!(Balances::free_balance(HOUSE), 20);
+	});
+}
+
+#[test]
+fn staked_game_draw_splits_prize_pool() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(HexalemModule::create_staked_game(RuntimeOrigin::signed(1), vec![1, 2, 3], 25));
+
+		let game_id: GameId =
+			HexBoardStorage::<TestRuntime>::get(1).unwrap().get_game_id().unwrap();
+		assert_ok!(HexalemModule::join_staked_game(RuntimeOrigin::signed(2), game_id));
+		assert_ok!(HexalemModule::join_staked_game(RuntimeOrigin::signed(3), game_id));
+
+		for _ in 0..<mock::TestRuntime as pallet::Config>::MaxRounds::get() {
+			assert_ok!(HexalemModule::finish_turn(RuntimeOrigin::signed(1)));
+			assert_ok!(HexalemModule::finish_turn(RuntimeOrigin::signed(2)));
+			assert_ok!(HexalemModule::finish_turn(RuntimeOrigin::signed(3)));
+		}
+
+		for player in 1..=3 {
+			assert_eq!(PrizeStorage::<TestRuntime>::get(player), 90);
+			assert_ok!(HexalemModule::receive_rewards(RuntimeOrigin::signed(player)));
+			assert_eq!(Balances::free_balance(player), 990);
+		}
+
+		assert_eq!(Balances::free_balance(HexalemModule::account_id()), 1);
+		assert_eq!(Balances::free_balance(HOUSE), 30);
+	});
+}
+
+#[test]
+fn free_game_has_no_prize_pool() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(HexalemModule::create_game(RuntimeOrigin::signed(1), vec![1, 2], 25));
+		assert_eq!(Balances::reserved_balance(1), 0);
+
+		prepare_win(1);
+		assert_ok!(HexalemModule::finish_turn(RuntimeOrigin::signed(1)));
+		assert_eq!(PrizeStorage::<TestRuntime>::get(1), 0);
+
+		assert_ok!(HexalemModule::receive_rewards(RuntimeOrigin::signed(1)));
+		assert_eq!(Balances::free_balance(1), 1_000);
+	});
+}
+
+#[test]
+fn root_delete_game_refunds_stakes() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		// Free and staked players are not matched with each other.
+		assert_ok!(HexalemModule::queue(RuntimeOrigin::signed(3)));
+		assert_ok!(HexalemModule::queue_staked(RuntimeOrigin::signed(1)));
+		assert_eq!(MatchmakerModule::queue_size(0), 1);
+		assert_eq!(MatchmakerModule::queue_size(1), 1);
+
+		assert_ok!(HexalemModule::queue_staked(RuntimeOrigin::signed(2)));
+
+		let game_id: GameId =
+			HexBoardStorage::<TestRuntime>::get(1).unwrap().get_game_id().unwrap();
+		assert_eq!(Balances::reserved_balance(1), 100);
+		assert_eq!(Balances::reserved_balance(3), 0);
+
+		assert_ok!(HexalemModule::root_delete_game(RuntimeOrigin::root(), game_id));
+
+		System::assert_has_event(Event::StakeRefunded { player: 1, amount: 100 }.into());
+		System::assert_has_event(Event::StakeRefunded { player: 2, amount: 100 }.into());
+		assert_eq!(Balances::free_balance(1), 1_000);
+		assert_eq!(Balances::free_balance(2), 1_000);
+		assert_eq!(StakeStorage::<TestRuntime>::get(1), 0);
+
+		// The free player is still waiting for a free game.
+		assert_eq!(MatchmakerModule::queue_size(0), 1);
+		assert_eq!(
+			HexBoardStorage::<TestRuntime>::get(3).unwrap().matchmaking_state,
+			MatchmakingState::Matchmaking
+		);
+	});
+}
+
+#[test]
+fn staked_game_waits_for_players_to_join() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(HexalemModule::create_staked_game(RuntimeOrigin::signed(1), vec![1, 2, 3], 25));
+
+		// Only the creator's stake is reserved.
+		assert_eq!(Balances::reserved_balance(1), 100);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert!(HexBoardStorage::<TestRuntime>::get(2).is_none());
+
+		let game_id: GameId =
+			HexBoardStorage::<TestRuntime>::get(1).unwrap().get_game_id().unwrap();
+		assert_eq!(GameStorage::<TestRuntime>::get(game_id).unwrap().state, GameState::Matchmaking);
+
+		assert_noop!(
+			HexalemModule::finish_turn(RuntimeOrigin::signed(1)),
+			Error::<TestRuntime>::GameNotPlaying
+		);
+		assert_noop!(
+			HexalemModule::join_staked_game(RuntimeOrigin::signed(4), game_id),
+			Error::<TestRuntime>::PlayerNotInGame
+		);
+
+		assert_ok!(HexalemModule::join_staked_game(RuntimeOrigin::signed(2), game_id));
+		System::assert_has_event(Event::PlayerJoined { game_id, player: 2 }.into());
+		assert_eq!(Balances::reserved_balance(2), 100);
+		assert_noop!(
+			HexalemModule::join_staked_game(RuntimeOrigin::signed(2), game_id),
+			Error::<TestRuntime>::AlreadyPlaying
+		);
+		assert_eq!(GameStorage::<TestRuntime>::get(game_id).unwrap().state, GameState::Matchmaking);
+
+		System::set_block_number(5);
+		assert_ok!(HexalemModule::join_staked_game(RuntimeOrigin::signed(3), game_id));
+
+		let game = GameStorage::<TestRuntime>::get(game_id).unwrap();
+		assert_eq!(game.state, GameState::Playing);
+		assert_eq!(game.last_played_block, 5);
+		assert_noop!(
+			HexalemModule::join_staked_game(RuntimeOrigin::signed(3), game_id),
+			Error::<TestRuntime>::GameNotWaitingForPlayers
+		);
+		assert_ok!(HexalemModule::finish_turn(RuntimeOrigin::signed(1)));
+	});
+}
+
+#[test]
+fn force_finish_turn_waits_for_players_to_join() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(HexalemModule::create_staked_game(RuntimeOrigin::signed(1), vec![1, 2], 25));
+
+		let game_id: GameId =
+			HexBoardStorage::<TestRuntime>::get(1).unwrap().get_game_id().unwrap();
+
+		System::set_block_number(100);
+
+		assert_noop!(
+			HexalemModule::force_finish_turn(RuntimeOrigin::signed(2), game_id),
+			Error::<TestRuntime>::GameNotPlaying
+		);
+
+		assert_ok!(HexalemModule::join_staked_game(RuntimeOrigin::signed(2), game_id));
+
+		// Joining starts the turn of the first player.
+		assert_noop!(
+			HexalemModule::force_finish_turn(RuntimeOrigin::signed(2), game_id),
+			Error::<TestRuntime>::BlocksToPlayLimitNotPassed
+		);
+
+		System::set_block_number(111);
+
+		assert_ok!(HexalemModule::force_finish_turn(RuntimeOrigin::signed(2), game_id));
+	});
+}
+
+#[test]
+fn staked_game_rejects_duplicate_players() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			HexalemModule::create_staked_game(RuntimeOrigin::signed(1), vec![1, 2, 1], 25),
+			Error::<TestRuntime>::AlreadyPlaying
+		);
+	});
+}
+
+#[test]
+fn cancel_staked_game_refunds_joined_players() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(HexalemModule::create_staked_game(RuntimeOrigin::signed(1), vec![1, 2, 3], 25));
+
+		let game_id: GameId =
+			HexBoardStorage::<TestRuntime>::get(1).unwrap().get_game_id().unwrap();
+		assert_ok!(HexalemModule::join_staked_game(RuntimeOrigin::signed(2), game_id));
+
+		assert_noop!(
+			HexalemModule::cancel_staked_game(RuntimeOrigin::signed(4), game_id),
+			Error::<TestRuntime>::PlayerNotInGame
+		);
+
+		// A player who has not joined yet can still decline the game.
+		assert_ok!(HexalemModule::cancel_staked_game(RuntimeOrigin::signed(3), game_id));
+
+		System::assert_has_event(Event::GameCancelled { game_id }.into());
+		System::assert_has_event(Event::StakeRefunded { player: 1, amount: 100 }.into());
+		System::assert_has_event(Event::StakeRefunded { player: 2, amount: 100 }.into());
+		assert_eq!(Balances::free_balance(1), 1_000);
+		assert_eq!(Balances::free_balance(2), 1_000);
+		assert!(GameStorage::<TestRuntime>::get(game_id).is_none());
+		assert!(HexBoardStorage::<TestRuntime>::get(1).is_none());
+		assert!(HexBoardStorage::<TestRuntime>::get(2).is_none());
+	});
+}
+
+#[test]
+fn dequeue_refunds_stake() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_noop!(
+			HexalemModule::dequeue(RuntimeOrigin::signed(1)),
+			Error::<TestRuntime>::HexBoardNotInitialized
+		);
+
+		assert_ok!(HexalemModule::queue_staked(RuntimeOrigin::signed(1)));
+		assert_eq!(Balances::reserved_balance(1), 100);
+
+		assert_ok!(HexalemModule::dequeue(RuntimeOrigin::signed(1)));
+
+		System::assert_has_event(Event::StakeRefunded { player: 1, amount: 100 }.into());
+		System::assert_has_event(Event::HexBoardDeleted { player: 1 }.into());
+		assert_eq!(Balances::free_balance(1), 1_000);
+		assert_eq!(MatchmakerModule::queue_size(1), 0);
+		assert!(HexBoardStorage::<TestRuntime>::get(1).is_none());
+
+		// The player can queue again, and is matched with the next staked player.
+		assert_ok!(HexalemModule::queue_staked(RuntimeOrigin::signed(1)));
+		assert_ok!(HexalemModule::queue_staked(RuntimeOrigin::signed(2)));
+		assert_noop!(
+			HexalemModule::dequeue(RuntimeOrigin::signed(1)),
+			Error::<TestRuntime>::PlayerNotQueued
+		);
+	});
+}
+
+#[test]
+fn staked_game_fails_without_entry_fee() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_err!(
+			HexalemModule::create_staked_game(RuntimeOrigin::signed(5), vec![5, 1], 25),
+			pallet_balances::Error::<TestRuntime>::InsufficientBalance
+		);
+
+		assert_ok!(HexalemModule::create_staked_game(RuntimeOrigin::signed(1), vec![1, 5], 25));
+
+		let game_id: GameId =
+			HexBoardStorage::<TestRuntime>::get(1).unwrap().get_game_id().unwrap();
+		assert_err!(
+			HexalemModule::join_staked_game(RuntimeOrigin::signed(5), game_id),
+			pallet_balances::Error::<TestRuntime>::InsufficientBalance
+		);
+	});
+}
+
+/// Plays the first round of a game, reaching the refill at the given block. Returns the initial
+/// selection, the refilled one and the one drawn for the player that did not play.
+fn first_round_selections(block_number: u64, pulse: u64) -> Vec<TileSelectionOf<TestRuntime>> {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		BeaconPulse::set(&pulse);
+
+		assert_ok!(HexalemModule::create_game(RuntimeOrigin::signed(1), vec![1, 2], 25));
+
+		let game_id: GameId =
+			HexBoardStorage::<TestRuntime>::get(1).unwrap().get_game_id().unwrap();
+		let mut selections = vec![GameStorage::<TestRuntime>::get(game_id).unwrap().selection];
+
+		System::set_block_number(block_number);
+
+		assert_ok!(HexalemModule::play(
+			RuntimeOrigin::signed(1),
+			Move { place_index: 11, buy_index: 0 }
+		));
+		selections.push(GameStorage::<TestRuntime>::get(game_id).unwrap().selection);
+
+		assert_ok!(HexalemModule::finish_turn(RuntimeOrigin::signed(1)));
+		assert_ok!(HexalemModule::finish_turn(RuntimeOrigin::signed(2)));
+		selections.push(GameStorage::<TestRuntime>::get(game_id).unwrap().selection);
+
+		System::assert_has_event(
+			Event::NewTileSelection { game_id, selection: selections[2].clone() }.into(),
+		);
+
+		selections
+	})
+}
+
+#[test]
+fn tile_selections_are_reproducible() {
+	let selections = first_round_selections(2, 0);
+
+	assert_eq!(selections[1].len(), 4);
+	assert_eq!(selections[1][0], selections[0][1]);
+
+	// The block number, at which a turn is finished, does not change the drawn tiles.
+	assert_eq!(first_round_selections(2, 0), selections);
+	assert_eq!(first_round_selections(31, 0), selections);
+
+	// A new pulse of the beacon does.
+	assert_ne!(first_round_selections(2, 1), selections);
+}
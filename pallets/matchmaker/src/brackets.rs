@@ -9,7 +9,7 @@
 //! types and just returns the boxed trait object.
 use core::marker::PhantomData;
 use frame_support::storage::{StorageDoubleMap, StorageMap, StorageValue};
-use parity_scale_codec::{Codec, EncodeLike};
+use parity_scale_codec::{Codec, Encode, EncodeLike};
 use sp_std::vec::Vec;
 
 /// Trait object presenting the brackets interface.
@@ -30,6 +30,10 @@ where
 	///
 	/// Returns `None` if the queue is empty.
 	fn pop(&mut self, b: Bracket) -> Option<Item>;
+	/// Remove an item from whichever queue holds it, keeping the order of the others.
+	///
+	/// Returns `false` if the item_key is not queued.
+	fn remove(&mut self, j: ItemKey) -> bool;
 	/// Return whether the queue is empty.
 	fn is_empty(&self, b: Bracket) -> bool;
 	/// Return the size of the brackets queue.
@@ -192,6 +196,41 @@ where
 			})
 	}
 
+	/// Remove an item from whichever queue holds it, keeping the order of the others.
+	///
+	/// Will remove the item, but will not update the bounds in storage.
+	fn remove(&mut self, item_key: ItemKey) -> bool {
+		let bracket = match (0..self.index_vector.len() as Bracket)
+			.find(|bracket| N::contains_key(*bracket, &item_key))
+		{
+			Some(bracket) => bracket,
+			None => return false,
+		};
+
+		let (v_start, v_end) = self.index_vector[bracket as usize];
+		let encoded_key = Some(item_key.encode());
+
+		// find the slot holding the item key
+		let mut index = v_start;
+		while index != v_end && M::get(bracket, index).map(|key| key.encode()) != encoded_key {
+			index = index.wrapping_add(1 as u16);
+		}
+
+		// shift every later item key one slot towards the start
+		while index.wrapping_add(1 as u16) != v_end {
+			let next_index = index.wrapping_add(1 as u16);
+			if let Some(next_key) = M::take(bracket, next_index) {
+				M::insert(bracket, index, next_key);
+			}
+			index = next_index;
+		}
+		M::remove(bracket, index);
+		N::remove(bracket, &item_key);
+
+		self.index_vector[bracket as usize] = (v_start, v_end.wrapping_sub(1 as u16));
+		true
+	}
+
 	/// Return whether to consider the queue empty.
 	fn is_empty(&self, bracket: Bracket) -> bool {
 		let (v_start, v_end) = self.index_vector[bracket as usize];
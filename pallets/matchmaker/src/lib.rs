@@ -45,9 +45,10 @@ pub mod pallet {
 
 	/// Configure the pallet by specifying the parameters and types on which it depends.
 	#[pallet::config]
-	pub trait Config: frame_system::Config {
+	pub trait Config<I: 'static = ()>: frame_system::Config {
 		/// Because this pallet emits events, it depends on the runtime's definition of an event.
-		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		type RuntimeEvent: From<Event<Self, I>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
 		/// Constant that indicates how many players are needed to create a new match.
 		#[pallet::constant]
@@ -59,37 +60,38 @@ pub mod pallet {
 	}
 
 	#[pallet::pallet]
-	pub struct Pallet<T>(_);
+	pub struct Pallet<T, I = ()>(_);
 
 	#[pallet::type_value]
-	pub fn BracketsCountDefault<T: Config>() -> u8 {
+	pub fn BracketsCountDefault<T: Config<I>, I: 'static>() -> u8 {
 		T::AmountBrackets::get()
 	}
 
 	#[pallet::storage]
 	#[pallet::getter(fn brackets_count)]
-	pub type BracketsCount<T: Config> = StorageValue<_, u8, ValueQuery, BracketsCountDefault<T>>;
+	pub type BracketsCount<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, u8, ValueQuery, BracketsCountDefault<T, I>>;
 
 	// Default value for Nonce
 	#[pallet::type_value]
-	pub fn BracketIndicesDefault<T: Config>() -> (BufferIndex, BufferIndex) {
+	pub fn BracketIndicesDefault<T: Config<I>, I: 'static>() -> (BufferIndex, BufferIndex) {
 		(0, 0)
 	}
 
 	#[pallet::storage]
 	#[pallet::getter(fn indices)]
-	pub type BracketIndices<T: Config> = StorageMap<
+	pub type BracketIndices<T: Config<I>, I: 'static = ()> = StorageMap<
 		_,
 		Blake2_128Concat,
 		Bracket,
 		(BufferIndex, BufferIndex),
 		ValueQuery,
-		BracketIndicesDefault<T>,
+		BracketIndicesDefault<T, I>,
 	>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn index_key)]
-	pub type BracketIndexKeyMap<T: Config> = StorageDoubleMap<
+	pub type BracketIndexKeyMap<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
 		_,
 		Blake2_128Concat,
 		Bracket,
@@ -101,7 +103,7 @@ pub mod pallet {
 
 	#[pallet::storage]
 	#[pallet::getter(fn key_value)]
-	pub type BracketKeyValueMap<T: Config> = StorageDoubleMap<
+	pub type BracketKeyValueMap<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
 		_,
 		Blake2_128Concat,
 		Bracket,
@@ -115,25 +117,27 @@ pub mod pallet {
 	// https://substrate.dev/docs/en/knowledgebase/runtime/events
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
-	pub enum Event<T: Config> {
+	pub enum Event<T: Config<I>, I: 'static = ()> {
 		/// Queued event
 		Queued(PlayerStruct<T::AccountId>),
 		/// Popped event
 		Popped(PlayerStruct<T::AccountId>),
+		/// Removed event
+		Removed(PlayerStruct<T::AccountId>),
 	}
 
 	// Errors inform users that something went wrong.
 	#[pallet::error]
-	pub enum Error<T> {
+	pub enum Error<T, I = ()> {
 		/// Player has already queued, can not queue twice
 		AlreadyQueued,
 	}
 
 	#[pallet::call]
-	impl<T: Config> Pallet<T> {}
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {}
 }
 
-impl<T: Config> Pallet<T> {
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// Constructor function so we don't have to specify the types every time.
 	///
 	/// Constructs a ringbuffer transient and returns it as a boxed trait object.
@@ -142,10 +146,10 @@ impl<T: Config> Pallet<T> {
 		Box::new(BracketsTransient::<
 			T::AccountId,
 			PlayerStruct<T::AccountId>,
-			BracketsCount<T>,
-			BracketIndices<T>,
-			BracketIndexKeyMap<T>,
-			BracketKeyValueMap<T>,
+			BracketsCount<T, I>,
+			BracketIndices<T, I>,
+			BracketIndexKeyMap<T, I>,
+			BracketKeyValueMap<T, I>,
 		>::new())
 	}
 
@@ -155,7 +159,7 @@ impl<T: Config> Pallet<T> {
 		let player = PlayerStruct { account };
 		// duplicate check if we can add key to the queue
 		if !queue.push(bracket, player.account.clone(), player.clone()) {
-			return Err(Error::<T>::AlreadyQueued.into());
+			return Err(Error::<T, I>::AlreadyQueued.into());
 		}
 
 		Self::deposit_event(Event::Queued(player));
@@ -217,6 +221,36 @@ impl<T: Config> Pallet<T> {
 		result
 	}
 
+	fn do_try_match_bracket(bracket: u8) -> Vec<T::AccountId> {
+		let mut queue = Self::queue_transient();
+		let max_players = T::AmountPlayers::get();
+
+		let mut result: Vec<T::AccountId> = Vec::new();
+		// only match players waiting in the same bracket
+		if queue.size(bracket) < max_players as BufferIndex {
+			return result;
+		}
+
+		for _ in 0..max_players {
+			if let Some(p) = queue.pop(bracket) {
+				result.push(p.account.clone());
+				Self::deposit_event(Event::Popped(p));
+			}
+		}
+		// return result
+		result
+	}
+
+	fn do_remove_queue(account: T::AccountId) -> bool {
+		if !Self::queue_transient().remove(account.clone()) {
+			return false;
+		}
+
+		Self::deposit_event(Event::Removed(PlayerStruct { account }));
+
+		true
+	}
+
 	fn do_is_queued(account: T::AccountId) -> bool {
 		Self::queue_transient().is_queued(account)
 	}
@@ -238,7 +272,7 @@ impl<T: Config> Pallet<T> {
 	}
 }
 
-impl<T: Config> MatchFunc<T::AccountId> for Pallet<T> {
+impl<T: Config<I>, I: 'static> MatchFunc<T::AccountId> for Pallet<T, I> {
 	fn empty_queue(bracket: u8) {
 		Self::do_empty_queue(bracket);
 	}
@@ -255,6 +289,14 @@ impl<T: Config> MatchFunc<T::AccountId> for Pallet<T> {
 		Self::do_try_match()
 	}
 
+	fn try_match_bracket(bracket: u8) -> Vec<T::AccountId> {
+		Self::do_try_match_bracket(bracket)
+	}
+
+	fn remove_queue(account: T::AccountId) -> bool {
+		Self::do_remove_queue(account)
+	}
+
 	fn is_queued(account: T::AccountId) -> bool {
 		Self::do_is_queued(account)
 	}
@@ -281,6 +323,12 @@ pub trait MatchFunc<AccountId> {
 	/// try create a match
 	fn try_match() -> Vec<AccountId>;
 
+	/// try create a match only from players of the given bracket
+	fn try_match_bracket(bracket: u8) -> Vec<AccountId>;
+
+	/// return true if the account was queued and has been removed
+	fn remove_queue(account: AccountId) -> bool;
+
 	// return true if an account is queued in any bracket
 	fn is_queued(account: AccountId) -> bool;
 
@@ -111,3 +111,37 @@ fn test_brackets() {
 		assert_eq!(MatchMaker::do_try_match(), [5, 6]);
 	});
 }
+
+#[test]
+fn test_remove_queue() {
+	new_test_ext().execute_with(|| {
+		assert!(!MatchMaker::do_remove_queue(1));
+		assert_eq!(MatchMaker::do_add_queue(1, 0), Ok(()));
+		assert_eq!(MatchMaker::do_add_queue(2, 0), Ok(()));
+		assert_eq!(MatchMaker::do_add_queue(3, 0), Ok(()));
+		assert!(MatchMaker::do_remove_queue(2));
+		assert!(!MatchMaker::do_is_queued(2));
+		assert_eq!(MatchMaker::do_queue_size(0), 2);
+		assert!(!MatchMaker::do_remove_queue(2));
+		// the remaining players keep their order
+		assert_eq!(MatchMaker::do_add_queue(2, 0), Ok(()));
+		assert_eq!(MatchMaker::do_try_match(), [1, 3]);
+		assert!(MatchMaker::do_remove_queue(2));
+		assert_eq!(MatchMaker::do_all_queue_size(), 0);
+		assert!(MatchMaker::do_try_match().is_empty());
+	});
+}
+
+#[test]
+fn test_try_match_bracket() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(MatchMaker::do_add_queue(1, 0), Ok(()));
+		assert_eq!(MatchMaker::do_add_queue(2, 1), Ok(()));
+		assert!(MatchMaker::do_try_match_bracket(0).is_empty());
+		assert!(MatchMaker::do_try_match_bracket(1).is_empty());
+		assert_eq!(MatchMaker::do_add_queue(3, 1), Ok(()));
+		assert_eq!(MatchMaker::do_try_match_bracket(1), [2, 3]);
+		assert_eq!(MatchMaker::do_queue_size(0), 1);
+		assert!(MatchMaker::do_try_match_bracket(1).is_empty());
+	});
+}
@@ -3,6 +3,9 @@
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
+#[cfg(test)]
+mod tests;
+
 use codec::Encode;
 use pallet_grandpa::AuthorityId as GrandpaId;
 use sp_api::impl_runtime_apis;
@@ -20,8 +23,14 @@ use sp_std::prelude::*;
 use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
 
-use frame_support::PalletId;
+use frame_support::{
+	instances::Instance1,
+	traits::{AsEnsureOriginWithArg, EitherOfDiverse},
+	PalletId,
+};
 use frame_system::{EnsureRoot, EnsureSigned};
+use pallet_game::{EnsureRole, Role};
+use pallet_nfts::PalletFeatures;
 
 pub use frame_support::{
 	dynamic_params::{dynamic_pallet_params, dynamic_params},
@@ -48,7 +57,7 @@ pub use pallet_timestamp::Call as TimestampCall;
 use pallet_transaction_payment::{ConstFeeMultiplier, FungibleAdapter, Multiplier};
 #[cfg(any(feature = "std", test))]
 pub use sp_runtime::BuildStorage;
-pub use sp_runtime::{Perbill, Permill};
+pub use sp_runtime::{Perbill, Percent, Permill};
 
 use sp_core::crypto::UncheckedFrom;
 
@@ -127,7 +136,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 101,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -366,6 +375,53 @@ impl pallet_contracts::Config for Runtime {
 	type MaxTransientStorageSize = ();
 }
 
+parameter_types! {
+	pub const NftsCollectionDeposit: Balance = 10 * DOLLARS;
+	pub const NftsItemDeposit: Balance = DOLLARS / 100;
+	pub const NftsMetadataDepositBase: Balance = deposit(1, 129);
+	pub const NftsAttributeDepositBase: Balance = deposit(1, 0);
+	pub const NftsDepositPerByte: Balance = deposit(0, 1);
+	pub const NftsApprovalsLimit: u32 = 20;
+	pub const NftsItemAttributesApprovalsLimit: u32 = 20;
+	pub const NftsMaxTips: u32 = 10;
+	pub const NftsMaxDeadlineDuration: BlockNumber = 12 * 30 * DAYS;
+	pub const NftsMaxAttributesPerCall: u32 = 10;
+	pub NftsFeatures: PalletFeatures = PalletFeatures::all_enabled();
+}
+
+/// The nfts of the game, the game pallet creates a collection for every color each round.
+impl pallet_nfts::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = u32;
+	type ItemId = u32;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type Locker = ();
+	type CollectionDeposit = NftsCollectionDeposit;
+	type ItemDeposit = NftsItemDeposit;
+	type MetadataDepositBase = NftsMetadataDepositBase;
+	type AttributeDepositBase = NftsAttributeDepositBase;
+	type DepositPerByte = NftsDepositPerByte;
+	type StringLimit = ConstU32<256>;
+	type KeyLimit = ConstU32<64>;
+	type ValueLimit = ConstU32<256>;
+	type ApprovalsLimit = NftsApprovalsLimit;
+	type ItemAttributesApprovalsLimit = NftsItemAttributesApprovalsLimit;
+	type MaxTips = NftsMaxTips;
+	type MaxDeadlineDuration = NftsMaxDeadlineDuration;
+	type MaxAttributesPerCall = NftsMaxAttributesPerCall;
+	type Features = NftsFeatures;
+	type OffchainSignature = Signature;
+	type OffchainPublic = <Signature as Verify>::Signer;
+	type WeightInfo = pallet_nfts::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type Helper = ();
+}
+
+/// The maximum amount of properties in the catalogue of the game.
+pub type MaxProperties = ParameterGet<100>;
+
 parameter_types! {
 	pub const GamePalletId: PalletId = PalletId(*b"py/rlxdl");
 	pub const MaxOngoingGame: u32 = 200;
@@ -373,6 +429,28 @@ parameter_types! {
 	pub const MaxAdmin: u32 = 10;
 	pub const RequestLimits: BlockNumber = 100800;
 	pub const GameStringLimit: u32 = 500;
+	pub const GameRevealDelay: pallet_game::RoundNumber = 40;
+	pub const GameInitialPoints: u32 = 50;
+	pub const GameMaxPrizes: u32 = 10;
+	pub const GameMarketplaceFee: Percent = Percent::from_percent(5);
+	pub const GameListingDuration: BlockNumber = 7 * DAYS;
+	pub const GameSettlementPeriod: BlockNumber = DAYS;
+	pub const GamePropertyPoolThreshold: u32 = 20;
+	pub const GameMaxLobbyPlayers: u32 = 8;
+	pub const GameLobbyMatchSize: u8 = 4;
+	pub const GameLobbyBrackets: u8 = 2;
+	pub const GameFaucetAmount: Balance = 100 * DOLLARS;
+	pub const GameRegistrationDeposit: Option<Balance> = Some(DOLLARS);
+	pub const GameRegistrarRole: Role = Role::Registrar;
+	pub const GamePropertyCuratorRole: Role = Role::PropertyCurator;
+	pub const GameResultsOracleRole: Role = Role::ResultsOracle;
+	pub const GameSeasonManagerRole: Role = Role::SeasonManager;
+	pub const GamePriceEndpoint: &'static str = "http://localhost:8080/prices";
+	pub const GameDisputeWindow: BlockNumber = HOURS;
+	pub const GameUnsignedPriority: sp_runtime::transaction_validity::TransactionPriority =
+		sp_runtime::transaction_validity::TransactionPriority::MAX / 2;
+	pub const GameCraftingCost: u32 = 3;
+	pub const GameTrophyPoints: u32 = 1000;
 }
 
 /*
@@ -388,6 +466,14 @@ impl pallet_game::Config for Runtime {
 	type Currency = Balances;
 	type WeightInfo = pallet_game::weights::SubstrateWeight<Runtime>;
 	type GameOrigin = EnsureRoot<Self::AccountId>;
+	type RegistrarOrigin =
+		EitherOfDiverse<EnsureRoot<Self::AccountId>, EnsureRole<Runtime, GameRegistrarRole>>;
+	type PropertyCuratorOrigin =
+		EitherOfDiverse<EnsureRoot<Self::AccountId>, EnsureRole<Runtime, GamePropertyCuratorRole>>;
+	type ResultsOracleOrigin =
+		EitherOfDiverse<EnsureRoot<Self::AccountId>, EnsureRole<Runtime, GameResultsOracleRole>>;
+	type SeasonManagerOrigin =
+		EitherOfDiverse<EnsureRoot<Self::AccountId>, EnsureRole<Runtime, GameSeasonManagerRole>>;
 	type CollectionId = u32;
 	type ItemId = u32;
 	type MaxProperty = MaxProperties;
@@ -398,19 +484,109 @@ impl pallet_game::Config for Runtime {
 	type LeaderboardLimit = LeaderLimit;
 	type MaxAdmins = MaxAdmin;
 	type RequestLimit = RequestLimits;
+	type FaucetAmount = GameFaucetAmount;
+	type RegistrationDeposit = GameRegistrationDeposit;
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type Timelock = DrandTimelock;
+	#[cfg(feature = "runtime-benchmarks")]
+	type Timelock = BenchmarkTimelock;
+	type RevealDelay = GameRevealDelay;
+	type InitialPoints = GameInitialPoints;
+	type MaxPrizes = GameMaxPrizes;
+	type MarketplaceFee = GameMarketplaceFee;
+	type ListingDuration = GameListingDuration;
+	type SettlementPeriod = GameSettlementPeriod;
+	type PropertyPoolThreshold = GamePropertyPoolThreshold;
+	type Matchmaker = GameMatchmaker;
+	type MaxLobbyPlayers = GameMaxLobbyPlayers;
+	type AuthorityId = pallet_game::crypto::OracleAuthId;
+	type PriceSource = pallet_game::HttpPriceSource<GamePriceEndpoint>;
+	type DisputeWindow = GameDisputeWindow;
+	type UnsignedPriority = GameUnsignedPriority;
+	type CraftingCost = GameCraftingCost;
+	type TrophyPoints = GameTrophyPoints;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = BenchmarkTimelock;
+}
+
+/// The matchmaker of the game lobbies.
+impl pallet_matchmaker::Config<Instance1> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AmountPlayers = GameLobbyMatchSize;
+	type AmountBrackets = GameLobbyBrackets;
 }
 
 //  Ajuna Battle Mogs
 
+parameter_types! {
+	pub const BattleMogsPalletId: PalletId = PalletId(*b"aj/mogwi");
+	pub BattleMogsAccount: AccountId =
+		sp_runtime::traits::AccountIdConversion::into_account_truncating(&BattleMogsPalletId::get());
+	pub const BattleMogsWorldEventNotice: BlockNumber = 12 * HOURS;
+	pub const BattleMogsWorldEventMaxDelay: BlockNumber = 2 * DAYS;
+	pub const BattleMogsWorldEventDuration: BlockNumber = 6 * HOURS;
+	pub const BattleMogsMaxLendingDuration: BlockNumber = 30 * DAYS;
+	pub const BattleMogsMaxLendingExpiriesPerBlock: u32 = 50;
+	pub const BattleMogsMaxBatchSize: u32 = 24;
+	pub const BattleMogsOfferDuration: BlockNumber = 7 * DAYS;
+	pub const BattleMogsMaxOffersPerMogwai: u32 = 20;
+	pub const BattleMogsMaxOfferedMogwais: u32 = 4;
+	pub const BattleMogsMaxOfferExpiriesPerBlock: u32 = 100;
+	pub const BattleMogsMaxNftImportsPerBlock: u32 = 20;
+}
+
+/// Rewards paid out by battle mogs for completed achievements.
+pub struct BattleMogsAchievementRewards;
+
+impl
+	sp_runtime::traits::Convert<
+		pallet_ajuna_battle_mogs::AccountAchievement,
+		Option<pallet_ajuna_battle_mogs::AchievementReward<Balance>>,
+	> for BattleMogsAchievementRewards
+{
+	fn convert(
+		achievement: pallet_ajuna_battle_mogs::AccountAchievement,
+	) -> Option<pallet_ajuna_battle_mogs::AchievementReward<Balance>> {
+		use pallet_ajuna_battle_mogs::{AccountAchievement, AchievementReward, RarityType};
+
+		match achievement {
+			AccountAchievement::EggHatcher => Some(AchievementReward::SpecialEgg(RarityType::Rare)),
+			AccountAchievement::Sacrificer => Some(AchievementReward::Currency(10 * DOLLARS)),
+			AccountAchievement::Morpheus => Some(AchievementReward::ConfigUpgrade(1)),
+			AccountAchievement::LegendBreeder =>
+				Some(AchievementReward::SpecialEgg(RarityType::Legendary)),
+			AccountAchievement::Promiscuous => Some(AchievementReward::Currency(5 * DOLLARS)),
+			AccountAchievement::Buyer => Some(AchievementReward::ConfigUpgrade(1)),
+			AccountAchievement::Seller => Some(AchievementReward::Currency(10 * DOLLARS)),
+		}
+	}
+}
+
 impl pallet_ajuna_battle_mogs::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type Randomness = Drand;
+	type MintOrigin = frame_system::EnsureRootWithSuccess<AccountId, BattleMogsAccount>;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type FeeDestination = pallet_ajuna_battle_mogs::DepositToPallet<Runtime>;
+	type PalletId = BattleMogsPalletId;
+	type WorldEventNotice = BattleMogsWorldEventNotice;
+	type WorldEventMaxDelay = BattleMogsWorldEventMaxDelay;
+	type WorldEventDuration = BattleMogsWorldEventDuration;
+	type MaxLendingDuration = BattleMogsMaxLendingDuration;
+	type MaxLendingExpiriesPerBlock = BattleMogsMaxLendingExpiriesPerBlock;
+	type MaxBatchSize = BattleMogsMaxBatchSize;
+	type OfferDuration = BattleMogsOfferDuration;
+	type MaxOffersPerMogwai = BattleMogsMaxOffersPerMogwai;
+	type MaxOfferedMogwais = BattleMogsMaxOfferedMogwais;
+	type MaxOfferExpiriesPerBlock = BattleMogsMaxOfferExpiriesPerBlock;
+	type MaxNftImportsPerBlock = BattleMogsMaxNftImportsPerBlock;
+	type NftHandler = ();
+	type AchievementRewards = BattleMogsAchievementRewards;
 	type WeightInfo = ();
 }
 
-// Hexalem
-
+/// A `u32` constant usable as a bound of storage types.
 #[derive(Debug, PartialEq, Eq, Clone, Encode, Decode, MaxEncodedLen, TypeInfo)]
 pub struct ParameterGet<const N: u32>;
 
@@ -420,186 +596,6 @@ impl<const N: u32> Get<u32> for ParameterGet<N> {
 	}
 }
 
-pub type HexalemMaxPlayers = ParameterGet<100>;
-pub type HexalemMaxHexGridSize = ParameterGet<49>;
-pub type HexalemMaxTileSelection = ParameterGet<16>;
-
-// matchmaker parameters
-parameter_types! {
-	pub const AmountPlayers: u8 = 2;
-	pub const AmountBrackets: u8 = 2;
-}
-
-impl pallet_matchmaker::Config for Runtime {
-	type RuntimeEvent = RuntimeEvent;
-	type AmountPlayers = AmountPlayers;
-	type AmountBrackets = AmountBrackets;
-}
-
-impl pallet_elo::Config for Runtime {
-	type RuntimeEvent = RuntimeEvent;
-	type MaxPlayers = HexalemMaxPlayers;
-}
-
-parameter_types! {
-	pub FeeMultiplier: Multiplier = Multiplier::one();
-
-	pub const HexalemMinPlayers: u8 = 1;
-	pub const HexalemMaxRounds: u8 = 25;
-
-	pub const HexalemBlocksToPlayLimit: u8 = 10;
-
-	pub const HexalemTileResourceProductions: [ResourceProductions; NUMBER_OF_TILE_TYPES] = [
-		// Empty
-		ResourceProductions{
-			produces: [0, 0, 0, 0, 0, 0, 0],
-			human_requirements: [0, 0, 0, 0, 0, 0, 0],
-		},
-		// Home
-		ResourceProductions{
-			produces: [0, 1, 0, 0, 0, 0, 0],
-			human_requirements: [0, 0, 0, 0, 0, 0, 0],
-		},
-		// Grass
-		ResourceProductions{
-			produces: [0, 0, 0, 2, 0, 0, 0],
-			human_requirements: [0, 0, 0, 0, 0, 0, 0],
-		},
-		// Water
-		ResourceProductions{
-			produces: [0, 0, 2, 0, 0, 0, 0],
-			human_requirements: [0, 0, 0, 0, 0, 0, 0],
-		},
-		// Mountain
-		ResourceProductions{
-			produces: [0, 0, 0, 0, 0, 4, 0],
-			human_requirements: [0, 0, 0, 0, 0, 4, 0],
-		},
-		// Tree
-		ResourceProductions{
-			produces: [0, 0, 0, 1, 3, 0, 0],
-			human_requirements: [0, 0, 0, 0, 2, 0, 0],
-		},
-		// Desert
-		ResourceProductions{
-			produces: [0, 0, 0, 0, 0, 0, 0],
-			human_requirements: [0, 0, 0, 0, 0, 0, 0],
-		},
-		// Cave
-		ResourceProductions{
-			produces: [0, 0, 0, 0, 0, 2, 1],
-			human_requirements: [0, 0, 0, 0, 0, 2, 3],
-		},
-	];
-
-	pub const HexalemTileCosts: [TileCost<HexalemTile>; 15] = [
-		// tile_to_buy: HexalemTile(16), // Grass, level 0
-		// tile_to_buy: HexalemTile(24), // Water, level 0
-		// tile_to_buy: HexalemTile(32), // Mountain, level 0
-		// tile_to_buy: HexalemTile(40), // Tree, level 0
-		// tile_to_buy: HexalemTile(48), // Desert, level 0
-		// tile_to_buy: HexalemTile(56), // Cave, level 0
-
-		TileCost {
-			tile_to_buy: HexalemTile(16), // Grass, level 0
-			cost: ResourceAmount { resource_type: ResourceType::Mana, amount: 1, }
-		},
-		TileCost {
-			tile_to_buy: HexalemTile(16), // Grass, level 0
-			cost: ResourceAmount { resource_type: ResourceType::Mana, amount: 1, }
-		},
-		TileCost {
-			tile_to_buy: HexalemTile(16), // Grass, level 0
-			cost: ResourceAmount { resource_type: ResourceType::Mana, amount: 1, }
-		},
-		TileCost {
-			tile_to_buy: HexalemTile(24), // Water, level 0
-			cost: ResourceAmount { resource_type: ResourceType::Mana, amount: 1, }
-		},
-		TileCost {
-			tile_to_buy: HexalemTile(24), // Water, level 0
-			cost: ResourceAmount { resource_type: ResourceType::Mana, amount: 1, }
-		},
-		TileCost {
-			tile_to_buy: HexalemTile(24), // Water, level 0
-			cost: ResourceAmount { resource_type: ResourceType::Mana, amount: 1, }
-		},
-
-		TileCost {
-			tile_to_buy: HexalemTile(32), // Mountain, level 0
-			cost: ResourceAmount { resource_type: ResourceType::Mana, amount: 1, }
-		},
-		TileCost {
-			tile_to_buy: HexalemTile(32), // Mountain, level 0
-			cost: ResourceAmount { resource_type: ResourceType::Mana, amount: 1, }
-		},
-		TileCost {
-			tile_to_buy: HexalemTile(32), // Mountain, level 0
-			cost: ResourceAmount { resource_type: ResourceType::Mana, amount: 1, }
-		},
-		TileCost {
-			tile_to_buy: HexalemTile(40), // Tree, level 0
-			cost: ResourceAmount { resource_type: ResourceType::Mana, amount: 1, }
-		},
-		TileCost {
-			tile_to_buy: HexalemTile(40), // Tree, level 0
-			cost: ResourceAmount { resource_type: ResourceType::Mana, amount: 1, }
-		},
-		TileCost {
-			tile_to_buy: HexalemTile(40), // Tree, level 0
-			cost: ResourceAmount { resource_type: ResourceType::Mana, amount: 1, }
-		},
-		TileCost {
-			tile_to_buy: HexalemTile(48), // Desert, level 0
-			cost: ResourceAmount { resource_type: ResourceType::Mana, amount: 1, }
-		},
-		TileCost {
-			tile_to_buy: HexalemTile(56), // Cave, level 0
-			cost: ResourceAmount { resource_type: ResourceType::Mana, amount: 1, }
-		},
-		TileCost {
-			tile_to_buy: HexalemTile(56), // Cave, level 0
-			cost: ResourceAmount { resource_type: ResourceType::Mana, amount: 1, }
-		},
-	];
-
-	pub const HexalemFoodPerHuman: u8 = 1u8;
-	pub const HexalemWaterPerHuman: u8 = 2u8;
-	pub const HexalemHomePerHumans: u8 = 3u8;
-	pub const HexalemFoodPerTree: u8 = 1u8;
-
-	pub const HexalemDefaultPlayerResources: [ResourceUnit; NUMBER_OF_RESOURCE_TYPES] = [1, 1, 0, 0, 0, 0, 0];
-
-	pub const HexalemTargetGoalGold: u8 = 10u8;
-	pub const HexalemTargetGoalHuman: u8 = 7u8;
-
-}
-
-/// Configure the pallet-hexalem in pallets/hexalem.
-impl pallet_hexalem::Config for Runtime {
-	type RuntimeEvent = RuntimeEvent;
-	type WeightInfo = pallet_hexalem::weights::SubstrateWeight<Runtime>;
-	type MaxPlayers = HexalemMaxPlayers;
-	type MinPlayers = HexalemMinPlayers;
-	type MaxRounds = HexalemMaxRounds;
-	type BlocksToPlayLimit = HexalemBlocksToPlayLimit;
-	type MaxHexGridSize = HexalemMaxHexGridSize;
-	type MaxTileSelection = HexalemMaxTileSelection;
-	type Tile = HexalemTile;
-	type TileCosts = HexalemTileCosts;
-	type TileResourceProductions = HexalemTileResourceProductions;
-	type WaterPerHuman = HexalemWaterPerHuman;
-	type FoodPerHuman = HexalemFoodPerHuman;
-	type FoodPerTree = HexalemFoodPerTree;
-	type HomePerHumans = HexalemHomePerHumans;
-	type DefaultPlayerResources = HexalemDefaultPlayerResources;
-	type TargetGoalGold = HexalemTargetGoalGold;
-	type TargetGoalHuman = HexalemTargetGoalHuman;
-	type Matchmaker = MatchmakerModule;
-	type Elo = EloModule;
-	type Randomness = Drand;
-}
-
 // Create the runtime by composing the FRAME pallets that were previously configured.
 #[frame_support::runtime]
 mod runtime {
@@ -649,10 +645,16 @@ mod runtime {
 	pub type Contracts = pallet_contracts;
 
 	#[runtime::pallet_index(10)]
-	pub type BattleMogs: pallet_ajuna_battle_mogs;
+	pub type BattleMogs = pallet_ajuna_battle_mogs;
 
 	#[runtime::pallet_index(11)]
-	pub type Game: pallet_game;
+	pub type Game = pallet_game;
+
+	#[runtime::pallet_index(12)]
+	pub type GameMatchmaker = pallet_matchmaker<Instance1>;
+
+	#[runtime::pallet_index(13)]
+	pub type Nfts = pallet_nfts;
 }
 
 impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Runtime
@@ -728,7 +730,10 @@ pub type SignedExtra = (
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+	pallet_ajuna_battle_mogs::migration::v2::MigrateToV2<Runtime>,
+	// `migration::nft_import::ImportMogwaiNfts` is only added once `NftHandler` mirrors mogwais.
+);
 
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic =
@@ -754,6 +759,8 @@ mod benches {
 		[pallet_timestamp, Timestamp]
 		[pallet_sudo, Sudo]
 		[pallet_drand, Drand]
+		[pallet_nfts, Nfts]
+		[pallet_game, Game]
 	);
 }
 
@@ -1089,6 +1096,122 @@ pub mod interface {
 	pub type MinimumBalance = <Runtime as pallet_balances::Config>::ExistentialDeposit;
 }
 
+/// Decrypts the timelocked property prices of pallet-game with the pulses bridged by pallet-drand.
+///
+/// The pulses are stored per block and beacon rounds don't line up with blocks, so the pulse of a
+/// round is looked up among all bridged pulses.
+pub struct DrandTimelock;
+
+/// Blocks without a pulse that are skipped while looking for one. The bridge brings a pulse on
+/// chain in nearly every block, so longer gaps are not searched.
+const MAX_PULSE_GAP: BlockNumber = 16;
+
+/// The block up to `latest` whose pulse is of `round`, if any.
+///
+/// The rounds of the bridged pulses grow with the block number, so the blocks are bisected.
+fn block_of_round(
+	round: pallet_game::RoundNumber,
+	latest: BlockNumber,
+	round_at: impl Fn(BlockNumber) -> Option<pallet_game::RoundNumber>,
+) -> Option<BlockNumber> {
+	let (mut low, mut high) = (0, latest.saturating_add(1));
+	while low < high {
+		let mid = low + (high - low) / 2;
+		// The first pulse at or after `mid`.
+		let Some((block, pulse_round)) = (mid..high.min(mid.saturating_add(MAX_PULSE_GAP)))
+			.find_map(|block| round_at(block).map(|pulse_round| (block, pulse_round)))
+		else {
+			high = mid;
+			continue;
+		};
+		match pulse_round.cmp(&round) {
+			core::cmp::Ordering::Equal => return Some(block),
+			core::cmp::Ordering::Less => low = block + 1,
+			core::cmp::Ordering::Greater => high = mid,
+		}
+	}
+	None
+}
+
+impl DrandTimelock {
+	/// The block whose pulse is of `round`.
+	fn block_of(round: pallet_game::RoundNumber) -> Option<BlockNumber> {
+		block_of_round(round, System::block_number(), |block| {
+			pallet_drand::Pulses::<Runtime>::get(block).map(|pulse| pulse.round)
+		})
+	}
+}
+
+impl pallet_game::TimelockDecrypter for DrandTimelock {
+	fn latest_round() -> pallet_game::RoundNumber {
+		let now = System::block_number();
+		(now.saturating_sub(MAX_PULSE_GAP)..=now)
+			.rev()
+			.find_map(pallet_drand::Pulses::<Runtime>::get)
+			.map(|pulse| pulse.round)
+			.unwrap_or_default()
+	}
+
+	fn is_revealed(round: pallet_game::RoundNumber) -> bool {
+		Self::block_of(round).is_some()
+	}
+
+	fn decrypt(round: pallet_game::RoundNumber, ciphertext: &[u8]) -> Option<Vec<u8>> {
+		use ark_serialize::CanonicalDeserialize;
+		use timelock::{
+			block_ciphers::AESGCMBlockCipherProvider,
+			curves::drand::TinyBLS381,
+			tlock::{tld, TLECiphertext},
+		};
+		use w3f_bls::EngineBLS;
+
+		let pulse = pallet_drand::Pulses::<Runtime>::get(Self::block_of(round)?)?;
+		let signature =
+			<TinyBLS381 as EngineBLS>::SignatureGroup::deserialize_compressed(&pulse.signature[..])
+				.ok()?;
+		let ciphertext = TLECiphertext::<TinyBLS381>::deserialize_compressed(ciphertext).ok()?;
+		tld::<TinyBLS381, AESGCMBlockCipherProvider>(ciphertext, signature).ok()
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+parameter_types! {
+	pub storage BenchmarkLatestRound: pallet_game::RoundNumber = 0;
+}
+
+/// The timelock pallet-game is benchmarked with.
+///
+/// Drand signatures can't be produced for arbitrary rounds, so its "ciphertexts" are the plain
+/// SCALE encoded prices, which it hands out once their round has been reached.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct BenchmarkTimelock;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_game::TimelockDecrypter for BenchmarkTimelock {
+	fn latest_round() -> pallet_game::RoundNumber {
+		BenchmarkLatestRound::get()
+	}
+
+	fn is_revealed(round: pallet_game::RoundNumber) -> bool {
+		round <= Self::latest_round()
+	}
+
+	fn decrypt(round: pallet_game::RoundNumber, ciphertext: &[u8]) -> Option<Vec<u8>> {
+		Self::is_revealed(round).then(|| ciphertext.to_vec())
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_game::BenchmarkHelper for BenchmarkTimelock {
+	fn lock_price(_round: pallet_game::RoundNumber, price: u32) -> Vec<u8> {
+		price.encode()
+	}
+
+	fn reveal_round(round: pallet_game::RoundNumber) {
+		BenchmarkLatestRound::set(&round.max(Self::latest_round()));
+	}
+}
+
 #[derive(Default)]
 pub struct DrandExtension;
 
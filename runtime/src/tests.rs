@@ -0,0 +1,90 @@
+use super::*;
+use frame_support::{
+	assert_ok,
+	traits::{Currency, OnInitialize},
+};
+use pallet_game::{DifficultyLevel, PointsDelta, PropertyInfoData};
+
+const PRICE: u32 = 220_000;
+
+fn new_test_ext() -> sp_io::TestExternalities {
+	let storage = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+	let mut ext = sp_io::TestExternalities::new(storage);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+/// A property whose price is locked to a drand round far in the future, so only the oracle
+/// can settle its games.
+fn property(id: u32) -> PropertyInfoData<Runtime> {
+	PropertyInfoData {
+		id,
+		data: "nfdjakl;fueif;janf,dnfm,dhfhfdksks".as_bytes().to_vec().try_into().unwrap(),
+		region: "Bristol".as_bytes().to_vec().try_into().unwrap(),
+		features: "3 bedrooms, garden".as_bytes().to_vec().try_into().unwrap(),
+		round: 1_000_000,
+		price: PRICE.encode().try_into().unwrap(),
+	}
+}
+
+/// Answers a game with the exact price and lets the oracle settle it.
+fn settle_game(player: &AccountId, game_id: u32) {
+	assert_ok!(Game::submit_answer(RuntimeOrigin::signed(player.clone()), PRICE, game_id));
+	assert_ok!(Game::submit_result(RuntimeOrigin::root(), game_id, PRICE));
+	let final_block = System::block_number() + GameDisputeWindow::get();
+	System::set_block_number(final_block);
+	Game::on_initialize(final_block);
+}
+
+#[test]
+fn game_mints_nfts_in_pallet_nfts() {
+	new_test_ext().execute_with(|| {
+		let player = AccountId::from([1; 32]);
+		// The pallet account pays the deposits of the collections of a round.
+		Balances::make_free_balance_be(&Game::account_id(), 1_000 * DOLLARS);
+		assert_ok!(Game::setup_game(RuntimeOrigin::root()));
+		assert_ok!(Game::add_property(RuntimeOrigin::root(), property(1)));
+		assert_ok!(Game::add_property(RuntimeOrigin::root(), property(2)));
+		assert_ok!(Game::register_user(RuntimeOrigin::root(), player.clone()));
+		assert_ok!(Game::play_game(
+			RuntimeOrigin::signed(player.clone()),
+			DifficultyLevel::Practice
+		));
+		settle_game(&player, 0);
+		assert_ok!(Game::play_game(RuntimeOrigin::signed(player.clone()), DifficultyLevel::Player));
+		settle_game(&player, 1);
+
+		let (collection_id, item_id) = System::events()
+			.into_iter()
+			.find_map(|record| match record.event {
+				RuntimeEvent::Game(pallet_game::Event::ResultChecked {
+					game_id: 1,
+					points: PointsDelta::Gained(100),
+					nft,
+					..
+				}) => nft,
+				_ => None,
+			})
+			.unwrap();
+		assert!(Game::round_collections(Game::current_round()).contains(&collection_id));
+		assert_eq!(Nfts::owner(collection_id, item_id), Some(player.clone()));
+		assert_eq!(Nfts::collection_owner(collection_id), Some(Game::account_id()));
+		assert_eq!(Game::users(&player).unwrap().wins, 1);
+	});
+}
+
+#[test]
+fn pulses_are_found_by_round_rather_than_block() {
+	// Two rounds per block, starting far from the block numbers, and a block without a pulse.
+	let round_at = |block: BlockNumber| (block != 7).then(|| 1_000 + 2 * block as u64);
+
+	assert_eq!(block_of_round(1_010, 20, round_at), Some(5));
+	assert_eq!(block_of_round(1_000, 20, round_at), Some(0));
+	assert_eq!(block_of_round(1_040, 20, round_at), Some(20));
+	assert_eq!(block_of_round(1_016, 20, round_at), Some(8));
+	// The pulse of block 7 is missing and odd rounds are skipped by the bridge.
+	assert_eq!(block_of_round(1_014, 20, round_at), None);
+	assert_eq!(block_of_round(1_011, 20, round_at), None);
+	// Not bridged yet.
+	assert_eq!(block_of_round(1_042, 20, round_at), None);
+}